@@ -25,25 +25,64 @@
 | server.compression.enabled | Whether to enable compression. | false |
 | server.compression.mime-types | The mime types to compress. | text/html,text/xml,text/plain,text/css,text/javascript,application/javascript,application/json,application/xml |
 | server.compression.min-response-size | The minimum response size to compress. (byte) | 2048 |
-| server.compression.algorithm | The compression algorithm to use. (gzip,deflate) | gzip |
+| server.compression.algorithm | The preferred compression algorithm. The algorithm is negotiated with the Accept-Encoding header of the request. (gzip,deflate,br,zstd) | gzip |
 | server.thread.limit | The thread limit to use. | None(max) |
 | server.request-timeout | The request timeout. (300 = 300 millisecond, 3s = 3 second, 2m = 2 minute) | No Timeout |
 | server.request.uri.max-length | The max length of the request URI. | None |
 | server.request.header.max-length | The max length of the request header. | None |
 | server.request.header.max-number-of-headers | The number of headers to allow. | None |
-| server.request.body.max-length | The max length of the request body. | 2MB |
+| server.request.body.max-length | The max length of the request body. (For compressed request bodies, the decompressed size) | 2MB |
+| server.request.body.decompression-enabled | Whether to decompress the request body according to the Content-Encoding header. (gzip,deflate,br,zstd) Streaming routes reject encoded bodies with 415 instead. | true |
 | server.http1.keep-alive | Whether to keep-alive for HTTP/1. (false=disable, true=enable) | false |
+| server.http2.enabled | Whether to serve HTTP/2 alongside HTTP/1.1. (h2 by ALPN with TLS, h2c with prior knowledge without TLS) | false |
+| server.http2.max-concurrent-streams | The max number of concurrent streams per HTTP/2 connection. | 200 |
+| server.http2.initial-window-size | The initial window size of HTTP/2 streams. (e.g. 65535, 1MB) | 64KB (65535) |
+| server.http2.keep-alive-interval | The interval of HTTP/2 keep-alive pings. (300 = 300 millisecond, 3s = 3 second, 2m = 2 minute) | Disabled |
+| server.http2.keep-alive-timeout | The timeout to wait for a keep-alive ping to be acknowledged. | 20s |
 | server.ssl.key | The SSL key file. (SSL is enabled by feature="tls") | None |
 | server.ssl.cert | The SSL cert file. (SSL is enabled by feature="tls") | None |
 | server.multipart.auto-parsing-enabled | Whether to enable auto parsing for multipart. | true |
+| server.multipart.max-file-size | The max size of a file in a streaming multipart body. | 10MB |
+| server.multipart.max-request-size | The max size of a streaming multipart body. | 100MB |
+| server.multipart.file-size-threshold | The size above which files of a streaming multipart body are spooled to disk. | 1MB |
+| server.multipart.location | The directory of the spooled files. | The temp directory of the OS |
 | server.cookie.auto-parsing-enabled | Whether to enable auto parsing for cookie. | true |
+| server.cors.enabled | Whether to enable CORS. (see [crate::http::cors]) | false |
+| server.cors.allowed-origins | The allowed origins. (comma separated, `*` and patterns such as `http://localhost:*` are allowed) | None |
+| server.cors.allowed-methods | The allowed methods. (comma separated, `*` for every method) | GET,HEAD,POST |
+| server.cors.allowed-headers | The allowed request headers. (comma separated, `*` for every header) | * |
+| server.cors.exposed-headers | The response headers exposed to the browser. (comma separated) | None |
+| server.cors.allow-credentials | Whether to allow credentials. (can't be used with `allowed-origins=*`) | false |
+| server.cors.max-age | How long the result of a preflight request can be cached. (second) | 1800 |
+| server.static.enabled | Whether to serve static files from a directory. (see [crate::http::static_files]) | false |
+| server.static.path-prefix | The URL prefix of the static files. | / |
+| server.static.location | The directory of the static files. | ./static |
+| server.static.index | The index file of directories. | index.html |
+| server.static.spa-fallback | Whether to serve the index file for unknown paths without an extension. (for single page applications) | false |
+| server.static.cache-control | The Cache-Control header of the static files. (e.g. max-age=3600) | None |
+| server.error.format | The body format of the default error responses. (text, problem-json) | text |
 | banner.enabled | Whether to enable the banner. | true |
 | banner.location | The location of the banner file. | None |
 | banner.charset | The charset of the banner file. (UTF-8, UTF-16) | UTF-8 |
+| swagger.openapi-version | The document version shown by the Swagger UI. `/docs/swagger.json` (2.0) and `/docs/openapi.json` (3.1) are both served. (2.0, 3.1) | 2.0 |
+| swagger.servers | The server URLs of the document. (comma separated, the first one is the host of the 2.0 document) | None |
+| swagger.path | The path of the Swagger UI and the documents. | /docs |
+| swagger.ui.enabled | Whether to serve the Swagger UI. If disabled, only the documents are served. | true |
+| swagger.title | The title of the document. | Rupring |
+| swagger.version | The version of the document. | None |
+| swagger.description | The description of the document. | None |
+| swagger.terms-of-service | The terms of service URL of the document. | http://swagger.io/terms/ |
+| swagger.contact.name | The contact name of the document. | None |
+| swagger.contact.url | The contact URL of the document. | None |
+| swagger.contact.email | The contact email of the document. | None |
+| swagger.license.name | The license name of the document. | Apache 2.0 |
+| swagger.license.url | The license URL of the document. | http://www.apache.org/licenses/LICENSE-2.0.html |
 */
 
 use std::{collections::HashMap, net::SocketAddr, time::Duration};
 
+use crate::http::cors::CorsConfig;
+
 // "250", "10KB", '10MB', "10GB" 같은 표현식을 실제 바이트 단위 정수값으로 변환
 pub fn parse_byte_size(size: &str) -> Option<usize> {
     let size = size.trim();
@@ -70,11 +109,36 @@ pub fn parse_byte_size(size: &str) -> Option<usize> {
     Some(size as usize)
 }
 
+// "300" = 300 millisecond, "3s" = 3 second, "2m" = 2 minute
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let number = value.trim_end_matches(|c| !char::is_numeric(c));
+    let number = number.parse::<u64>().ok()?;
+
+    let duration = match value.chars().last() {
+        Some('s') => Duration::from_secs(number),
+        Some('m') => Duration::from_secs(number * 60),
+        _ => Duration::from_millis(number),
+    };
+
+    Some(duration)
+}
+
+// "a, b,,c" => ["a", "b", "c"]
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|e| e.trim().to_string())
+        .filter(|e| !e.is_empty())
+        .collect()
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct ApplicationProperties {
     pub server: Server,
     pub environment: String,
     pub banner: Banner,
+    pub swagger: Swagger,
 
     pub etc: HashMap<String, String>,
 }
@@ -86,6 +150,7 @@ impl Default for ApplicationProperties {
             environment: "dev".to_string(),
             etc: HashMap::new(),
             banner: Banner::default(),
+            swagger: Swagger::default(),
         }
     }
 }
@@ -94,6 +159,8 @@ impl Default for ApplicationProperties {
 pub enum CompressionAlgorithm {
     Gzip,
     Deflate,
+    Brotli,
+    Zstd,
     Unknown(String),
 }
 
@@ -110,6 +177,8 @@ impl ToString for CompressionAlgorithm {
         match self {
             CompressionAlgorithm::Gzip => "gzip".to_string(),
             CompressionAlgorithm::Deflate => "deflate".to_string(),
+            CompressionAlgorithm::Brotli => "br".to_string(),
+            CompressionAlgorithm::Zstd => "zstd".to_string(),
             CompressionAlgorithm::Unknown(s) => s.to_string(),
         }
     }
@@ -120,6 +189,8 @@ impl From<String> for CompressionAlgorithm {
         match s.as_str() {
             "gzip" => CompressionAlgorithm::Gzip,
             "deflate" => CompressionAlgorithm::Deflate,
+            "br" | "brotli" => CompressionAlgorithm::Brotli,
+            "zstd" => CompressionAlgorithm::Zstd,
             _ => CompressionAlgorithm::Unknown(s),
         }
     }
@@ -165,6 +236,129 @@ impl Default for Banner {
     }
 }
 
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum OpenApiVersion {
+    // Swagger 2.0
+    #[default]
+    V2_0,
+    // OpenAPI 3.1
+    V3_1,
+}
+
+impl From<String> for OpenApiVersion {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "2.0" | "2" => OpenApiVersion::V2_0,
+            "3.1" | "3.1.0" | "3" => OpenApiVersion::V3_1,
+            _ => OpenApiVersion::V2_0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Swagger {
+    pub openapi_version: OpenApiVersion,
+    pub servers: Vec<String>,
+    // The path where the Swagger UI and the documents are served.
+    pub path: String,
+    // If disabled, only the documents (swagger.json, openapi.json) are served.
+    pub ui_enabled: bool,
+    pub info: crate::swagger::SwaggerInfo,
+    // The security schemes referred to by `auth`. (BearerAuth is always declared)
+    pub security_schemes: crate::swagger::SwaggerSecurityDefinitions,
+}
+
+impl Default for Swagger {
+    fn default() -> Self {
+        Swagger {
+            openapi_version: OpenApiVersion::default(),
+            servers: vec![],
+            path: "/docs".to_string(),
+            ui_enabled: true,
+            info: Default::default(),
+            security_schemes: Default::default(),
+        }
+    }
+}
+
+// "docs", "/docs/" => "/docs"
+fn normalize_swagger_path(path: &str) -> String {
+    format!("/{}", path.trim().trim_matches('/'))
+}
+
+impl Swagger {
+    pub fn openapi_version(mut self, openapi_version: OpenApiVersion) -> Self {
+        self.openapi_version = openapi_version;
+        self
+    }
+
+    pub fn server(mut self, url: impl ToString) -> Self {
+        self.servers.push(url.to_string());
+        self
+    }
+
+    pub fn path(mut self, path: impl ToString) -> Self {
+        self.path = normalize_swagger_path(&path.to_string());
+        self
+    }
+
+    pub fn ui_enabled(mut self, ui_enabled: bool) -> Self {
+        self.ui_enabled = ui_enabled;
+        self
+    }
+
+    pub fn title(mut self, title: impl ToString) -> Self {
+        self.info.title = title.to_string();
+        self
+    }
+
+    pub fn version(mut self, version: impl ToString) -> Self {
+        self.info.version = version.to_string();
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.info.description = description.to_string();
+        self
+    }
+
+    pub fn terms_of_service(mut self, terms_of_service: impl ToString) -> Self {
+        self.info.terms_of_service = terms_of_service.to_string();
+        self
+    }
+
+    pub fn contact(
+        mut self,
+        name: impl ToString,
+        url: impl ToString,
+        email: impl ToString,
+    ) -> Self {
+        self.info.contact = crate::swagger::SwaggerContact {
+            name: name.to_string(),
+            url: url.to_string(),
+            email: email.to_string(),
+        };
+        self
+    }
+
+    pub fn security_scheme(
+        mut self,
+        name: impl ToString,
+        definition: crate::swagger::SwaggerSecurityDefinition,
+    ) -> Self {
+        self.security_schemes.insert(name.to_string(), definition);
+        self
+    }
+
+    pub fn license(mut self, name: impl ToString, url: impl ToString) -> Self {
+        self.info.license = crate::swagger::SwaggerLicense {
+            name: name.to_string(),
+            url: url.to_string(),
+        };
+        self
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum ShutdownType {
     Immediate,
@@ -192,15 +386,46 @@ pub struct Http1 {
     pub keep_alive: bool,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct Http2 {
+    // If enabled, both HTTP/1.1 and HTTP/2 are served. (detected per connection)
+    pub enabled: bool,
+    pub max_concurrent_streams: Option<u32>,
+    pub initial_window_size: Option<u32>,
+    pub keep_alive_interval: Option<Duration>,
+    pub keep_alive_timeout: Option<Duration>,
+}
+
+impl Default for Http2 {
+    fn default() -> Self {
+        Http2 {
+            enabled: false,
+            max_concurrent_streams: Some(200),
+            initial_window_size: Some(65535), // 64KB
+            keep_alive_interval: None,
+            keep_alive_timeout: Some(Duration::from_secs(20)),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct Multipart {
     pub auto_parsing_enabled: bool,
+    // The limits of streaming multipart. (see [crate::http::multipart::Multipart])
+    pub max_file_size: usize,
+    pub max_request_size: usize,
+    pub file_size_threshold: usize,
+    pub location: Option<String>,
 }
 
 impl Default for Multipart {
     fn default() -> Self {
         Multipart {
             auto_parsing_enabled: true,
+            max_file_size: 10 * 1024 * 1024,     // 10MB
+            max_request_size: 100 * 1024 * 1024, // 100MB
+            file_size_threshold: 1024 * 1024,    // 1MB
+            location: None,
         }
     }
 }
@@ -232,12 +457,14 @@ pub struct RequestHeaderConfig {
 #[derive(Debug, PartialEq, Clone)]
 pub struct RequestBodyConfig {
     pub max_length: usize,
+    pub decompression_enabled: bool,
 }
 
 impl Default for RequestBodyConfig {
     fn default() -> Self {
         RequestBodyConfig {
             max_length: 2 * 1000 * 1000, // 2MB
+            decompression_enabled: true,
         }
     }
 }
@@ -249,6 +476,52 @@ pub struct RequestConfig {
     pub body: RequestBodyConfig,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct StaticFiles {
+    pub enabled: bool,
+    pub path_prefix: String,
+    pub location: String,
+    pub index: String,
+    pub spa_fallback: bool,
+    pub cache_control: Option<String>,
+}
+
+impl Default for StaticFiles {
+    fn default() -> Self {
+        StaticFiles {
+            enabled: false,
+            path_prefix: "/".to_string(),
+            location: "./static".to_string(),
+            index: "index.html".to_string(),
+            spa_fallback: false,
+            cache_control: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum ErrorFormat {
+    #[default]
+    Text,
+    // RFC 7807 (application/problem+json)
+    ProblemJson,
+}
+
+impl From<String> for ErrorFormat {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "text" => ErrorFormat::Text,
+            "problem-json" => ErrorFormat::ProblemJson,
+            _ => ErrorFormat::Text,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ErrorConfig {
+    pub format: ErrorFormat,
+}
+
 // Reference: https://docs.spring.io/spring-boot/appendix/application-properties/index.html#appendix.application-properties.server
 #[derive(Debug, PartialEq, Clone)]
 pub struct Server {
@@ -260,10 +533,14 @@ pub struct Server {
     pub thread_limit: Option<usize>,
     pub request_timeout: Option<Duration>,
     pub http1: Http1,
+    pub http2: Http2,
     pub ssl: SSL,
     pub multipart: Multipart,
     pub cookie: Cookie,
     pub request: RequestConfig,
+    pub error: ErrorConfig,
+    pub cors: CorsConfig,
+    pub static_files: StaticFiles,
 }
 
 impl Server {
@@ -293,10 +570,14 @@ impl Default for Server {
             thread_limit: None,
             request_timeout: None,
             http1: Http1::default(),
+            http2: Http2::default(),
             ssl: Default::default(),
             multipart: Default::default(),
             cookie: Default::default(),
             request: Default::default(),
+            error: Default::default(),
+            cors: Default::default(),
+            static_files: Default::default(),
         }
     }
 }
@@ -329,12 +610,14 @@ impl ApplicationProperties {
         let mut environment = "dev".to_string();
         let mut etc = HashMap::new();
         let mut banner = Banner::default();
+        let mut swagger = Swagger::default();
 
         let mut key_values = HashMap::new();
 
         // application.properties 파일에서 추출
         for line in text.lines() {
-            let mut parts = line.split("=");
+            // The value can contain "=". (ex. server.static.cache-control=max-age=3600)
+            let mut parts = line.splitn(2, "=");
 
             let key = match parts.next() {
                 Some(key) => key.trim().to_owned(),
@@ -404,22 +687,11 @@ impl ApplicationProperties {
                     }
                 }
                 "server.request-timeout" => {
-                    // * = millisecond
-                    // *s = second
-                    // *m = minute
-                    let timeout = value.trim_end_matches(|c| !char::is_numeric(c));
-
-                    if let Ok(timeout) = timeout.parse::<u64>() {
-                        if timeout == 0 {
+                    if let Some(duration) = parse_duration(value.as_str()) {
+                        if duration.is_zero() {
                             continue;
                         }
 
-                        let duration = match value.chars().last() {
-                            Some('s') => std::time::Duration::from_secs(timeout),
-                            Some('m') => std::time::Duration::from_secs(timeout * 60),
-                            _ => std::time::Duration::from_millis(timeout),
-                        };
-
                         server.request_timeout = Some(duration);
                     }
                 }
@@ -443,11 +715,37 @@ impl ApplicationProperties {
                         server.request.body.max_length = value;
                     }
                 }
+                "server.request.body.decompression-enabled" => {
+                    if let Ok(value) = value.parse::<bool>() {
+                        server.request.body.decompression_enabled = value;
+                    }
+                }
                 "server.http1.keep-alive" => {
                     if let Ok(value) = value.parse::<bool>() {
                         server.http1.keep_alive = value;
                     }
                 }
+                "server.http2.enabled" => {
+                    if let Ok(value) = value.parse::<bool>() {
+                        server.http2.enabled = value;
+                    }
+                }
+                "server.http2.max-concurrent-streams" => {
+                    if let Ok(value) = value.parse::<u32>() {
+                        server.http2.max_concurrent_streams = Some(value);
+                    }
+                }
+                "server.http2.initial-window-size" => {
+                    if let Some(value) = parse_byte_size(value.as_str()) {
+                        server.http2.initial_window_size = u32::try_from(value).ok();
+                    }
+                }
+                "server.http2.keep-alive-interval" => {
+                    server.http2.keep_alive_interval = parse_duration(value.as_str());
+                }
+                "server.http2.keep-alive-timeout" => {
+                    server.http2.keep_alive_timeout = parse_duration(value.as_str());
+                }
                 "server.ssl.key" => {
                     server.ssl.key = value.to_string();
                 }
@@ -456,11 +754,81 @@ impl ApplicationProperties {
                         server.multipart.auto_parsing_enabled = value;
                     }
                 }
+                "server.multipart.max-file-size" => {
+                    if let Some(value) = parse_byte_size(value.as_str()) {
+                        server.multipart.max_file_size = value;
+                    }
+                }
+                "server.multipart.max-request-size" => {
+                    if let Some(value) = parse_byte_size(value.as_str()) {
+                        server.multipart.max_request_size = value;
+                    }
+                }
+                "server.multipart.file-size-threshold" => {
+                    if let Some(value) = parse_byte_size(value.as_str()) {
+                        server.multipart.file_size_threshold = value;
+                    }
+                }
+                "server.multipart.location" => {
+                    server.multipart.location = Some(value.to_string());
+                }
                 "server.cookie.auto-parsing-enabled" => {
                     if let Ok(value) = value.parse::<bool>() {
                         server.cookie.auto_parsing_enabled = value;
                     }
                 }
+                "server.error.format" => {
+                    server.error.format = value.into();
+                }
+                "server.static.enabled" => {
+                    if let Ok(value) = value.parse::<bool>() {
+                        server.static_files.enabled = value;
+                    }
+                }
+                "server.static.path-prefix" => {
+                    server.static_files.path_prefix = value.to_string();
+                }
+                "server.static.location" => {
+                    server.static_files.location = value.to_string();
+                }
+                "server.static.index" => {
+                    server.static_files.index = value.to_string();
+                }
+                "server.static.spa-fallback" => {
+                    if let Ok(value) = value.parse::<bool>() {
+                        server.static_files.spa_fallback = value;
+                    }
+                }
+                "server.static.cache-control" => {
+                    server.static_files.cache_control = Some(value.to_string());
+                }
+                "server.cors.enabled" => {
+                    if let Ok(value) = value.parse::<bool>() {
+                        server.cors.enabled = value;
+                    }
+                }
+                "server.cors.allowed-origins" => {
+                    server.cors.allowed_origins = split_list(&value);
+                }
+                "server.cors.allowed-methods" => {
+                    server.cors.allowed_methods = split_list(&value);
+                }
+                "server.cors.allowed-headers" => {
+                    server.cors.allowed_headers = split_list(&value);
+                }
+                "server.cors.exposed-headers" => {
+                    server.cors.exposed_headers = split_list(&value);
+                }
+                "server.cors.allow-credentials" => {
+                    if let Ok(value) = value.parse::<bool>() {
+                        server.cors.allow_credentials = value;
+                    }
+                }
+                "server.cors.max-age" => {
+                    if let Ok(value) = value.parse::<u64>() {
+                        server.cors.max_age = Some(value);
+                    }
+                }
                 "server.ssl.cert" => {
                     server.ssl.cert = value.to_string();
                 }
@@ -476,21 +844,86 @@ impl ApplicationProperties {
                 "banner.charset" => {
                     banner.charset = value.to_string();
                 }
+                "swagger.openapi-version" => {
+                    swagger.openapi_version = value.into();
+                }
+                "swagger.servers" => {
+                    swagger.servers = split_list(&value);
+                }
+                "swagger.path" => {
+                    swagger.path = normalize_swagger_path(&value);
+                }
+                "swagger.ui.enabled" => {
+                    swagger.ui_enabled = value.parse::<bool>().unwrap_or(true);
+                }
+                "swagger.title" => {
+                    swagger.info.title = value.to_string();
+                }
+                "swagger.version" => {
+                    swagger.info.version = value.to_string();
+                }
+                "swagger.description" => {
+                    swagger.info.description = value.to_string();
+                }
+                "swagger.terms-of-service" => {
+                    swagger.info.terms_of_service = value.to_string();
+                }
+                "swagger.contact.name" => {
+                    swagger.info.contact.name = value.to_string();
+                }
+                "swagger.contact.url" => {
+                    swagger.info.contact.url = value.to_string();
+                }
+                "swagger.contact.email" => {
+                    swagger.info.contact.email = value.to_string();
+                }
+                "swagger.license.name" => {
+                    swagger.info.license.name = value.to_string();
+                }
+                "swagger.license.url" => {
+                    swagger.info.license.url = value.to_string();
+                }
                 _ => {
                     etc.insert(key, value);
                 }
             }
         }
 
+        server.cors.assert_valid();
+
         ApplicationProperties {
             server,
             etc,
             environment,
             banner,
+            swagger,
         }
     }
 }
 
+// 알아서 모든 대상에 대해 application.properties를 읽어서 ApplicationProperties를 반환하는 함수
+pub fn load_application_properties_from_all() -> ApplicationProperties {
+    // 1. 현재 경로에 application.properties가 있는지 확인하고, 있다면 읽어서 반환합니다.
+    if let Ok(text) = std::fs::read_to_string("application.properties") {
+        return ApplicationProperties::from_properties(text);
+    }
+
+    // 2. 실행파일 경로에 application.properties가 있는지 확인하고, 있다면 읽어서 반환합니다.
+    let exe_path = std::env::current_exe().expect("Failed to get current executable path");
+    let exe_dir = exe_path
+        .parent()
+        .expect("Failed to get executable directory");
+
+    let exe_properties_path = exe_dir.join("application.properties");
+    if let Ok(text) = std::fs::read_to_string(exe_properties_path) {
+        return ApplicationProperties::from_properties(text);
+    }
+
+    println!("application.properties Not Found. Use default properties.");
+
+    ApplicationProperties::default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -501,6 +934,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_http2_default() {
+        let http2 = Http2::default();
+
+        assert!(!http2.enabled);
+        assert_eq!(http2.max_concurrent_streams, Some(200));
+        assert_eq!(http2.initial_window_size, Some(65535));
+        assert_eq!(http2.keep_alive_interval, None);
+        assert_eq!(http2.keep_alive_timeout, Some(Duration::from_secs(20)));
+    }
+
+    #[test]
+    #[should_panic(expected = "allowed origins can't be \"*\" when credentials are allowed")]
+    fn test_from_properties_cors_wildcard_with_credentials() {
+        ApplicationProperties::from_properties(
+            r#"
+            server.cors.enabled=true
+            server.cors.allowed-origins=*
+            server.cors.allow-credentials=true
+            "#
+            .to_string(),
+        );
+    }
+
     #[test]
     fn test_from_properties() {
         struct TestCase {
@@ -661,6 +1118,227 @@ mod tests {
                     remove_all_env();
                 },
             },
+            TestCase {
+                name: "에러 응답 포맷 바인딩".to_string(),
+                input: r#"
+                    server.error.format=problem-json
+                    "#
+                .to_string(),
+                expected: ApplicationProperties {
+                    server: Server {
+                        error: ErrorConfig {
+                            format: ErrorFormat::ProblemJson,
+                        },
+                        ..Default::default()
+                    },
+                    environment: "dev".to_string(),
+                    etc: HashMap::new(),
+                    ..Default::default()
+                },
+                before: || {
+                    remove_all_env();
+                },
+            },
+            TestCase {
+                name: "HTTP/2 설정 바인딩".to_string(),
+                input: r#"
+                    server.http2.enabled=true
+                    server.http2.max-concurrent-streams=100
+                    server.http2.initial-window-size=1MB
+                    server.http2.keep-alive-interval=30s
+                    server.http2.keep-alive-timeout=500
+                    "#
+                .to_string(),
+                expected: ApplicationProperties {
+                    server: Server {
+                        http2: Http2 {
+                            enabled: true,
+                            max_concurrent_streams: Some(100),
+                            initial_window_size: Some(1024 * 1024),
+                            keep_alive_interval: Some(Duration::from_secs(30)),
+                            keep_alive_timeout: Some(Duration::from_millis(500)),
+                        },
+                        ..Default::default()
+                    },
+                    environment: "dev".to_string(),
+                    etc: HashMap::new(),
+                    ..Default::default()
+                },
+                before: || {
+                    remove_all_env();
+                },
+            },
+            TestCase {
+                name: "요청 본문 설정 바인딩".to_string(),
+                input: r#"
+                    server.request.body.max-length=10MB
+                    server.request.body.decompression-enabled=false
+                    "#
+                .to_string(),
+                expected: ApplicationProperties {
+                    server: Server {
+                        request: RequestConfig {
+                            body: RequestBodyConfig {
+                                max_length: 10 * 1024 * 1024,
+                                decompression_enabled: false,
+                            },
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    environment: "dev".to_string(),
+                    etc: HashMap::new(),
+                    ..Default::default()
+                },
+                before: || {
+                    remove_all_env();
+                },
+            },
+            TestCase {
+                name: "멀티파트 설정 바인딩".to_string(),
+                input: r#"
+                    server.multipart.max-file-size=1MB
+                    server.multipart.max-request-size=5MB
+                    server.multipart.file-size-threshold=0
+                    server.multipart.location=/tmp/uploads
+                    "#
+                .to_string(),
+                expected: ApplicationProperties {
+                    server: Server {
+                        multipart: Multipart {
+                            auto_parsing_enabled: true,
+                            max_file_size: 1024 * 1024,
+                            max_request_size: 5 * 1024 * 1024,
+                            file_size_threshold: 0,
+                            location: Some("/tmp/uploads".to_string()),
+                        },
+                        ..Default::default()
+                    },
+                    environment: "dev".to_string(),
+                    etc: HashMap::new(),
+                    ..Default::default()
+                },
+                before: || {
+                    remove_all_env();
+                },
+            },
+            TestCase {
+                name: "정적 파일 설정 바인딩".to_string(),
+                input: r#"
+                    server.static.enabled=true
+                    server.static.path-prefix=/assets
+                    server.static.location=./public
+                    server.static.spa-fallback=true
+                    server.static.cache-control=max-age=3600
+                    "#
+                .to_string(),
+                expected: ApplicationProperties {
+                    server: Server {
+                        static_files: StaticFiles {
+                            enabled: true,
+                            path_prefix: "/assets".to_string(),
+                            location: "./public".to_string(),
+                            index: "index.html".to_string(),
+                            spa_fallback: true,
+                            cache_control: Some("max-age=3600".to_string()),
+                        },
+                        ..Default::default()
+                    },
+                    environment: "dev".to_string(),
+                    etc: HashMap::new(),
+                    ..Default::default()
+                },
+                before: || {
+                    remove_all_env();
+                },
+            },
+            TestCase {
+                name: "CORS 설정 바인딩".to_string(),
+                input: r#"
+                    server.cors.enabled=true
+                    server.cors.allowed-origins=https://example.com, https://*.example.com
+                    server.cors.allowed-methods=GET,POST,DELETE
+                    server.cors.exposed-headers=x-total-count
+                    server.cors.allow-credentials=true
+                    server.cors.max-age=600
+                    "#
+                .to_string(),
+                expected: ApplicationProperties {
+                    server: Server {
+                        cors: CorsConfig {
+                            enabled: true,
+                            allowed_origins: vec![
+                                "https://example.com".to_string(),
+                                "https://*.example.com".to_string(),
+                            ],
+                            allowed_methods: vec![
+                                "GET".to_string(),
+                                "POST".to_string(),
+                                "DELETE".to_string(),
+                            ],
+                            allowed_headers: vec!["*".to_string()],
+                            exposed_headers: vec!["x-total-count".to_string()],
+                            allow_credentials: true,
+                            max_age: Some(600),
+                        },
+                        ..Default::default()
+                    },
+                    environment: "dev".to_string(),
+                    etc: HashMap::new(),
+                    ..Default::default()
+                },
+                before: || {
+                    remove_all_env();
+                },
+            },
+            TestCase {
+                name: "swagger 속성 바인딩".to_string(),
+                input: r#"
+                    swagger.openapi-version=3.1
+                    swagger.servers=https://api.example.com, http://localhost:3000
+                    swagger.path=/api-docs/
+                    swagger.ui.enabled=false
+                    swagger.title=Shop API
+                    swagger.version=1.2.0
+                    swagger.contact.name=API Team
+                    swagger.contact.email=api@example.com
+                    swagger.license.name=MIT
+                    swagger.license.url=https://opensource.org/licenses/MIT
+                    "#
+                .to_string(),
+                expected: ApplicationProperties {
+                    swagger: Swagger {
+                        openapi_version: OpenApiVersion::V3_1,
+                        servers: vec![
+                            "https://api.example.com".to_string(),
+                            "http://localhost:3000".to_string(),
+                        ],
+                        path: "/api-docs".to_string(),
+                        ui_enabled: false,
+                        info: crate::swagger::SwaggerInfo {
+                            title: "Shop API".to_string(),
+                            version: "1.2.0".to_string(),
+                            contact: crate::swagger::SwaggerContact {
+                                name: "API Team".to_string(),
+                                email: "api@example.com".to_string(),
+                                ..Default::default()
+                            },
+                            license: crate::swagger::SwaggerLicense {
+                                name: "MIT".to_string(),
+                                url: "https://opensource.org/licenses/MIT".to_string(),
+                            },
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    environment: "dev".to_string(),
+                    etc: HashMap::new(),
+                    ..Default::default()
+                },
+                before: || {
+                    remove_all_env();
+                },
+            },
         ];
 
         for tc in test_cases {
@@ -675,26 +1353,3 @@ mod tests {
         }
     }
 }
-
-// 알아서 모든 대상에 대해 application.properties를 읽어서 ApplicationProperties를 반환하는 함수
-pub fn load_application_properties_from_all() -> ApplicationProperties {
-    // 1. 현재 경로에 application.properties가 있는지 확인하고, 있다면 읽어서 반환합니다.
-    if let Ok(text) = std::fs::read_to_string("application.properties") {
-        return ApplicationProperties::from_properties(text);
-    }
-
-    // 2. 실행파일 경로에 application.properties가 있는지 확인하고, 있다면 읽어서 반환합니다.
-    let exe_path = std::env::current_exe().expect("Failed to get current executable path");
-    let exe_dir = exe_path
-        .parent()
-        .expect("Failed to get executable directory");
-
-    let exe_properties_path = exe_dir.join("application.properties");
-    if let Ok(text) = std::fs::read_to_string(exe_properties_path) {
-        return ApplicationProperties::from_properties(text);
-    }
-
-    println!("application.properties Not Found. Use default properties.");
-
-    ApplicationProperties::default()
-}
@@ -1,4 +1,7 @@
-use http_body_util::{BodyExt, Limited};
+use futures_util::TryStreamExt;
+use http_body_util::{BodyExt, Full, Limited};
+
+use crate::http::body::BodyStream;
 
 pub trait RequestAdapter {
     fn uri(&self) -> &hyper::Uri;
@@ -7,7 +10,13 @@ pub trait RequestAdapter {
         hyper::Version::HTTP_11
     }
     fn headers(&self) -> &hyper::HeaderMap;
+    // Returns the future of the upgraded connection, if the request can be upgraded. (ex. WebSocket)
+    fn on_upgrade(&mut self) -> Option<hyper::upgrade::OnUpgrade> {
+        None
+    }
     async fn body(self, limit: usize) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>>;
+    // The body is read chunk by chunk, instead of all at once.
+    fn into_body_stream(self, limit: Option<usize>) -> BodyStream;
 }
 
 pub struct HyperRequest(pub hyper::Request<hyper::body::Incoming>);
@@ -29,6 +38,17 @@ impl RequestAdapter for HyperRequest {
         self.0.headers()
     }
 
+    fn on_upgrade(&mut self) -> Option<hyper::upgrade::OnUpgrade> {
+        // Only HTTP/1.1 connections can be upgraded.
+        if self.0.version() != hyper::Version::HTTP_11
+            || !self.0.headers().contains_key(hyper::header::UPGRADE)
+        {
+            return None;
+        }
+
+        Some(hyper::upgrade::on(&mut self.0))
+    }
+
     async fn body(self, limit: usize) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let limited_request_body_stream = Limited::new(self.0, limit);
 
@@ -36,6 +56,16 @@ impl RequestAdapter for HyperRequest {
 
         Ok(bytes.to_bytes().to_vec())
     }
+
+    fn into_body_stream(self, limit: Option<usize>) -> BodyStream {
+        let stream = self
+            .0
+            .into_body()
+            .into_data_stream()
+            .map_err(|error| error.into());
+
+        BodyStream::new(stream, limit)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -70,4 +100,44 @@ impl RequestAdapter for AWSLambdaRequest {
     ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         Ok(self.body)
     }
+
+    fn into_body_stream(self, limit: Option<usize>) -> BodyStream {
+        BodyStream::from_bytes(self.body, limit)
+    }
+}
+
+// Request that is not received from a socket. (ex. [crate::test::TestClient])
+#[derive(Debug, Clone)]
+pub(crate) struct InMemoryRequest {
+    pub uri: hyper::Uri,
+    pub method: hyper::Method,
+    pub headers: hyper::HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl RequestAdapter for InMemoryRequest {
+    fn uri(&self) -> &hyper::Uri {
+        &self.uri
+    }
+
+    fn method(&self) -> &hyper::Method {
+        &self.method
+    }
+
+    fn headers(&self) -> &hyper::HeaderMap {
+        &self.headers
+    }
+
+    async fn body(self, limit: usize) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        // The limit is applied in the same way as the hyper request.
+        let limited_request_body = Limited::new(Full::new(bytes::Bytes::from(self.body)), limit);
+
+        let bytes = limited_request_body.collect().await?;
+
+        Ok(bytes.to_bytes().to_vec())
+    }
+
+    fn into_body_stream(self, limit: Option<usize>) -> BodyStream {
+        BodyStream::from_bytes(self.body, limit)
+    }
 }
@@ -9,7 +9,7 @@ use tokio_rustls::{
 use crate::application_properties::ApplicationProperties;
 
 fn error(err: String) -> io::Error {
-    io::Error::new(io::ErrorKind::Other, err)
+    io::Error::other(err)
 }
 
 fn load_certs(filename: &str) -> io::Result<Vec<CertificateDer<'static>>> {
@@ -47,6 +47,10 @@ pub fn new_tls_acceptor(
 
     server_config.alpn_protocols = vec![b"http/1.1".to_vec(), b"http/1.0".to_vec()];
 
+    if application_properties.server.http2.enabled {
+        server_config.alpn_protocols.insert(0, b"h2".to_vec());
+    }
+
     let tls_acceptor = TlsAcceptor::from(Arc::new(server_config));
 
     Ok(tls_acceptor)
@@ -1,6 +1,8 @@
-pub fn compress_with_gzip(body: &[u8]) -> anyhow::Result<Vec<u8>> {
-    use std::io::Write;
+use std::io::{Read, Write};
+
+use crate::{application_properties::CompressionAlgorithm, error::Errors};
 
+pub fn compress_with_gzip(body: &[u8]) -> anyhow::Result<Vec<u8>> {
     let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
     encoder.write_all(body)?;
     let compressed = encoder.finish()?;
@@ -9,8 +11,6 @@ pub fn compress_with_gzip(body: &[u8]) -> anyhow::Result<Vec<u8>> {
 }
 
 pub fn compress_with_deflate(body: &[u8]) -> anyhow::Result<Vec<u8>> {
-    use std::io::Write;
-
     let mut encoder =
         flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
     encoder.write_all(body)?;
@@ -18,3 +18,507 @@ pub fn compress_with_deflate(body: &[u8]) -> anyhow::Result<Vec<u8>> {
 
     Ok(compressed)
 }
+
+pub fn compress_with_brotli(body: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut encoder = new_brotli_encoder();
+    encoder.write_all(body)?;
+    let compressed = encoder.into_inner();
+
+    Ok(compressed)
+}
+
+pub fn compress_with_zstd(body: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let compressed = zstd::stream::encode_all(body, zstd::DEFAULT_COMPRESSION_LEVEL)?;
+
+    Ok(compressed)
+}
+
+pub fn compress(algorithm: &CompressionAlgorithm, body: &[u8]) -> anyhow::Result<Vec<u8>> {
+    match algorithm {
+        CompressionAlgorithm::Gzip => compress_with_gzip(body),
+        CompressionAlgorithm::Deflate => compress_with_deflate(body),
+        CompressionAlgorithm::Brotli => compress_with_brotli(body),
+        CompressionAlgorithm::Zstd => compress_with_zstd(body),
+        CompressionAlgorithm::Unknown(algorithm) => Err(anyhow::anyhow!(
+            "unknown compression algorithm: {}",
+            algorithm
+        )),
+    }
+}
+
+// quality 4 is much faster than the default(11), and still compresses better than gzip.
+fn new_brotli_encoder() -> brotli::CompressorWriter<Vec<u8>> {
+    brotli::CompressorWriter::new(Vec::new(), 4096, 4, 22)
+}
+
+/// Choose the compression algorithm for the request's `Accept-Encoding` header.
+/// - The algorithm with the highest q-value is chosen.
+/// - If the q-values are the same, the configured algorithm comes first, then br, zstd, gzip and deflate.
+/// - Returns None if the client does not accept any of them. (identity)
+pub fn negotiate_algorithm(
+    accept_encoding: &str,
+    preferred: &CompressionAlgorithm,
+) -> Option<CompressionAlgorithm> {
+    // (name, q-value)
+    let accepted = accept_encoding
+        .split(',')
+        .filter_map(|e| {
+            let mut parts = e.split(';').map(|e| e.trim());
+
+            let name = parts.next().filter(|e| !e.is_empty())?.to_ascii_lowercase();
+            let q = parts
+                .find_map(|e| e.strip_prefix("q="))
+                .map(|q| q.parse::<f32>().unwrap_or(0.0))
+                .unwrap_or(1.0);
+
+            Some((name, q))
+        })
+        .collect::<Vec<_>>();
+
+    let q_value_of = |algorithm: &CompressionAlgorithm| {
+        let name = algorithm.to_string();
+
+        accepted
+            .iter()
+            .find(|(e, _)| *e == name)
+            .or_else(|| accepted.iter().find(|(e, _)| e == "*"))
+            .map(|(_, q)| *q)
+            .unwrap_or(0.0)
+    };
+
+    let candidates = [
+        preferred.clone(),
+        CompressionAlgorithm::Brotli,
+        CompressionAlgorithm::Zstd,
+        CompressionAlgorithm::Gzip,
+        CompressionAlgorithm::Deflate,
+    ];
+
+    let mut selected: Option<(CompressionAlgorithm, f32)> = None;
+    for candidate in candidates {
+        if matches!(candidate, CompressionAlgorithm::Unknown(_)) {
+            continue;
+        }
+
+        let q = q_value_of(&candidate);
+
+        if q > 0.0
+            && selected
+                .as_ref()
+                .is_none_or(|(_, selected_q)| q > *selected_q)
+        {
+            selected = Some((candidate, q));
+        }
+    }
+
+    selected.map(|(algorithm, _)| algorithm)
+}
+
+/// Compressor for streaming responses.
+/// Each chunk is flushed, so that the client can decode it without waiting for the end of the stream. (ex. SSE)
+pub enum StreamEncoder {
+    Gzip(flate2::write::GzEncoder<Vec<u8>>),
+    Deflate(flate2::write::DeflateEncoder<Vec<u8>>),
+    Brotli(Box<brotli::CompressorWriter<Vec<u8>>>),
+    Zstd(zstd::stream::write::Encoder<'static, Vec<u8>>),
+}
+
+impl StreamEncoder {
+    pub fn new(algorithm: &CompressionAlgorithm) -> Option<Self> {
+        let encoder = match algorithm {
+            CompressionAlgorithm::Gzip => StreamEncoder::Gzip(flate2::write::GzEncoder::new(
+                Vec::new(),
+                flate2::Compression::default(),
+            )),
+            CompressionAlgorithm::Deflate => StreamEncoder::Deflate(
+                flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default()),
+            ),
+            CompressionAlgorithm::Brotli => StreamEncoder::Brotli(Box::new(new_brotli_encoder())),
+            CompressionAlgorithm::Zstd => StreamEncoder::Zstd(
+                zstd::stream::write::Encoder::new(Vec::new(), zstd::DEFAULT_COMPRESSION_LEVEL)
+                    .ok()?,
+            ),
+            CompressionAlgorithm::Unknown(_) => return None,
+        };
+
+        Some(encoder)
+    }
+
+    /// Compress the chunk, and returns the compressed bytes written so far.
+    pub fn encode(&mut self, chunk: &[u8]) -> std::io::Result<Vec<u8>> {
+        let buffer = match self {
+            StreamEncoder::Gzip(encoder) => {
+                encoder.write_all(chunk)?;
+                encoder.flush()?;
+                encoder.get_mut()
+            }
+            StreamEncoder::Deflate(encoder) => {
+                encoder.write_all(chunk)?;
+                encoder.flush()?;
+                encoder.get_mut()
+            }
+            StreamEncoder::Brotli(encoder) => {
+                encoder.write_all(chunk)?;
+                encoder.flush()?;
+                encoder.get_mut()
+            }
+            StreamEncoder::Zstd(encoder) => {
+                encoder.write_all(chunk)?;
+                encoder.flush()?;
+                encoder.get_mut()
+            }
+        };
+
+        Ok(std::mem::take(buffer))
+    }
+
+    /// Finish the stream, and returns the remaining compressed bytes.
+    pub fn finish(self) -> std::io::Result<Vec<u8>> {
+        match self {
+            StreamEncoder::Gzip(encoder) => encoder.finish(),
+            StreamEncoder::Deflate(encoder) => encoder.finish(),
+            StreamEncoder::Brotli(encoder) => Ok(encoder.into_inner()),
+            StreamEncoder::Zstd(encoder) => encoder.finish(),
+        }
+    }
+}
+
+/// Decompress the request body according to the `Content-Encoding` header.
+/// - Multiple encodings (ex. `gzip, br`) are decoded in the reverse order of application.
+/// - The limit applies to the decompressed size, to guard against zip bombs.
+pub fn decompress_request_body(
+    content_encoding: &str,
+    body: Vec<u8>,
+    limit: usize,
+) -> Result<Vec<u8>, Errors> {
+    let mut body = body;
+
+    for encoding in content_encoding.rsplit(',') {
+        let encoding = encoding.trim().to_ascii_lowercase();
+
+        let decoder: Box<dyn Read + '_> = match encoding.as_str() {
+            "" | "identity" => continue,
+            "gzip" | "x-gzip" => Box::new(flate2::read::MultiGzDecoder::new(&body[..])),
+            // "deflate" is zlib format, but some clients send raw deflate.
+            "deflate" if is_zlib_header(&body) => {
+                Box::new(flate2::read::ZlibDecoder::new(&body[..]))
+            }
+            "deflate" => Box::new(flate2::read::DeflateDecoder::new(&body[..])),
+            "br" => Box::new(brotli::Decompressor::new(&body[..], 4096)),
+            "zstd" => Box::new(
+                zstd::stream::read::Decoder::new(&body[..])
+                    .map_err(|e| Errors::DecompressionFailed(e.to_string()))?,
+            ),
+            _ => return Err(Errors::UnsupportedContentEncoding(encoding)),
+        };
+
+        // Read one more byte than the limit to detect overflow.
+        let mut decompressed = vec![];
+        decoder
+            .take(limit as u64 + 1)
+            .read_to_end(&mut decompressed)
+            .map_err(|e| Errors::DecompressionFailed(e.to_string()))?;
+
+        if decompressed.len() > limit {
+            return Err(Errors::PayloadTooLarge);
+        }
+
+        body = decompressed;
+    }
+
+    Ok(body)
+}
+
+// "identity", or no encoding at all
+pub fn is_identity_encoding(content_encoding: &str) -> bool {
+    content_encoding.split(',').all(|e| {
+        let e = e.trim();
+        e.is_empty() || e.eq_ignore_ascii_case("identity")
+    })
+}
+
+// CMF (compression method 8) + FLG, and the check bits. (RFC 1950)
+fn is_zlib_header(body: &[u8]) -> bool {
+    match body {
+        [cmf, flg, ..] => cmf & 0x0f == 8 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_algorithm() {
+        struct TestCase {
+            name: String,
+            accept_encoding: String,
+            preferred: CompressionAlgorithm,
+            expected: Option<CompressionAlgorithm>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "설정된 알고리즘 우선".to_string(),
+                accept_encoding: "gzip, deflate, br, zstd".to_string(),
+                preferred: CompressionAlgorithm::Gzip,
+                expected: Some(CompressionAlgorithm::Gzip),
+            },
+            TestCase {
+                name: "설정된 알고리즘을 지원하지 않음".to_string(),
+                accept_encoding: "deflate, br".to_string(),
+                preferred: CompressionAlgorithm::Gzip,
+                expected: Some(CompressionAlgorithm::Brotli),
+            },
+            TestCase {
+                name: "q-value가 높은 쪽 선택".to_string(),
+                accept_encoding: "gzip;q=0.5, zstd;q=0.9".to_string(),
+                preferred: CompressionAlgorithm::Gzip,
+                expected: Some(CompressionAlgorithm::Zstd),
+            },
+            TestCase {
+                name: "q=0은 거부".to_string(),
+                accept_encoding: "gzip;q=0, deflate".to_string(),
+                preferred: CompressionAlgorithm::Gzip,
+                expected: Some(CompressionAlgorithm::Deflate),
+            },
+            TestCase {
+                name: "와일드카드".to_string(),
+                accept_encoding: "*".to_string(),
+                preferred: CompressionAlgorithm::Zstd,
+                expected: Some(CompressionAlgorithm::Zstd),
+            },
+            TestCase {
+                name: "와일드카드와 명시적 거부".to_string(),
+                accept_encoding: "br;q=0, *;q=0.5".to_string(),
+                preferred: CompressionAlgorithm::Brotli,
+                expected: Some(CompressionAlgorithm::Zstd),
+            },
+            TestCase {
+                name: "지원하는 알고리즘 없음".to_string(),
+                accept_encoding: "identity".to_string(),
+                preferred: CompressionAlgorithm::Gzip,
+                expected: None,
+            },
+            TestCase {
+                name: "빈 헤더".to_string(),
+                accept_encoding: "".to_string(),
+                preferred: CompressionAlgorithm::Gzip,
+                expected: None,
+            },
+        ];
+
+        for tc in test_cases {
+            assert_eq!(
+                negotiate_algorithm(&tc.accept_encoding, &tc.preferred),
+                tc.expected,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_encoder() {
+        let chunks = ["data: hello\n\n", "data: world\n\n"];
+
+        for algorithm in [
+            CompressionAlgorithm::Gzip,
+            CompressionAlgorithm::Deflate,
+            CompressionAlgorithm::Brotli,
+            CompressionAlgorithm::Zstd,
+        ] {
+            let mut encoder = StreamEncoder::new(&algorithm).unwrap();
+
+            let mut compressed = vec![];
+            for chunk in chunks {
+                let encoded = encoder.encode(chunk.as_bytes()).unwrap();
+                // flushed for each chunk
+                assert!(!encoded.is_empty(), "{:?} failed", algorithm);
+
+                compressed.extend(encoded);
+            }
+            compressed.extend(encoder.finish().unwrap());
+
+            let mut decompressed = vec![];
+            match algorithm {
+                CompressionAlgorithm::Gzip => {
+                    std::io::Read::read_to_end(
+                        &mut flate2::read::GzDecoder::new(&compressed[..]),
+                        &mut decompressed,
+                    )
+                    .unwrap();
+                }
+                CompressionAlgorithm::Deflate => {
+                    std::io::Read::read_to_end(
+                        &mut flate2::read::DeflateDecoder::new(&compressed[..]),
+                        &mut decompressed,
+                    )
+                    .unwrap();
+                }
+                CompressionAlgorithm::Brotli => {
+                    brotli::BrotliDecompress(&mut &compressed[..], &mut decompressed).unwrap();
+                }
+                CompressionAlgorithm::Zstd => {
+                    decompressed = zstd::stream::decode_all(&compressed[..]).unwrap();
+                }
+                CompressionAlgorithm::Unknown(_) => unreachable!(),
+            }
+
+            assert_eq!(
+                String::from_utf8(decompressed).unwrap(),
+                chunks.concat(),
+                "{:?} failed",
+                algorithm
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_identity_encoding() {
+        struct TestCase {
+            name: String,
+            content_encoding: String,
+            expected: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "빈 값".to_string(),
+                content_encoding: "".to_string(),
+                expected: true,
+            },
+            TestCase {
+                name: "identity".to_string(),
+                content_encoding: "Identity".to_string(),
+                expected: true,
+            },
+            TestCase {
+                name: "gzip".to_string(),
+                content_encoding: "gzip".to_string(),
+                expected: false,
+            },
+            TestCase {
+                name: "identity와 gzip".to_string(),
+                content_encoding: "identity, gzip".to_string(),
+                expected: false,
+            },
+        ];
+
+        for tc in test_cases {
+            assert_eq!(
+                is_identity_encoding(&tc.content_encoding),
+                tc.expected,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_decompress_request_body() {
+        let body = r#"{"message":"hello"}"#.repeat(100);
+
+        let zlib = {
+            let mut encoder =
+                flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(body.as_bytes()).unwrap();
+            encoder.finish().unwrap()
+        };
+
+        struct TestCase {
+            name: String,
+            content_encoding: String,
+            body: Vec<u8>,
+            limit: usize,
+            expected: Result<String, String>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "gzip".to_string(),
+                content_encoding: "gzip".to_string(),
+                body: compress_with_gzip(body.as_bytes()).unwrap(),
+                limit: 1024 * 1024,
+                expected: Ok(body.clone()),
+            },
+            TestCase {
+                name: "deflate (zlib)".to_string(),
+                content_encoding: "deflate".to_string(),
+                body: zlib,
+                limit: 1024 * 1024,
+                expected: Ok(body.clone()),
+            },
+            TestCase {
+                name: "deflate (raw)".to_string(),
+                content_encoding: "deflate".to_string(),
+                body: compress_with_deflate(body.as_bytes()).unwrap(),
+                limit: 1024 * 1024,
+                expected: Ok(body.clone()),
+            },
+            TestCase {
+                name: "br".to_string(),
+                content_encoding: "br".to_string(),
+                body: compress_with_brotli(body.as_bytes()).unwrap(),
+                limit: 1024 * 1024,
+                expected: Ok(body.clone()),
+            },
+            TestCase {
+                name: "zstd".to_string(),
+                content_encoding: "zstd".to_string(),
+                body: compress_with_zstd(body.as_bytes()).unwrap(),
+                limit: 1024 * 1024,
+                expected: Ok(body.clone()),
+            },
+            TestCase {
+                name: "여러 인코딩은 역순으로 해제".to_string(),
+                content_encoding: "gzip, br".to_string(),
+                body: compress_with_brotli(&compress_with_gzip(body.as_bytes()).unwrap()).unwrap(),
+                limit: 1024 * 1024,
+                expected: Ok(body.clone()),
+            },
+            TestCase {
+                name: "identity".to_string(),
+                content_encoding: "identity".to_string(),
+                body: body.as_bytes().to_vec(),
+                limit: 1024 * 1024,
+                expected: Ok(body.clone()),
+            },
+            TestCase {
+                name: "해제된 크기가 제한을 넘음".to_string(),
+                content_encoding: "gzip".to_string(),
+                body: compress_with_gzip(&vec![0; 10 * 1024 * 1024]).unwrap(),
+                limit: 1024 * 1024,
+                expected: Err("Payload Too Large".to_string()),
+            },
+            TestCase {
+                name: "지원하지 않는 인코딩".to_string(),
+                content_encoding: "compress".to_string(),
+                body: body.as_bytes().to_vec(),
+                limit: 1024 * 1024,
+                expected: Err("Unsupported Content-Encoding: compress".to_string()),
+            },
+            TestCase {
+                name: "손상된 데이터".to_string(),
+                content_encoding: "gzip".to_string(),
+                body: body.as_bytes().to_vec(),
+                limit: 1024 * 1024,
+                expected: Err("Failed to decompress request body".to_string()),
+            },
+        ];
+
+        for tc in test_cases {
+            let got = decompress_request_body(&tc.content_encoding, tc.body, tc.limit)
+                .map(|e| String::from_utf8(e).unwrap())
+                .map_err(|e| e.to_string());
+
+            match (&got, &tc.expected) {
+                (Err(got), Err(expected)) => {
+                    assert!(got.starts_with(expected), "{} failed: {}", tc.name, got)
+                }
+                _ => assert_eq!(got, tc.expected, "{} failed", tc.name),
+            }
+        }
+    }
+}
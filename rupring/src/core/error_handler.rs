@@ -1,10 +1,93 @@
 use http_body_util::combinators::BoxBody;
 use http_body_util::BodyExt;
+use std::any::Any;
 use std::convert::Infallible;
 use std::error::Error;
 use tokio::time::error::Elapsed;
 
-use crate::response::BoxedResponseBody;
+use crate::application_properties::ErrorFormat;
+use crate::core::ConnectionContext;
+use crate::error::{find_error_response, ErrorHandlerFunction, Errors, HttpError};
+use crate::response::{BoxedResponseBody, ProblemDetail};
+
+pub(crate) type DefaultErrorHandler =
+    fn() -> Result<hyper::Response<BoxedResponseBody>, Infallible>;
+
+// Use the response of the registered error handler if there is one, otherwise the default response.
+pub(crate) fn handle_error(
+    error_handlers: &[ErrorHandlerFunction],
+    error: HttpError,
+    error_format: ErrorFormat,
+    connection_context: &ConnectionContext,
+    default_handler: impl FnOnce() -> Result<hyper::Response<BoxedResponseBody>, Infallible>,
+) -> Result<hyper::Response<BoxedResponseBody>, Infallible> {
+    match find_error_response(error_handlers, &error) {
+        Some(response) => Ok(response.into_hyper_response(connection_context)),
+        None => match error_format {
+            ErrorFormat::ProblemJson => {
+                Ok(problem_response(&error).into_hyper_response(connection_context))
+            }
+            ErrorFormat::Text => default_handler(),
+        },
+    }
+}
+
+// Errors returned from handlers (or panics) are converted to crate::Response, since they go through post-processing.
+pub(crate) fn handle_response_error(
+    error_handlers: &[ErrorHandlerFunction],
+    error: HttpError,
+    error_format: ErrorFormat,
+) -> crate::Response {
+    if let Some(response) = find_error_response(error_handlers, &error) {
+        return response;
+    }
+
+    if error_format == ErrorFormat::ProblemJson {
+        return problem_response(&error);
+    }
+
+    if let Some(Errors::ValidationFailed(validation_errors)) = error.downcast_ref::<Errors>() {
+        return crate::Response::new()
+            .status(error.status)
+            .json(validation_errors);
+    }
+
+    let reason = hyper::StatusCode::from_u16(error.status)
+        .ok()
+        .and_then(|status| status.canonical_reason())
+        .unwrap_or("Internal Server Error");
+
+    crate::Response::new().status(error.status).text(reason)
+}
+
+// Default response for `server.error.format=problem-json`.
+// The error message is only exposed for client errors.
+pub(crate) fn problem_response(error: &HttpError) -> crate::Response {
+    let mut problem = ProblemDetail::new(error.status).instance(&error.path);
+
+    let message = error.error.to_string();
+    if error.status < 500 && message != problem.title {
+        problem = problem.detail(message);
+    }
+
+    if let Some(Errors::ValidationFailed(validation_errors)) = error.downcast_ref::<Errors>() {
+        problem = problem.extension("errors", &validation_errors.errors);
+    }
+
+    crate::Response::new().problem(problem)
+}
+
+pub(crate) fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    if let Some(message) = panic.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    "panic".to_string()
+}
 
 pub(crate) fn default_404_handler() -> Result<hyper::Response<BoxedResponseBody>, Infallible> {
     let mut response: hyper::Response<BoxedResponseBody> = hyper::Response::builder()
@@ -18,6 +101,47 @@ pub(crate) fn default_404_handler() -> Result<hyper::Response<BoxedResponseBody>
     Ok::<hyper::Response<BoxedResponseBody>, Infallible>(response)
 }
 
+pub(crate) fn default_method_not_allowed_handler(
+    allowed_methods: &[hyper::Method],
+) -> Result<hyper::Response<BoxedResponseBody>, Infallible> {
+    let mut response: hyper::Response<BoxedResponseBody> = hyper::Response::builder()
+        .header(hyper::header::ALLOW, allow_header_value(allowed_methods))
+        .body(BodyExt::boxed(BoxBody::new(
+            "Method Not Allowed".to_string(),
+        )))
+        .unwrap();
+
+    if let Ok(status) = hyper::StatusCode::from_u16(405) {
+        *response.status_mut() = status;
+    }
+
+    Ok(response)
+}
+
+// Automatic response for OPTIONS requests without an OPTIONS route.
+pub(crate) fn default_options_handler(
+    allowed_methods: &[hyper::Method],
+) -> Result<hyper::Response<BoxedResponseBody>, Infallible> {
+    let mut response: hyper::Response<BoxedResponseBody> = hyper::Response::builder()
+        .header(hyper::header::ALLOW, allow_header_value(allowed_methods))
+        .body(BodyExt::boxed(BoxBody::new(String::new())))
+        .unwrap();
+
+    if let Ok(status) = hyper::StatusCode::from_u16(204) {
+        *response.status_mut() = status;
+    }
+
+    Ok(response)
+}
+
+pub(crate) fn allow_header_value(allowed_methods: &[hyper::Method]) -> String {
+    allowed_methods
+        .iter()
+        .map(|method| method.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 pub(crate) fn default_payload_too_large_handler(
 ) -> Result<hyper::Response<BoxedResponseBody>, Infallible> {
     let mut response: hyper::Response<BoxedResponseBody> = hyper::Response::builder()
@@ -33,6 +157,34 @@ pub(crate) fn default_payload_too_large_handler(
     Ok(response)
 }
 
+pub(crate) fn default_unsupported_content_encoding_handler(
+) -> Result<hyper::Response<BoxedResponseBody>, Infallible> {
+    let mut response: hyper::Response<BoxedResponseBody> = hyper::Response::builder()
+        .body(BodyExt::boxed(BoxBody::new(
+            "Unsupported Media Type".to_string(),
+        )))
+        .unwrap();
+
+    if let Ok(status) = hyper::StatusCode::from_u16(415) {
+        *response.status_mut() = status;
+    }
+
+    Ok(response)
+}
+
+pub(crate) fn default_decompression_failed_handler(
+) -> Result<hyper::Response<BoxedResponseBody>, Infallible> {
+    let mut response: hyper::Response<BoxedResponseBody> = hyper::Response::builder()
+        .body(BodyExt::boxed(BoxBody::new("Bad Request".to_string())))
+        .unwrap();
+
+    if let Ok(status) = hyper::StatusCode::from_u16(400) {
+        *response.status_mut() = status;
+    }
+
+    Ok(response)
+}
+
 pub(crate) fn default_uri_too_long_handler(
 ) -> Result<hyper::Response<BoxedResponseBody>, Infallible> {
     let mut response: hyper::Response<BoxedResponseBody> = hyper::Response::builder()
@@ -107,3 +259,92 @@ pub(crate) fn default_join_error_handler(
 
     Ok::<hyper::Response<BoxedResponseBody>, Infallible>(response)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::{FieldError, ValidationErrors};
+    use crate::Method;
+
+    #[test]
+    fn test_problem_response() {
+        struct TestCase {
+            name: String,
+            error: HttpError,
+            expected: ProblemDetail,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "404 Not Found".to_string(),
+                error: HttpError::new(404, Method::GET, "/asdf", Errors::NotFound.into()),
+                expected: ProblemDetail::new(404).instance("/asdf"),
+            },
+            TestCase {
+                name: "바인딩 실패는 detail 포함".to_string(),
+                error: HttpError::new(
+                    400,
+                    Method::POST,
+                    "/users",
+                    Errors::BindFailed("missing field `name`".to_string()).into(),
+                ),
+                expected: ProblemDetail::new(400)
+                    .instance("/users")
+                    .detail("Bad Request: missing field `name`"),
+            },
+            TestCase {
+                name: "검증 실패는 필드 에러 목록 포함".to_string(),
+                error: HttpError::new(
+                    400,
+                    Method::POST,
+                    "/users",
+                    Errors::ValidationFailed(ValidationErrors {
+                        errors: vec![FieldError {
+                            field: "age".to_string(),
+                            message: "must be greater than or equal to 14".to_string(),
+                        }],
+                    })
+                    .into(),
+                ),
+                expected: ProblemDetail::new(400)
+                    .instance("/users")
+                    .detail("Validation Failed: age: must be greater than or equal to 14")
+                    .extension(
+                        "errors",
+                        vec![FieldError {
+                            field: "age".to_string(),
+                            message: "must be greater than or equal to 14".to_string(),
+                        }],
+                    ),
+            },
+            TestCase {
+                name: "500 에러는 detail 제외".to_string(),
+                error: HttpError::new(
+                    500,
+                    Method::GET,
+                    "/users/1",
+                    Errors::Panic("secret".to_string()).into(),
+                ),
+                expected: ProblemDetail::new(500).instance("/users/1"),
+            },
+        ];
+
+        for tc in test_cases {
+            let response = problem_response(&tc.error);
+
+            assert_eq!(response.status, tc.expected.status, "{} failed", tc.name);
+            assert_eq!(
+                response
+                    .headers
+                    .get(&crate::HeaderName::from_static(crate::header::CONTENT_TYPE)),
+                Some(&vec![crate::http::meme::PROBLEM_JSON.to_string()]),
+                "{} failed",
+                tc.name
+            );
+
+            let problem: ProblemDetail =
+                serde_json::from_slice(&response.data.into_bytes()).unwrap();
+            assert_eq!(problem, tc.expected, "{} failed", tc.name);
+        }
+    }
+}
@@ -3,7 +3,7 @@ mod banner;
 pub mod boot;
 pub(crate) mod bootings;
 mod compression;
-mod error_handler;
+pub(crate) mod error_handler;
 mod graceful;
 mod parse;
 pub(crate) mod stream;
@@ -13,29 +13,51 @@ use bootings::aws_lambda::LambdaRequestEvent;
 
 #[cfg(feature = "tls")]
 use bootings::tls;
+use error_handler::allow_header_value;
 use error_handler::default_404_handler;
+use error_handler::default_decompression_failed_handler;
 use error_handler::default_header_fields_to_large;
 use error_handler::default_header_size_too_big;
 use error_handler::default_join_error_handler;
+use error_handler::default_method_not_allowed_handler;
+use error_handler::default_options_handler;
 use error_handler::default_payload_too_large_handler;
 use error_handler::default_timeout_handler;
+use error_handler::default_unsupported_content_encoding_handler;
 use error_handler::default_uri_too_long_handler;
+use error_handler::handle_error;
+use error_handler::handle_response_error;
+use error_handler::panic_message;
+use error_handler::problem_response;
+use error_handler::DefaultErrorHandler;
 use http_body_util::combinators::BoxBody;
 use http_body_util::BodyExt;
+use http_body_util::LengthLimitError;
 use http_body_util::StreamBody;
 use tokio::time::Instant;
 
 use crate::application_properties;
 use crate::application_properties::ApplicationProperties;
 use crate::application_properties::CompressionAlgorithm;
+use crate::application_properties::ErrorFormat;
 use crate::core::adapter::HyperRequest;
+use crate::core::adapter::InMemoryRequest;
 use crate::core::adapter::RequestAdapter;
 use crate::core::stream::StreamChannelType;
 use crate::core::stream::StreamHandler;
+use crate::core::stream::STREAM_CHANNEL_CAPACITY;
 use crate::di;
+use crate::error::find_error_response;
+use crate::error::Errors;
+use crate::error::HttpError;
 use crate::header;
+use crate::http::body::BodyStream;
+use crate::http::body::TakeOnce;
 use crate::http::cookie;
+use crate::http::cors::CorsConfig;
 use crate::http::multipart;
+use crate::http::static_files;
+use crate::http::websocket;
 use crate::request::Metadata;
 use crate::response::BoxedResponseBody;
 use crate::response::ResponseData;
@@ -45,15 +67,18 @@ pub(crate) mod route;
 use std::collections::HashMap;
 use std::convert::Infallible;
 use std::net::IpAddr;
+use std::panic::AssertUnwindSafe;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use std::vec;
 
+use futures_util::FutureExt;
 use hyper::body::Bytes;
 use hyper::service::service_fn;
 
-use hyper_util::rt::TokioIo;
+use hyper_util::rt::{TokioExecutor, TokioIo, TokioTimer};
+use hyper_util::server::conn::auto;
 use log::Level;
 use tokio::net::TcpListener;
 
@@ -67,6 +92,10 @@ pub(crate) struct ConnectionContext {
     pub closed: Arc<AtomicBool>,
     pub ip: IpAddr,
     pub running_task_count: Arc<AtomicU64>,
+    // false while the server is shutting down gracefully
+    pub service_available: Arc<AtomicBool>,
+    // The connection is handed over to a WebSocket session, so it is not closed when the HTTP connection ends.
+    pub upgraded: Arc<AtomicBool>,
 }
 
 pub async fn run_server(
@@ -78,12 +107,19 @@ pub async fn run_server(
     di_context.initialize(Box::new(root_module.clone()));
     let di_context = Arc::new(di_context);
 
-    // 2. Prepare Swagger Serving, if enabled
+    // 2. Build the router once from the module tree
+    let router = Arc::new(route::Router::from_module(
+        Box::new(root_module.clone()),
+        &application_properties,
+    ));
+
+    // 3. Prepare Swagger Serving, if enabled
     if let Some(swagger_context) = di_context.get::<SwaggerContext>() {
-        swagger_context.initialize_from_module(root_module.clone());
+        swagger_context
+            .initialize_from_module_with_properties(root_module.clone(), &application_properties);
     }
 
-    // 3. ready, set, go!
+    // 4. ready, set, go!
     banner::print_banner(&application_properties);
 
     let socket_address = application_properties.server.make_address()?;
@@ -97,7 +133,7 @@ pub async fn run_server(
 
     let application_properties = Arc::new(application_properties);
 
-    // 4. for graceful shutdown
+    // 5. for graceful shutdown
     let service_avaliable = Arc::new(AtomicBool::new(true));
     let is_graceful_shutdown = application_properties.server.is_graceful_shutdown();
     let running_task_count = Arc::new(AtomicU64::new(0));
@@ -111,6 +147,11 @@ pub async fn run_server(
     }
 
     let keep_alive = application_properties.server.http1.keep_alive.to_owned();
+    let http2 = application_properties.server.http2.to_owned();
+
+    if http2.enabled {
+        print_system_log(Level::Info, "HTTP/2 Enabled");
+    }
 
     #[cfg(feature = "tls")]
     let tls_acceptor = {
@@ -119,7 +160,7 @@ pub async fn run_server(
         tls::new_tls_acceptor(&application_properties)?
     };
 
-    // 5. Main Server Loop
+    // 6. Main Server Loop
     // Spawns a new async Task for each request.
     loop {
         let (mut tcp_stream, _) = listener.accept().await?;
@@ -141,7 +182,7 @@ pub async fn run_server(
         // copy for each request
         let di_context = Arc::clone(&di_context);
         let application_properties = Arc::clone(&application_properties);
-        let root_module = root_module.clone();
+        let router = Arc::clone(&router);
 
         let max_number_of_headers = application_properties
             .server
@@ -149,18 +190,23 @@ pub async fn run_server(
             .header
             .max_number_of_headers;
 
+        let http2 = http2.clone();
+
         // for Graceful Shutdown
         let running_task_count = Arc::clone(&running_task_count);
+        let service_avaliable = Arc::clone(&service_avaliable);
 
         #[cfg(feature = "tls")]
         let tls_acceptor = tls_acceptor.clone();
 
-        // 6. create tokio task per HTTP request
+        // 7. create tokio task per HTTP request
         tokio::task::spawn(async move {
             let _connection_context = ConnectionContext {
                 closed: Arc::new(AtomicBool::new(false)),
                 ip,
                 running_task_count: Arc::clone(&running_task_count),
+                service_available: service_avaliable,
+                upgraded: Arc::new(AtomicBool::new(false)),
             };
 
             let connection_context = _connection_context.clone();
@@ -169,7 +215,7 @@ pub async fn run_server(
                 handle_http_connection(
                     Arc::clone(&application_properties),
                     Arc::clone(&di_context),
-                    root_module.clone(),
+                    Arc::clone(&router),
                     request,
                     connection_context.clone(),
                 )
@@ -193,7 +239,46 @@ pub async fn run_server(
             #[cfg(not(feature = "tls"))]
             let io = TokioIo::new(tcp_stream);
 
-            {
+            if http2.enabled {
+                // HTTP/1.1 and HTTP/2 (h2, h2c with prior knowledge) are detected per connection.
+                let mut http_builder = auto::Builder::new(TokioExecutor::new());
+
+                {
+                    let mut http1_builder = http_builder.http1();
+
+                    if let Some(max_number_of_headers) = max_number_of_headers {
+                        http1_builder.max_headers(max_number_of_headers);
+                    }
+
+                    if keep_alive {
+                        http1_builder.keep_alive(keep_alive);
+                    }
+                }
+
+                {
+                    let mut http2_builder = http_builder.http2();
+
+                    http2_builder
+                        .timer(TokioTimer::new())
+                        .initial_stream_window_size(http2.initial_window_size)
+                        .keep_alive_interval(http2.keep_alive_interval);
+
+                    // hyper removes the limit for None, so only a configured limit is set.
+                    if let Some(max_concurrent_streams) = http2.max_concurrent_streams {
+                        http2_builder.max_concurrent_streams(max_concurrent_streams);
+                    }
+
+                    if let Some(keep_alive_timeout) = http2.keep_alive_timeout {
+                        http2_builder.keep_alive_timeout(keep_alive_timeout);
+                    }
+                }
+
+                let result = http_builder
+                    .serve_connection_with_upgrades(io, service)
+                    .await;
+
+                finish_connection(&connection_context, result);
+            } else {
                 let mut http_builder = hyper::server::conn::http1::Builder::new();
 
                 if let Some(max_number_of_headers) = max_number_of_headers {
@@ -204,21 +289,44 @@ pub async fn run_server(
                     http_builder.keep_alive(keep_alive);
                 }
 
-                if let Err(err) = http_builder.serve_connection(io, service).await {
-                    if err.is_parse_too_large() {
-                        return;
-                    }
-                    log::debug!("Error serving connection: {:?}", err);
-                }
+                let result = http_builder
+                    .serve_connection(io, service)
+                    .with_upgrades()
+                    .await
+                    .map_err(Into::into);
 
-                connection_context
-                    .closed
-                    .store(true, std::sync::atomic::Ordering::Release);
+                finish_connection(&connection_context, result);
             }
         });
     }
 }
 
+// After the HTTP/1.1 or HTTP/2 connection is served
+// The connection stays open if it was handed over to a WebSocket session.
+fn finish_connection(
+    connection_context: &ConnectionContext,
+    result: Result<(), Box<dyn std::error::Error + Send + Sync>>,
+) {
+    if let Err(err) = result {
+        if err
+            .downcast_ref::<hyper::Error>()
+            .is_some_and(|err| err.is_parse_too_large())
+        {
+            return;
+        }
+        log::debug!("Error serving connection: {:?}", err);
+    }
+
+    if !connection_context
+        .upgraded
+        .load(std::sync::atomic::Ordering::Acquire)
+    {
+        connection_context
+            .closed
+            .store(true, std::sync::atomic::Ordering::Release);
+    }
+}
+
 pub(crate) type ResponseBytesBody = http_body_util::combinators::BoxBody<Bytes, Infallible>;
 
 // Handles each HTTP connection
@@ -228,7 +336,7 @@ pub(crate) type ResponseBytesBody = http_body_util::combinators::BoxBody<Bytes,
 async fn handle_http_connection(
     application_properties: Arc<ApplicationProperties>,
     di_context: Arc<di::DIContext>,
-    root_module: impl IModule + Clone + Send + Sync + 'static,
+    router: Arc<route::Router>,
     request: hyper::Request<hyper::body::Incoming>,
     connection_context: ConnectionContext,
 ) -> Result<hyper::Response<ResponseBytesBody>, Infallible> {
@@ -236,10 +344,16 @@ async fn handle_http_connection(
     let application_properties = Arc::clone(&application_properties);
 
     let running_task_count = Arc::clone(&connection_context.running_task_count);
-    let root_module = root_module.clone();
 
     let is_graceful_shutdown = application_properties.server.is_graceful_shutdown();
 
+    // for error handlers
+    let error_handlers = Arc::clone(&router.error_handlers);
+    let error_format = application_properties.server.error.format;
+    let request_method = request.method().clone();
+    let request_path = request.uri().path().to_string();
+    let _connection_context = connection_context.clone();
+
     if let Some(max_length) = application_properties.server.request.uri.max_length {
         let incoming_url_length = request
             .uri()
@@ -248,7 +362,13 @@ async fn handle_http_connection(
             .unwrap_or_default();
 
         if incoming_url_length > max_length {
-            return default_uri_too_long_handler();
+            return handle_error(
+                &error_handlers,
+                HttpError::new(414, request_method, request_path, Errors::UriTooLong.into()),
+                error_format,
+                &connection_context,
+                default_uri_too_long_handler,
+            );
         }
     }
 
@@ -267,7 +387,7 @@ async fn handle_http_connection(
                 let result = execute_request_pipeline(
                     application_properties,
                     di_context,
-                    root_module,
+                    router,
                     request,
                     connection_context,
                     ProcessRequestOption {
@@ -286,8 +406,30 @@ async fn handle_http_connection(
 
         match handle.await {
             Ok(Ok(response)) => response,
-            Ok(Err(error)) => default_join_error_handler(error),
-            Err(error) => default_timeout_handler(error),
+            Ok(Err(error)) => handle_error(
+                &error_handlers,
+                HttpError::new(
+                    500,
+                    request_method,
+                    request_path,
+                    Errors::Unknown(error.to_string()).into(),
+                ),
+                error_format,
+                &_connection_context,
+                || default_join_error_handler(error),
+            ),
+            Err(error) => handle_error(
+                &error_handlers,
+                HttpError::new(
+                    500,
+                    request_method,
+                    request_path,
+                    Errors::RequestTimeout.into(),
+                ),
+                error_format,
+                &_connection_context,
+                || default_timeout_handler(error),
+            ),
         }
     } else {
         let _running_task_count = Arc::clone(&running_task_count);
@@ -302,7 +444,7 @@ async fn handle_http_connection(
             let result = execute_request_pipeline(
                 application_properties,
                 di_context,
-                root_module,
+                router,
                 request,
                 connection_context,
                 ProcessRequestOption {
@@ -322,11 +464,54 @@ async fn handle_http_connection(
 
         match result {
             Ok(response) => response,
-            Err(error) => default_join_error_handler(error),
+            Err(error) => handle_error(
+                &error_handlers,
+                HttpError::new(
+                    500,
+                    request_method,
+                    request_path,
+                    Errors::Unknown(error.to_string()).into(),
+                ),
+                error_format,
+                &_connection_context,
+                || default_join_error_handler(error),
+            ),
         }
     }
 }
 
+// Handles a request that is not received from a socket. (ex. [crate::test::TestClient])
+// The connection level checks (request timeout, URI length) are not applied.
+pub(crate) async fn handle_in_memory_request(
+    application_properties: Arc<ApplicationProperties>,
+    di_context: Arc<di::DIContext>,
+    router: Arc<route::Router>,
+    request: InMemoryRequest,
+) -> hyper::Response<ResponseBytesBody> {
+    let connection_context = ConnectionContext {
+        closed: Arc::new(AtomicBool::new(false)),
+        ip: IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
+        running_task_count: Arc::new(AtomicU64::new(0)),
+        service_available: Arc::new(AtomicBool::new(true)),
+        upgraded: Arc::new(AtomicBool::new(false)),
+    };
+
+    let result = execute_request_pipeline(
+        application_properties,
+        di_context,
+        router,
+        request,
+        connection_context,
+        ProcessRequestOption::default(),
+    )
+    .await;
+
+    match result {
+        Ok(response) => response,
+        Err(infallible) => match infallible {},
+    }
+}
+
 #[cfg(feature = "aws-lambda")]
 pub async fn run_server_on_aws_lambda(
     application_properties: application_properties::ApplicationProperties,
@@ -339,23 +524,30 @@ pub async fn run_server_on_aws_lambda(
     di_context.initialize(Box::new(root_module.clone()));
     let di_context = Arc::new(di_context);
 
-    // 2. Prepare Swagger Serving, if enabled
+    // 2. Build the router once from the module tree
+    let router = Arc::new(route::Router::from_module(
+        Box::new(root_module.clone()),
+        &application_properties,
+    ));
+
+    // 3. Prepare Swagger Serving, if enabled
     if let Some(swagger_context) = di_context.get::<SwaggerContext>() {
-        swagger_context.initialize_from_module(root_module.clone());
+        swagger_context
+            .initialize_from_module_with_properties(root_module.clone(), &application_properties);
     }
 
-    // 3. ready, set, go!
+    // 4. ready, set, go!
     banner::print_banner(&application_properties);
 
     let application_properties = Arc::new(application_properties);
 
     loop {
-        // 4. extract request context from AWS Lambda event
+        // 5. extract request context from AWS Lambda event
         let lambda_request_context = bootings::aws_lambda::get_request_context().await?;
 
         let di_context = Arc::clone(&di_context);
         let application_properties = Arc::clone(&application_properties);
-        let root_module = root_module.clone();
+        let router = Arc::clone(&router);
 
         tokio::spawn(async move {
             let aws_request_id = lambda_request_context.aws_request_id.clone();
@@ -364,7 +556,7 @@ pub async fn run_server_on_aws_lambda(
                 lambda_request_context,
                 application_properties,
                 di_context,
-                root_module,
+                router,
             )
             .await;
 
@@ -389,7 +581,7 @@ pub async fn handle_event_on_aws_lambda(
     mut lambda_request_context: LambdaRequestEvent,
     application_properties: Arc<application_properties::ApplicationProperties>,
     di_context: Arc<di::DIContext>,
-    root_module: impl IModule + Clone + Send + Sync + 'static,
+    router: Arc<route::Router>,
 ) -> anyhow::Result<()> {
     use bootings::aws_lambda::LambdaReponse;
     use hyper::Version;
@@ -443,13 +635,15 @@ pub async fn handle_event_on_aws_lambda(
         closed: Arc::new(AtomicBool::new(false)),
         ip,
         running_task_count: Arc::new(AtomicU64::new(0)),
+        service_available: Arc::new(AtomicBool::new(true)),
+        upgraded: Arc::new(AtomicBool::new(false)),
     };
 
-    // 5. process request
+    // 6. process request
     let mut response = execute_request_pipeline(
         application_properties,
         di_context,
-        root_module,
+        router,
         request,
         connection_context,
         ProcessRequestOption {
@@ -458,7 +652,7 @@ pub async fn handle_event_on_aws_lambda(
     )
     .await?;
 
-    // 6. convert response to AWS Lambda response format
+    // 7. convert response to AWS Lambda response format
     let status_code = response.status();
     let headermap = response.headers();
 
@@ -482,7 +676,7 @@ pub async fn handle_event_on_aws_lambda(
         }
     };
 
-    // 7. send response to AWS Lambda
+    // 8. send response to AWS Lambda
     bootings::aws_lambda::send_response_to_lambda(
         &lambda_request_context.aws_request_id,
         LambdaReponse {
@@ -527,13 +721,15 @@ impl Default for ProcessRequestOption {
 async fn execute_request_pipeline(
     application_properties: Arc<application_properties::ApplicationProperties>,
     di_context: Arc<di::DIContext>,
-    root_module: impl IModule + Clone + Send + Sync + 'static,
-    request: impl RequestAdapter,
+    router: Arc<route::Router>,
+    mut request: impl RequestAdapter,
     connection_context: ConnectionContext,
     option: ProcessRequestOption,
 ) -> Result<hyper::Response<ResponseBytesBody>, Infallible> {
     // 1. Prepare URI matching
     let di_context = Arc::clone(&di_context);
+    let on_upgrade = request.on_upgrade();
+    let error_format = application_properties.server.error.format;
 
     let uri = request.uri();
     let request_path = uri.path();
@@ -549,22 +745,120 @@ async fn execute_request_pipeline(
         format!("[Request] {} {}", request_method, request_path).as_str(),
     );
 
+    // 1.1. CORS Preflight
+    // Preflight requests are answered before the route handlers run, with the CORS configuration of the requested route.
+    if request_method == hyper::Method::OPTIONS {
+        if let Some(response) = cors_preflight_response(
+            &router,
+            &application_properties.server.cors,
+            request.headers(),
+            request_path,
+        ) {
+            return Ok(response.into_hyper_response(&connection_context));
+        }
+    }
+
     // 2. Find the one that matches the current request among the routes included in the hierarchical module structure.
-    let found_route = route::find_route(Box::new(root_module), request_path, request_method);
+    let found_route = match router.lookup(request_method, request_path) {
+        route::RouteLookup::Found(route) => route,
+        // The path exists, but the method is different.
+        route::RouteLookup::MethodNotAllowed(allowed_methods) => {
+            if request_method == hyper::Method::OPTIONS {
+                return default_options_handler(&allowed_methods);
+            }
+
+            let error = HttpError::new(
+                405,
+                request_method.clone(),
+                request_path,
+                Errors::MethodNotAllowed(allowed_methods.clone()).into(),
+            );
+
+            let response =
+                find_error_response(&router.error_handlers, &error).or_else(
+                    || match error_format {
+                        ErrorFormat::ProblemJson => Some(problem_response(&error)),
+                        ErrorFormat::Text => None,
+                    },
+                );
+
+            return match response {
+                Some(mut response) => {
+                    let allow = crate::HeaderName::from_static(header::ALLOW);
 
-    let found_route = match found_route {
-        Some(route) => route,
-        // TODO: 404 Handler Customization
-        None => {
-            return default_404_handler();
+                    response
+                        .headers
+                        .entry(allow)
+                        .or_insert_with(|| vec![allow_header_value(&allowed_methods)]);
+
+                    Ok(response.into_hyper_response(&connection_context))
+                }
+                None => default_method_not_allowed_handler(&allowed_methods),
+            };
+        }
+        route::RouteLookup::NotFound => {
+            // 2.1. Static Files
+            // Requests that do not match any route can be answered with a file in the static directory.
+            if application_properties.server.static_files.enabled {
+                let static_response = {
+                    let application_properties = Arc::clone(&application_properties);
+                    let request_method = request_method.clone();
+                    let request_path = request_path.to_owned();
+                    let headers = request.headers().clone();
+
+                    // File I/O is blocking.
+                    tokio::task::spawn_blocking(move || {
+                        static_files::serve_static_file(
+                            &application_properties.server.static_files,
+                            &request_method,
+                            &request_path,
+                            &headers,
+                        )
+                    })
+                    .await
+                    .ok()
+                    .flatten()
+                };
+
+                if let Some(response) = static_response {
+                    let is_head_request = request_method == hyper::Method::HEAD;
+
+                    let accept_encoding = request
+                        .headers()
+                        .get(header::ACCEPT_ENCODING)
+                        .and_then(|e| e.to_str().ok());
+
+                    let mut response =
+                        post_process_response(application_properties, accept_encoding, response);
+
+                    if is_head_request {
+                        strip_response_body(&mut response);
+                    }
+
+                    return Ok(response.into_hyper_response(&connection_context));
+                }
+            }
+
+            return handle_error(
+                &router.error_handlers,
+                HttpError::new(
+                    404,
+                    request_method.clone(),
+                    request_path,
+                    Errors::NotFound.into(),
+                ),
+                error_format,
+                &connection_context,
+                default_404_handler,
+            );
         }
     };
 
     // 3. Get the handler function for the matched route value,
     // prepare the request context, and pass it to the handler function.
-    let (route, route_path, middlewares) = found_route;
-
-    let handler = route.handler();
+    let handler = Arc::clone(&found_route.route.handler);
+    let middlewares = Arc::clone(&found_route.route.middlewares);
+    let error_handlers = Arc::clone(&found_route.route.error_handlers);
 
     let raw_querystring = uri.query().unwrap_or_default();
     let mut cookies = HashMap::new();
@@ -585,7 +879,18 @@ async fn execute_request_pipeline(
 
         if let Some(header_max_length) = application_properties.server.request.header.max_length {
             if request_metadata.header_size > header_max_length {
-                return default_header_size_too_big();
+                return handle_error(
+                    &error_handlers,
+                    HttpError::new(
+                        400,
+                        request_method.clone(),
+                        request_path,
+                        Errors::HeaderSizeTooBig.into(),
+                    ),
+                    error_format,
+                    &connection_context,
+                    default_header_size_too_big,
+                );
             }
         }
 
@@ -596,11 +901,23 @@ async fn execute_request_pipeline(
             .max_number_of_headers
         {
             if request_metadata.number_of_headers > max_number_of_headers {
-                return default_header_fields_to_large();
+                return handle_error(
+                    &error_handlers,
+                    HttpError::new(
+                        431,
+                        request_method.clone(),
+                        request_path,
+                        Errors::HeaderFieldsTooLarge.into(),
+                    ),
+                    error_format,
+                    &connection_context,
+                    default_header_fields_to_large,
+                );
             }
         }
 
-        if application_properties.server.multipart.auto_parsing_enabled
+        if (application_properties.server.multipart.auto_parsing_enabled
+            || found_route.route.stream_multipart)
             && header_name == header::CONTENT_TYPE
             && header_value.starts_with("multipart/form-data")
         {
@@ -617,10 +934,20 @@ async fn execute_request_pipeline(
     }
     preprocess_headers(&mut headers);
 
-    // 3.3. Parse Path Parameters
-    let path_parameters = parse::parse_path_parameter(route_path, request_path);
+    // CORS configuration for the response (the configuration of the controller first)
+    let cors = found_route
+        .route
+        .cors
+        .clone()
+        .unwrap_or_else(|| Arc::new(application_properties.server.cors.clone()));
+    let origin = headers.get(header::ORIGIN).cloned();
+    let accept_encoding = headers.get(header::ACCEPT_ENCODING).cloned();
+
+    // 3.3. Path Parameters (captured by the router)
+    let path_parameters = found_route.path_parameters;
 
     let request_method = request_method.to_owned();
+    let is_head_request = request_method == hyper::Method::HEAD;
     let request_path = request_path.to_owned();
 
     let mut request_body = "".to_string();
@@ -631,12 +958,112 @@ async fn execute_request_pipeline(
     // request body limit (default: 2MB)
     let body_limit = application_properties.server.request.body.max_length;
 
+    // The multipart body of a `stream_multipart` route is read by the handler.
+    let mut streaming_multipart = TakeOnce::default();
+    let stream_multipart = found_route.route.stream_multipart && multipart_boundary.is_some();
+    let multipart_config = &application_properties.server.multipart;
+
+    // The body of a `stream_body` route is read by the handler.
+    let mut streaming_body = TakeOnce::default();
+    let stream_body = found_route.route.stream_body && !stream_multipart;
+
+    // A body declared larger than the limit is rejected before the handler.
+    if stream_body {
+        let content_length = headers
+            .get(header::CONTENT_LENGTH)
+            .and_then(|e| e.parse::<usize>().ok());
+
+        if content_length.is_some_and(|e| e > body_limit) {
+            return handle_error(
+                &error_handlers,
+                HttpError::new(
+                    413,
+                    request_method,
+                    request_path,
+                    Errors::PayloadTooLarge.into(),
+                ),
+                error_format,
+                &connection_context,
+                default_payload_too_large_handler,
+            );
+        }
+    }
+
+    // Streaming bodies are not decompressed, so an encoded body is rejected instead of being passed as it is.
+    if (stream_body || stream_multipart)
+        && application_properties
+            .server
+            .request
+            .body
+            .decompression_enabled
+    {
+        let content_encoding = headers
+            .get(header::CONTENT_ENCODING)
+            .filter(|e| !compression::is_identity_encoding(e));
+
+        if let Some(content_encoding) = content_encoding {
+            return handle_error(
+                &error_handlers,
+                HttpError::new(
+                    415,
+                    request_method,
+                    request_path,
+                    Errors::UnsupportedContentEncoding(content_encoding.to_owned()).into(),
+                ),
+                error_format,
+                &connection_context,
+                default_unsupported_content_encoding_handler,
+            );
+        }
+    }
+
     match option.boot_mode {
+        BootMode::AWSLambda(aws_request_body) if stream_multipart => {
+            let body = BodyStream::from_bytes(
+                aws_request_body.request_body,
+                Some(multipart_config.max_request_size),
+            );
+
+            streaming_multipart = TakeOnce::new(multipart::Multipart::new(
+                body,
+                multipart_boundary.as_deref().unwrap_or_default(),
+                multipart_config.clone(),
+            ));
+        }
+        BootMode::Normal if stream_multipart => {
+            let body = request.into_body_stream(Some(multipart_config.max_request_size));
+
+            streaming_multipart = TakeOnce::new(multipart::Multipart::new(
+                body,
+                multipart_boundary.as_deref().unwrap_or_default(),
+                multipart_config.clone(),
+            ));
+        }
+        BootMode::AWSLambda(aws_request_body) if stream_body => {
+            streaming_body = TakeOnce::new(BodyStream::from_bytes(
+                aws_request_body.request_body,
+                Some(body_limit),
+            ));
+        }
+        BootMode::Normal if stream_body => {
+            streaming_body = TakeOnce::new(request.into_body_stream(Some(body_limit)));
+        }
         BootMode::AWSLambda(aws_request_body) => {
             let request_body = aws_request_body.request_body;
 
             if request_body.len() > application_properties.server.request.body.max_length {
-                return default_payload_too_large_handler();
+                return handle_error(
+                    &error_handlers,
+                    HttpError::new(
+                        413,
+                        request_method,
+                        request_path,
+                        Errors::PayloadTooLarge.into(),
+                    ),
+                    error_format,
+                    &connection_context,
+                    default_payload_too_large_handler,
+                );
             }
 
             // TODO: 멀티파트 파싱 로직 추가
@@ -648,6 +1075,55 @@ async fn execute_request_pipeline(
                 Ok(body) => {
                     raw_request_body = body;
 
+                    // Decompress the request body according to the Content-Encoding header.
+                    // The body limit applies to the decompressed size.
+                    if application_properties
+                        .server
+                        .request
+                        .body
+                        .decompression_enabled
+                    {
+                        if let Some(content_encoding) = headers.remove(header::CONTENT_ENCODING) {
+                            match compression::decompress_request_body(
+                                &content_encoding,
+                                raw_request_body,
+                                body_limit,
+                            ) {
+                                Ok(body) => {
+                                    headers.insert(
+                                        header::CONTENT_LENGTH.to_string(),
+                                        body.len().to_string(),
+                                    );
+                                    raw_request_body = body;
+                                }
+                                Err(error) => {
+                                    let default_handler: DefaultErrorHandler = match error {
+                                        Errors::PayloadTooLarge => {
+                                            default_payload_too_large_handler
+                                        }
+                                        Errors::UnsupportedContentEncoding(_) => {
+                                            default_unsupported_content_encoding_handler
+                                        }
+                                        _ => default_decompression_failed_handler,
+                                    };
+
+                                    return handle_error(
+                                        &error_handlers,
+                                        HttpError::new(
+                                            error.status(),
+                                            request_method,
+                                            request_path,
+                                            error.into(),
+                                        ),
+                                        error_format,
+                                        &connection_context,
+                                        default_handler,
+                                    );
+                                }
+                            }
+                        }
+                    }
+
                     if application_properties.server.multipart.auto_parsing_enabled {
                         if let Some(boundary) = multipart_boundary {
                             files = multipart::parse_multipart(&raw_request_body, &boundary)
@@ -659,7 +1135,22 @@ async fn execute_request_pipeline(
                         }
                     }
                 }
-                Err(_) => {
+                Err(error) => {
+                    if error.downcast_ref::<LengthLimitError>().is_some() {
+                        return handle_error(
+                            &error_handlers,
+                            HttpError::new(
+                                413,
+                                request_method,
+                                request_path,
+                                Errors::PayloadTooLarge.into(),
+                            ),
+                            error_format,
+                            &connection_context,
+                            default_payload_too_large_handler,
+                        );
+                    }
+
                     let response: hyper::Response<ResponseBytesBody> = hyper::Response::builder()
                         .body(BodyExt::boxed(BoxBody::new(
                             "Error reading request body".to_string(),
@@ -672,8 +1163,13 @@ async fn execute_request_pipeline(
         }
     }
 
+    // for error handlers
+    let error_method = request_method.clone();
+    let error_path = request_path.clone();
+
     // 3.4. Call the handler function
-    let response = std::panic::catch_unwind(move || {
+    // The whole chain runs inside the future, so a panic in a sync or async handler is caught the same way.
+    let response = AssertUnwindSafe(async move {
         let mut request = crate::Request {
             method: request_method,
             path: request_path,
@@ -686,51 +1182,98 @@ async fn execute_request_pipeline(
             files,
             metadata: request_metadata,
             di_context: Arc::clone(&di_context),
+            multipart: streaming_multipart,
+            body_stream: streaming_body,
         };
 
         request.parse_cookies();
 
-        let mut response = crate::Response::new();
-
         // 3.5. middleware chain processing
-        for middleware in middlewares {
-            let middleware_result =
-                middleware(request, response.clone(), move |request, response| {
-                    let next = Some(Box::new((request, response)));
+        // The handler is called at the end of the chain.
+        let next = crate::Next::new(middlewares, handler);
 
-                    let mut response = crate::Response::new();
-                    response.next = next;
+        next.run(request).await
+    })
+    .catch_unwind()
+    .await;
 
-                    response
-                });
-
-            match middleware_result.next {
-                Some(next) => {
-                    let (next_request, next_response) = *next;
+    // 4. Unhandled Error Handling
+    // Errors returned from handlers and panics are passed to the error handlers of the module.
+    let response = match response {
+        Ok(mut response) => match response.error.take() {
+            Some(error) => handle_response_error(
+                &error_handlers,
+                HttpError {
+                    status: response.status,
+                    method: error_method.clone(),
+                    path: error_path.clone(),
+                    error,
+                },
+                error_format,
+            ),
+            None => response,
+        },
+        Err(panic) => handle_response_error(
+            &error_handlers,
+            HttpError::new(
+                500,
+                error_method.clone(),
+                error_path.clone(),
+                Errors::Panic(panic_message(panic.as_ref())).into(),
+            ),
+            error_format,
+        ),
+    };
 
-                    request = next_request;
-                    response = next_response;
-                }
-                None => {
-                    return middleware_result;
-                }
-            }
+    // 4.1. WebSocket Upgrade
+    // The WebSocket handler runs on the upgraded connection, after the 101 response is sent.
+    let response = match (&response.data, on_upgrade) {
+        (ResponseData::WebSocket(websocket_response), Some(on_upgrade))
+            if response.status == 101 =>
+        {
+            connection_context
+                .upgraded
+                .store(true, std::sync::atomic::Ordering::Release);
+
+            websocket::spawn_session(
+                on_upgrade,
+                websocket_response.clone(),
+                Arc::clone(&connection_context.closed),
+                Arc::clone(&connection_context.service_available),
+                Arc::clone(&connection_context.running_task_count),
+            );
+
+            response
         }
+        (ResponseData::WebSocket(_), None) if response.status == 101 => handle_response_error(
+            &error_handlers,
+            HttpError::new(
+                400,
+                error_method,
+                error_path,
+                Errors::WebSocketHandshakeFailed("the connection cannot be upgraded".to_string())
+                    .into(),
+            ),
+            error_format,
+        ),
+        _ => response,
+    };
 
-        handler.handle(request, response)
-    });
-
-    // 4. Unhandled Error Handling
-    let response = match response {
-        Ok(response) => response,
-        Err(_err) => crate::Response::new()
-            .status(500)
-            .text("Internal Server Error".to_string()),
+    // 4.2. CORS Headers
+    let response = match origin {
+        Some(origin) if cors.enabled => cors.apply(&origin, response),
+        _ => response,
     };
 
     // 5. Post-Processing Response
     // ex) Compression, etc.
-    let response = post_process_response(application_properties, response);
+    let mut response =
+        post_process_response(application_properties, accept_encoding.as_deref(), response);
+
+    // HEAD responses have the same headers as GET, without the body.
+    if is_head_request {
+        strip_response_body(&mut response);
+    }
 
     // 6. Convert to hyper::Response, and return
     let response: hyper::Response<ResponseBytesBody> =
@@ -739,8 +1282,50 @@ async fn execute_request_pipeline(
     Ok(response)
 }
 
+// Returns None if the request is not a preflight request, or CORS is not enabled for the requested route.
+fn cors_preflight_response(
+    router: &route::Router,
+    cors: &CorsConfig,
+    headers: &hyper::HeaderMap,
+    request_path: &str,
+) -> Option<crate::Response> {
+    let header_value = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+    let origin = header_value(header::ORIGIN)?;
+    let request_method = header_value(header::ACCESS_CONTROL_REQUEST_METHOD)?;
+    let request_headers = header_value(header::ACCESS_CONTROL_REQUEST_HEADERS);
+
+    let method = hyper::Method::from_bytes(request_method.as_bytes()).ok()?;
+
+    let route::RouteLookup::Found(found_route) = router.lookup(&method, request_path) else {
+        return None;
+    };
+
+    let cors = found_route.route.cors.as_deref().unwrap_or(cors);
+
+    if !cors.enabled {
+        return None;
+    }
+
+    Some(cors.preflight_response(origin, request_method, request_headers))
+}
+
+fn strip_response_body(response: &mut crate::Response) {
+    if let ResponseData::Immediate(body) = &response.data {
+        let content_length = crate::HeaderName::from_static(header::CONTENT_LENGTH);
+
+        response
+            .headers
+            .entry(content_length)
+            .or_insert_with(|| vec![body.len().to_string()]);
+    }
+
+    response.data = ResponseData::Immediate(vec![]);
+}
+
 fn post_process_response(
     application_properties: Arc<application_properties::ApplicationProperties>,
+    accept_encoding: Option<&str>,
     mut response: crate::Response,
 ) -> crate::Response {
     if !application_properties.server.compression.enabled {
@@ -773,72 +1358,169 @@ fn post_process_response(
         return response;
     }
 
-    // Skip compression for streaming responses
-    if !matches!(response.data, ResponseData::Immediate(_)) {
+    // Skip compression for responses that are already encoded (ex. precompressed static files), or partial responses
+    if response.status == 206
+        || response
+            .headers
+            .contains_key(&crate::HeaderName::from_static(header::CONTENT_ENCODING))
+    {
         return response;
     }
 
-    if let ResponseData::Immediate(bytes) = &response.data {
-        if bytes.len() < application_properties.server.compression.min_response_size {
-            return response;
-        }
+    let content_length = crate::HeaderName::from_static(header::CONTENT_LENGTH);
 
-        match application_properties.server.compression.algorithm {
-            CompressionAlgorithm::Gzip => {
-                // compression
-                let compressed_bytes = compression::compress_with_gzip(bytes);
+    // Streams can announce their size with Content-Length. (ex. static files)
+    let response_size = match &response.data {
+        ResponseData::Immediate(bytes) => Some(bytes.len()),
+        _ => response
+            .headers
+            .get(&content_length)
+            .and_then(|e| e.first())
+            .and_then(|e| e.parse::<usize>().ok()),
+    };
 
-                let compressed_bytes = match compressed_bytes {
-                    Ok(compressed_bytes) => compressed_bytes,
-                    Err(err) => {
-                        eprintln!("Error compressing response body: {:?}", err);
-                        return response;
-                    }
-                };
+    if response_size
+        .is_some_and(|e| e < application_properties.server.compression.min_response_size)
+    {
+        return response;
+    }
 
-                response.data = ResponseData::Immediate(compressed_bytes);
+    // The response depends on the Accept-Encoding header of the request.
+    add_vary_header(&mut response, "Accept-Encoding");
 
-                // add header for compression
-                response.headers.insert(
-                    crate::HeaderName::from_static(header::CONTENT_ENCODING),
-                    vec![application_properties
-                        .server
-                        .compression
-                        .algorithm
-                        .to_string()],
-                );
-            }
-            CompressionAlgorithm::Deflate => {
-                // compression
-                let compressed_bytes = compression::compress_with_deflate(bytes);
+    let algorithm = match accept_encoding.and_then(|accept_encoding| {
+        compression::negotiate_algorithm(
+            accept_encoding,
+            &application_properties.server.compression.algorithm,
+        )
+    }) {
+        Some(algorithm) => algorithm,
+        None => return response,
+    };
 
-                let compressed_bytes = match compressed_bytes {
-                    Ok(compressed_bytes) => compressed_bytes,
-                    Err(err) => {
-                        eprintln!("Error compressing response body: {:?}", err);
-                        return response;
-                    }
-                };
+    // The compressed size is not known in advance.
+    response.headers.remove(&content_length);
+
+    match &response.data {
+        ResponseData::Immediate(bytes) => {
+            // compression
+            let compressed_bytes = match compression::compress(&algorithm, bytes) {
+                Ok(compressed_bytes) => compressed_bytes,
+                Err(err) => {
+                    eprintln!("Error compressing response body: {:?}", err);
+                    return response;
+                }
+            };
 
-                response.data = ResponseData::Immediate(compressed_bytes.clone());
+            response.data = ResponseData::Immediate(compressed_bytes);
+        }
+        ResponseData::Stream(stream_response) => {
+            let Some(stream_fn) = stream_response.stream.clone() else {
+                return response;
+            };
 
-                // add header for compression
-                response.headers.insert(
-                    crate::HeaderName::from_static(header::CONTENT_ENCODING),
-                    vec![application_properties
-                        .server
-                        .compression
-                        .algorithm
-                        .to_string()],
-                );
+            response.data = ResponseData::Stream(crate::response::StreamResponse {
+                stream: Some(compressed_stream(stream_fn, algorithm.clone())),
+                ..stream_response.clone()
+            });
+        }
+        ResponseData::WebSocket(_) => return response,
+    }
+
+    // add header for compression
+    response.headers.insert(
+        crate::HeaderName::from_static(header::CONTENT_ENCODING),
+        vec![algorithm.to_string()],
+    );
+
+    // The compressed body is not byte-for-byte the same as the original, so the ETag is only weak,
+    // and ranges of it can't be served.
+    if let Some(etags) = response
+        .headers
+        .get_mut(&crate::HeaderName::from_static(header::ETAG))
+    {
+        for etag in etags.iter_mut() {
+            if !etag.starts_with("W/") {
+                *etag = format!("W/{etag}");
             }
-            _ => {}
         }
     }
+    response
+        .headers
+        .remove(&crate::HeaderName::from_static(header::ACCEPT_RANGES));
 
     response
 }
 
+// Wrap the stream function so that the chunks are compressed before they are sent.
+fn compressed_stream(
+    stream_fn: Arc<crate::response::StreamFn>,
+    algorithm: CompressionAlgorithm,
+) -> Arc<crate::response::StreamFn> {
+    Arc::new(move |stream_handler: StreamHandler| {
+        let stream_fn = Arc::clone(&stream_fn);
+        let algorithm = algorithm.clone();
+
+        Box::pin(async move {
+            let Some(mut encoder) = compression::StreamEncoder::new(&algorithm) else {
+                return stream_fn(stream_handler).await;
+            };
+
+            let (sender, mut receiver) =
+                tokio::sync::mpsc::channel::<StreamChannelType>(STREAM_CHANNEL_CAPACITY);
+            let inner_handler = StreamHandler::new(sender, stream_handler.closed());
+
+            // The receiver ends when the handler (and its clones) are dropped.
+            let compress_chunks = async move {
+                while let Some(Ok(frame)) = receiver.recv().await {
+                    let Ok(chunk) = frame.into_data() else {
+                        continue;
+                    };
+
+                    let compressed = match encoder.encode(&chunk) {
+                        Ok(compressed) => compressed,
+                        Err(err) => {
+                            eprintln!("Error compressing response stream: {:?}", err);
+                            return;
+                        }
+                    };
+
+                    if !compressed.is_empty()
+                        && stream_handler.send_bytes(&compressed).await.is_err()
+                    {
+                        return;
+                    }
+                }
+
+                match encoder.finish() {
+                    Ok(compressed) => {
+                        let _ = stream_handler.send_bytes(&compressed).await;
+                    }
+                    Err(err) => eprintln!("Error compressing response stream: {:?}", err),
+                }
+            };
+
+            futures_util::future::join(stream_fn(inner_handler), compress_chunks).await;
+        })
+    })
+}
+
+fn add_vary_header(response: &mut crate::Response, value: &str) {
+    let values = response
+        .headers
+        .entry(crate::HeaderName::from_static(header::VARY))
+        .or_default();
+
+    let exists = values
+        .iter()
+        .flat_map(|e| e.split(','))
+        .any(|e| e.trim().eq_ignore_ascii_case(value));
+
+    if !exists {
+        values.push(value.to_string());
+    }
+}
+
 impl Response {
     pub(crate) fn into_hyper_response(
         self,
@@ -864,12 +1546,15 @@ impl Response {
             ResponseData::Immediate(body) => builder
                 .body(BodyExt::boxed(http_body_util::Full::from(body)))
                 .unwrap(),
+            // The connection is upgraded after the response is sent.
+            ResponseData::WebSocket(_) => builder
+                .body(BodyExt::boxed(http_body_util::Empty::new()))
+                .unwrap(),
             ResponseData::Stream(stream_response) => {
                 let (sender, receiver) =
-                    tokio::sync::mpsc::unbounded_channel::<StreamChannelType>();
+                    tokio::sync::mpsc::channel::<StreamChannelType>(STREAM_CHANNEL_CAPACITY);
 
-                let receiver_stream =
-                    tokio_stream::wrappers::UnboundedReceiverStream::new(receiver);
+                let receiver_stream = tokio_stream::wrappers::ReceiverStream::new(receiver);
 
                 let stream_handler = StreamHandler::new(sender, connection_context.closed.clone());
 
@@ -1,28 +1,10 @@
-use hyper::Method;
-
-pub(crate) fn is_route_matching_request(route_path: String, request_path: &str) -> bool {
-    // remove query parameters
-    let request_path = request_path.split("?").collect::<Vec<&str>>()[0];
+use std::collections::HashMap;
+use std::sync::Arc;
 
-    let route_path = route_path.split("/").collect::<Vec<&str>>();
-    let request_path = request_path.split("/").collect::<Vec<&str>>();
-
-    if route_path.len() != request_path.len() {
-        return false;
-    }
-
-    for (route_path_part, request_path_part) in route_path.iter().zip(request_path.iter()) {
-        if route_path_part.starts_with(":") {
-            continue;
-        }
-
-        if route_path_part != request_path_part {
-            return false;
-        }
-    }
+use hyper::Method;
+use regex::Regex;
 
-     true
-}
+use crate::http::cors::CorsConfig;
 
 pub(crate) fn normalize_path(prefix: String, path: String) -> String {
     let mut normalized_path = "/".to_string();
@@ -47,123 +29,1090 @@ pub(crate) fn normalize_path(prefix: String, path: String) -> String {
         normalized_path.pop();
     }
 
-     normalized_path
+    normalized_path
 }
 
-// return (route, route_path, middlewares)
-pub(crate) fn find_route(
-    root_module: Box<dyn crate::IModule>,
-    request_path: &str,
-    request_method: &Method,
-) -> Option<(
-    Box<dyn crate::IRoute + Send + 'static>,
-    String,
-    Vec<crate::MiddlewareFunction>,
-)> {
-    for controller in root_module.controllers() {
-        let prefix = controller.prefix();
-
-        for route in controller.routes() {
-            if route.method() != request_method {
-                continue;
+// Segment of a route path.
+// ex) users, :id, :id?, :id(\d+), {id}, {id:int}, *rest, {*rest}
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PathSegment<'a> {
+    Static(&'a str),
+    Param {
+        name: &'a str,
+        pattern: Option<String>,
+        optional: bool,
+    },
+    // Matches the rest of the path. It must be the last segment.
+    CatchAll {
+        name: &'a str,
+    },
+}
+
+// Regular expressions for the typed constraints. ex) {id:int}
+fn typed_constraint_pattern(type_name: &str) -> Option<&'static str> {
+    match type_name {
+        "int" => Some(r"-?[0-9]+"),
+        "uint" => Some(r"[0-9]+"),
+        "float" => Some(r"-?[0-9]+(\.[0-9]+)?"),
+        "bool" => Some(r"true|false"),
+        "alpha" => Some(r"[a-zA-Z]+"),
+        "uuid" => {
+            Some(r"[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}")
+        }
+        _ => None,
+    }
+}
+
+// Swagger formats of the typed constraints. ex) {id:uuid}
+fn typed_constraint_format(type_name: &str) -> Option<&'static str> {
+    match type_name {
+        "int" | "uint" => Some("int64"),
+        "float" => Some("double"),
+        "uuid" => Some("uuid"),
+        _ => None,
+    }
+}
+
+// The constraint of a path parameter, as the swagger pattern and format.
+// ex) /users/{id:uuid} => (^(?:[0-9a-fA-F]{8}-...)$, uuid)
+pub(crate) fn path_parameter_constraint(
+    route_path: &str,
+    name: &str,
+) -> (Option<String>, Option<&'static str>) {
+    for segment in split_segments(route_path) {
+        let PathSegment::Param {
+            name: param_name,
+            pattern,
+            ..
+        } = parse_path_segment(segment)
+        else {
+            continue;
+        };
+
+        if param_name != name {
+            continue;
+        }
+
+        let format = segment
+            .strip_prefix("{")
+            .and_then(|segment| segment.strip_suffix("}"))
+            .and_then(|inner| inner.split_once(":"))
+            .and_then(|(_, constraint)| typed_constraint_format(constraint));
+
+        // Patterns are matched against the whole segment by the router.
+        return (pattern.map(|pattern| format!("^(?:{pattern})$")), format);
+    }
+
+    (None, None)
+}
+
+pub(crate) fn parse_path_segment(segment: &str) -> PathSegment<'_> {
+    if let Some(name) = segment.strip_prefix("*") {
+        return PathSegment::CatchAll { name };
+    }
+
+    // {name}, {name?}, {name:type}, {name:regex}, {*name}
+    if let Some(inner) = segment
+        .strip_prefix("{")
+        .and_then(|segment| segment.strip_suffix("}"))
+    {
+        if let Some(name) = inner.strip_prefix("*") {
+            return PathSegment::CatchAll { name };
+        }
+
+        let (name, constraint) = match inner.split_once(":") {
+            Some((name, constraint)) => (name, Some(constraint)),
+            None => (inner, None),
+        };
+
+        let (name, optional) = match name.strip_suffix("?") {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+
+        let pattern = constraint.map(|constraint| {
+            typed_constraint_pattern(constraint)
+                .unwrap_or(constraint)
+                .to_string()
+        });
+
+        return PathSegment::Param {
+            name,
+            pattern,
+            optional,
+        };
+    }
+
+    // :name, :name?, :name(regex), :name(regex)?
+    if let Some(param) = segment.strip_prefix(":") {
+        let (param, optional) = match param.strip_suffix("?") {
+            Some(param) => (param, true),
+            None => (param, false),
+        };
+
+        if let (Some(start), true) = (param.find("("), param.ends_with(")")) {
+            return PathSegment::Param {
+                name: &param[..start],
+                pattern: Some(param[start + 1..param.len() - 1].to_string()),
+                optional,
+            };
+        }
+
+        return PathSegment::Param {
+            name: param,
+            pattern: None,
+            optional,
+        };
+    }
+
+    PathSegment::Static(segment)
+}
+
+// A route registered in the router.
+// Everything needed to serve the route is resolved once at startup.
+#[derive(Clone)]
+pub(crate) struct RouteEntry {
+    pub method: Method,
+    pub route_path: String,
+    pub param_names: Vec<String>,
+    pub handler: Arc<dyn crate::IAsyncHandler + Send + Sync + 'static>,
+    pub middlewares: Arc<Vec<crate::MiddlewareFunction>>,
+    pub error_handlers: Arc<Vec<crate::ErrorHandlerFunction>>,
+    // CORS configuration of the controller, if it overrides the application configuration.
+    pub cors: Option<Arc<CorsConfig>>,
+    // The multipart body is read by the handler. (see [crate::IRoute::stream_multipart])
+    pub stream_multipart: bool,
+    // The body is read by the handler. (see [crate::IRoute::stream_body])
+    pub stream_body: bool,
+}
+
+pub(crate) struct RouteMatch<'a> {
+    pub route: &'a RouteEntry,
+    pub path_parameters: HashMap<String, String>,
+}
+
+pub(crate) enum RouteLookup<'a> {
+    Found(RouteMatch<'a>),
+    // The path exists, but no route is registered for the method.
+    MethodNotAllowed(Vec<Method>),
+    NotFound,
+}
+
+struct ParamChild {
+    // None if the parameter has no constraint.
+    pattern: Option<Regex>,
+    node: RouterNode,
+}
+
+impl ParamChild {
+    fn matches(&self, segment: &str) -> bool {
+        match &self.pattern {
+            Some(pattern) => pattern.is_match(segment),
+            None => true,
+        }
+    }
+}
+
+#[derive(Default)]
+struct RouterNode {
+    static_children: HashMap<String, RouterNode>,
+    // Constrained parameters are placed before the unconstrained one.
+    param_children: Vec<ParamChild>,
+    catch_all_child: Option<Box<RouterNode>>,
+    routes: Vec<RouteEntry>,
+}
+
+impl RouterNode {
+    fn insert(&mut self, segments: &[PathSegment], route: RouteEntry) {
+        let (segment, rest) = match segments.split_first() {
+            None => {
+                self.add_route(route);
+                return;
+            }
+            Some(pair) => pair,
+        };
+
+        // If all the remaining segments are optional, the route also ends here.
+        let is_rest_optional = segments
+            .iter()
+            .all(|segment| matches!(segment, PathSegment::Param { optional: true, .. }));
+
+        if is_rest_optional {
+            self.add_route(route.clone());
+        }
+
+        match segment {
+            PathSegment::Static(segment) => {
+                self.static_children
+                    .entry(segment.to_string())
+                    .or_default()
+                    .insert(rest, route);
+            }
+            PathSegment::Param { pattern, .. } => {
+                self.param_child(pattern.as_deref(), &route.route_path)
+                    .insert(rest, route);
+            }
+            PathSegment::CatchAll { .. } => {
+                if rest
+                    .iter()
+                    .any(|segment| *segment != PathSegment::Static(""))
+                {
+                    panic!(
+                        "catch-all segment must be the last segment in route '{}'",
+                        route.route_path
+                    );
+                }
+
+                self.catch_all_child
+                    .get_or_insert_with(Default::default)
+                    .add_route(route);
+            }
+        }
+    }
+
+    fn add_route(&mut self, route: RouteEntry) {
+        // The first registered route wins, same as the declaration order of modules and controllers.
+        if self.routes.iter().all(|e| e.method != route.method) {
+            self.routes.push(route);
+        }
+    }
+
+    fn param_child(&mut self, pattern: Option<&str>, route_path: &str) -> &mut RouterNode {
+        let pattern = pattern.map(|pattern| {
+            Regex::new(format!("^(?:{pattern})$").as_str()).unwrap_or_else(|error| {
+                panic!("invalid path parameter pattern in route '{route_path}': {error}")
+            })
+        });
+
+        let position = self.param_children.iter().position(|child| {
+            child.pattern.as_ref().map(|e| e.as_str()) == pattern.as_ref().map(|e| e.as_str())
+        });
+
+        let position = match position {
+            Some(position) => position,
+            None => {
+                let is_constrained = pattern.is_some();
+
+                let child = ParamChild {
+                    pattern,
+                    node: RouterNode::default(),
+                };
+
+                if is_constrained {
+                    let index = self
+                        .param_children
+                        .iter()
+                        .take_while(|child| child.pattern.is_some())
+                        .count();
+
+                    self.param_children.insert(index, child);
+                    index
+                } else {
+                    self.param_children.push(child);
+                    self.param_children.len() - 1
+                }
             }
+        };
 
-            let route_path = normalize_path(prefix.clone(), route.path());
+        &mut self.param_children[position].node
+    }
+
+    fn find_route(&self, method: &Method) -> Option<&RouteEntry> {
+        self.routes.iter().find(|e| e.method == method)
+    }
 
-            if !is_route_matching_request(route_path.clone(), request_path) {
+    // Static segments are tried first, then constrained parameters, unconstrained parameters, and catch-all.
+    // If a branch has no route for the method, fall back to the next one.
+    fn find(
+        &self,
+        segments: &[&str],
+        method: &Method,
+        captured: &mut Vec<String>,
+    ) -> Option<&RouteEntry> {
+        let (segment, rest) = match segments.split_first() {
+            None => {
+                if let Some(route) = self.find_route(method) {
+                    return Some(route);
+                }
+
+                // The catch-all also matches an empty rest.
+                let route = self.catch_all_child.as_ref()?.find_route(method)?;
+                captured.push("".to_string());
+
+                return Some(route);
+            }
+            Some(pair) => pair,
+        };
+
+        if let Some(child) = self.static_children.get(*segment) {
+            if let Some(route) = child.find(rest, method, captured) {
+                return Some(route);
+            }
+        }
+
+        for child in &self.param_children {
+            if !child.matches(segment) {
                 continue;
             }
 
-            let middlewares = root_module
-                .middlewares()
-                .into_iter()
-                .chain(controller.middlewares().into_iter())
-                .collect();
-            return Some((route, route_path, middlewares));
+            captured.push(segment.to_string());
+
+            if let Some(route) = child.node.find(rest, method, captured) {
+                return Some(route);
+            }
+
+            captured.pop();
+        }
+
+        if let Some(child) = &self.catch_all_child {
+            if let Some(route) = child.find_route(method) {
+                captured.push(segments.join("/"));
+
+                return Some(route);
+            }
         }
+
+        None
     }
 
-    for child_module in root_module.child_modules() {
-        let result = find_route(child_module, request_path, request_method);
+    // Collect the methods of every route whose path matches, regardless of the method.
+    fn collect_methods(&self, segments: &[&str], methods: &mut Vec<Method>) {
+        fn push_methods(routes: &[RouteEntry], methods: &mut Vec<Method>) {
+            for route in routes {
+                if !methods.contains(&route.method) {
+                    methods.push(route.method.clone());
+                }
+            }
+        }
 
-        match result {
-            Some((route, route_path, middlewares)) => {
-                let middlewares = root_module
-                    .middlewares()
-                    .into_iter()
-                    .chain(middlewares.into_iter())
-                    .collect();
-                return Some((route, route_path, middlewares));
+        if let Some(child) = &self.catch_all_child {
+            push_methods(&child.routes, methods);
+        }
+
+        let (segment, rest) = match segments.split_first() {
+            None => {
+                push_methods(&self.routes, methods);
+
+                return;
+            }
+            Some(pair) => pair,
+        };
+
+        if let Some(child) = self.static_children.get(*segment) {
+            child.collect_methods(rest, methods);
+        }
+
+        for child in &self.param_children {
+            if child.matches(segment) {
+                child.node.collect_methods(rest, methods);
             }
-            None => {}
         }
     }
+}
+
+// The path is split by "/" before the segments are parsed, so a pattern can't contain "/". ex) /files/:path(.+/.+)
+fn check_pattern_slash(route_path: &str) {
+    let mut depth = 0usize;
 
-     None
+    for c in route_path.chars() {
+        match c {
+            '(' | '{' => depth += 1,
+            ')' | '}' => depth = depth.saturating_sub(1),
+            '/' if depth > 0 => {
+                panic!("path parameter pattern in route '{route_path}' can't contain '/'")
+            }
+            _ => {}
+        }
+    }
+}
+
+fn split_segments(path: &str) -> Vec<&str> {
+    let path = path.strip_prefix("/").unwrap_or(path);
+
+    path.split("/").collect()
+}
+
+// Router compiled from the IModule tree.
+// It is built once at startup, so the module tree is not walked for each request.
+#[derive(Default)]
+pub(crate) struct Router {
+    root: RouterNode,
+    // Error handlers of the root module, for errors that occur before a route is found.
+    pub error_handlers: Arc<Vec<crate::ErrorHandlerFunction>>,
 }
 
+impl Router {
+    pub(crate) fn from_module(
+        root_module: Box<dyn crate::IModule>,
+        application_properties: &crate::application_properties::ApplicationProperties,
+    ) -> Self {
+        let mut router = Router {
+            error_handlers: Arc::new(root_module.error_handlers()),
+            ..Default::default()
+        };
+
+        router.register_module(root_module.as_ref(), &[], application_properties);
+
+        router
+    }
+
+    // Module middlewares are applied from the parent module to the child module, and then the controller middlewares.
+    // Error handlers are tried from the child module to the parent module.
+    fn register_module(
+        &mut self,
+        module: &dyn crate::IModule,
+        parents: &[&dyn crate::IModule],
+        application_properties: &crate::application_properties::ApplicationProperties,
+    ) {
+        let mut modules = parents.to_vec();
+        modules.push(module);
+
+        let error_handlers: Vec<crate::ErrorHandlerFunction> = modules
+            .iter()
+            .rev()
+            .flat_map(|module| module.error_handlers())
+            .collect();
+        let error_handlers = Arc::new(error_handlers);
+
+        for controller in module.controllers() {
+            let prefix = controller.resolve_prefix(application_properties);
+
+            let middlewares: Vec<crate::MiddlewareFunction> = modules
+                .iter()
+                .flat_map(|module| module.middlewares())
+                .chain(controller.middlewares())
+                .collect();
+            let middlewares = Arc::new(middlewares);
+
+            let cors = controller.cors().map(Arc::new);
+
+            for route in controller.routes() {
+                self.insert(RouteEntry {
+                    method: route.method(),
+                    route_path: normalize_path(prefix.clone(), route.path()),
+                    param_names: vec![],
+                    handler: Arc::from(route.handler()),
+                    middlewares: Arc::clone(&middlewares),
+                    error_handlers: Arc::clone(&error_handlers),
+                    cors: cors.clone(),
+                    stream_multipart: route.stream_multipart(),
+                    stream_body: route.stream_body(),
+                });
+            }
+        }
+
+        for child_module in module.child_modules() {
+            self.register_module(child_module.as_ref(), &modules, application_properties);
+        }
+    }
+
+    pub(crate) fn insert(&mut self, mut route: RouteEntry) {
+        let route_path = route.route_path.clone();
+        check_pattern_slash(&route_path);
+
+        let segments = split_segments(&route_path)
+            .into_iter()
+            .map(parse_path_segment)
+            .collect::<Vec<_>>();
+
+        route.param_names = segments
+            .iter()
+            .filter_map(|segment| match segment {
+                PathSegment::Static(_) => None,
+                PathSegment::Param { name, .. } | PathSegment::CatchAll { name } => {
+                    Some(name.to_string())
+                }
+            })
+            .collect();
+
+        self.root.insert(&segments, route);
+    }
+
+    // 1. Exact match of the method and path
+    // 2. HEAD is answered by the GET route
+    // 3. If only the method is different, the allowed methods are returned. (OPTIONS is always allowed)
+    pub(crate) fn lookup(&self, method: &Method, request_path: &str) -> RouteLookup<'_> {
+        if let Some(found) = self.find(method, request_path) {
+            return RouteLookup::Found(found);
+        }
+
+        if method == Method::HEAD {
+            if let Some(found) = self.find(&Method::GET, request_path) {
+                return RouteLookup::Found(found);
+            }
+        }
+
+        let allowed_methods = self.allowed_methods(request_path);
+
+        if allowed_methods.is_empty() {
+            return RouteLookup::NotFound;
+        }
+
+        RouteLookup::MethodNotAllowed(allowed_methods)
+    }
+
+    pub(crate) fn allowed_methods(&self, request_path: &str) -> Vec<Method> {
+        let request_path = request_path.split("?").next().unwrap_or_default();
+        let segments = split_segments(request_path);
+
+        let mut methods = vec![];
+        self.root.collect_methods(&segments, &mut methods);
+
+        if methods.is_empty() {
+            return methods;
+        }
+
+        if methods.contains(&Method::GET) && !methods.contains(&Method::HEAD) {
+            methods.push(Method::HEAD);
+        }
+
+        if !methods.contains(&Method::OPTIONS) {
+            methods.push(Method::OPTIONS);
+        }
+
+        methods
+    }
+
+    pub(crate) fn find(&self, method: &Method, request_path: &str) -> Option<RouteMatch<'_>> {
+        // remove query parameters
+        let request_path = request_path.split("?").next().unwrap_or_default();
+        let segments = split_segments(request_path);
+
+        let mut captured = vec![];
+        let route = self.root.find(&segments, method, &mut captured)?;
+
+        let path_parameters = route.param_names.iter().cloned().zip(captured).collect();
+
+        Some(RouteMatch {
+            route,
+            path_parameters,
+        })
+    }
+}
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    struct NoopHandler {}
+
+    impl crate::IHandler for NoopHandler {
+        fn handle(&self, _: crate::Request, response: crate::Response) -> crate::Response {
+            response
+        }
+    }
+
+    fn route_entry(method: Method, route_path: &str) -> RouteEntry {
+        RouteEntry {
+            method,
+            route_path: route_path.to_string(),
+            param_names: vec![],
+            handler: Arc::new(NoopHandler {}),
+            middlewares: Arc::new(vec![]),
+            error_handlers: Arc::new(vec![]),
+            cors: None,
+            stream_multipart: false,
+            stream_body: false,
+        }
+    }
+
     #[test]
-    fn test_is_route_matching_request() {
+    fn test_router_find() {
+        fn make_router(routes: &[(Method, &str)]) -> Router {
+            let mut router = Router::default();
+
+            for (method, route_path) in routes {
+                router.insert(route_entry(method.clone(), route_path));
+            }
+
+            router
+        }
+
         struct TestCase {
             name: String,
-            route_path: String,
+            routes: Vec<(Method, &'static str)>,
+            request_method: Method,
             request_path: String,
-            expected: bool,
+            expected: Option<(String, Vec<(&'static str, &'static str)>)>,
         }
 
-        let test_cases = vec![
+        let test_cases = [
             TestCase {
                 name: "route_path is empty".to_string(),
-                route_path: "".to_string(),
+                routes: vec![(Method::GET, "")],
+                request_method: Method::GET,
                 request_path: "".to_string(),
-                expected: true,
+                expected: Some(("".to_string(), vec![])),
             },
             TestCase {
                 name: "route_path and request_path are the same".to_string(),
-                route_path: "/hello".to_string(),
+                routes: vec![(Method::GET, "/hello")],
+                request_method: Method::GET,
                 request_path: "/hello".to_string(),
-                expected: true,
+                expected: Some(("/hello".to_string(), vec![])),
             },
             TestCase {
                 name: "route_path and request_path are different".to_string(),
-                route_path: "/hello".to_string(),
+                routes: vec![(Method::GET, "/hello")],
+                request_method: Method::GET,
                 request_path: "/world".to_string(),
-                expected: false,
+                expected: None,
             },
             TestCase {
                 name: "route_path and request_path have different length".to_string(),
-                route_path: "/hello".to_string(),
+                routes: vec![(Method::GET, "/hello")],
+                request_method: Method::GET,
                 request_path: "/hello/world".to_string(),
-                expected: false,
+                expected: None,
             },
             TestCase {
                 name: "single path parameter".to_string(),
-                route_path: "/hello/:name".to_string(),
+                routes: vec![(Method::GET, "/hello/:name")],
+                request_method: Method::GET,
                 request_path: "/hello/world".to_string(),
-                expected: true,
+                expected: Some(("/hello/:name".to_string(), vec![("name", "world")])),
+            },
+            TestCase {
+                name: "multiple path parameters".to_string(),
+                routes: vec![(Method::GET, "/hello/:name/:age")],
+                request_method: Method::GET,
+                request_path: "/hello/world/42".to_string(),
+                expected: Some((
+                    "/hello/:name/:age".to_string(),
+                    vec![("name", "world"), ("age", "42")],
+                )),
             },
             TestCase {
                 name: "single query parameter".to_string(),
-                route_path: "/hello".to_string(),
+                routes: vec![(Method::GET, "/hello")],
+                request_method: Method::GET,
                 request_path: "/hello?name=world".to_string(),
-                expected: true,
+                expected: Some(("/hello".to_string(), vec![])),
+            },
+            TestCase {
+                name: "static segment is preferred over path parameter".to_string(),
+                routes: vec![(Method::GET, "/users/:id"), (Method::GET, "/users/me")],
+                request_method: Method::GET,
+                request_path: "/users/me".to_string(),
+                expected: Some(("/users/me".to_string(), vec![])),
+            },
+            TestCase {
+                name: "fall back to path parameter if static branch does not match".to_string(),
+                routes: vec![
+                    (Method::GET, "/users/:id/posts"),
+                    (Method::GET, "/users/me/profile"),
+                ],
+                request_method: Method::GET,
+                request_path: "/users/me/posts".to_string(),
+                expected: Some(("/users/:id/posts".to_string(), vec![("id", "me")])),
+            },
+            TestCase {
+                name: "fall back to path parameter if method does not match".to_string(),
+                routes: vec![(Method::GET, "/users/:id"), (Method::POST, "/users/me")],
+                request_method: Method::GET,
+                request_path: "/users/me".to_string(),
+                expected: Some(("/users/:id".to_string(), vec![("id", "me")])),
+            },
+            TestCase {
+                name: "parameter names of each route".to_string(),
+                routes: vec![
+                    (Method::GET, "/posts/:id"),
+                    (Method::DELETE, "/posts/:post_id"),
+                ],
+                request_method: Method::DELETE,
+                request_path: "/posts/3".to_string(),
+                expected: Some(("/posts/:post_id".to_string(), vec![("post_id", "3")])),
+            },
+            TestCase {
+                name: "catch-all captures the rest of the path".to_string(),
+                routes: vec![(Method::GET, "/static/*file")],
+                request_method: Method::GET,
+                request_path: "/static/js/app.js".to_string(),
+                expected: Some(("/static/*file".to_string(), vec![("file", "js/app.js")])),
+            },
+            TestCase {
+                name: "catch-all matches an empty rest".to_string(),
+                routes: vec![(Method::GET, "/static/{*file}")],
+                request_method: Method::GET,
+                request_path: "/static".to_string(),
+                expected: Some(("/static/{*file}".to_string(), vec![("file", "")])),
+            },
+            TestCase {
+                name: "catch-all is tried after the other routes".to_string(),
+                routes: vec![(Method::GET, "/*path"), (Method::GET, "/api/:id")],
+                request_method: Method::GET,
+                request_path: "/api/1".to_string(),
+                expected: Some(("/api/:id".to_string(), vec![("id", "1")])),
+            },
+            TestCase {
+                name: "catch-all as SPA fallback".to_string(),
+                routes: vec![(Method::GET, "/*path"), (Method::GET, "/api/:id")],
+                request_method: Method::GET,
+                request_path: "/api/1/posts".to_string(),
+                expected: Some(("/*path".to_string(), vec![("path", "api/1/posts")])),
+            },
+            TestCase {
+                name: "optional trailing segment is omitted".to_string(),
+                routes: vec![(Method::GET, "/posts/:page?")],
+                request_method: Method::GET,
+                request_path: "/posts".to_string(),
+                expected: Some(("/posts/:page?".to_string(), vec![])),
+            },
+            TestCase {
+                name: "optional trailing segment is given".to_string(),
+                routes: vec![(Method::GET, "/posts/{page?}")],
+                request_method: Method::GET,
+                request_path: "/posts/2".to_string(),
+                expected: Some(("/posts/{page?}".to_string(), vec![("page", "2")])),
+            },
+            TestCase {
+                name: "regex constraint matches".to_string(),
+                routes: vec![(Method::GET, r"/users/:id(\d+)")],
+                request_method: Method::GET,
+                request_path: "/users/42".to_string(),
+                expected: Some((r"/users/:id(\d+)".to_string(), vec![("id", "42")])),
+            },
+            TestCase {
+                name: "regex constraint does not match".to_string(),
+                routes: vec![(Method::GET, r"/users/:id(\d+)")],
+                request_method: Method::GET,
+                request_path: "/users/me".to_string(),
+                expected: None,
+            },
+            TestCase {
+                name: "constrained parameter is preferred over unconstrained parameter".to_string(),
+                routes: vec![
+                    (Method::GET, "/users/:name"),
+                    (Method::GET, "/users/{id:int}"),
+                ],
+                request_method: Method::GET,
+                request_path: "/users/-7".to_string(),
+                expected: Some(("/users/{id:int}".to_string(), vec![("id", "-7")])),
+            },
+            TestCase {
+                name: "fall back to unconstrained parameter".to_string(),
+                routes: vec![
+                    (Method::GET, "/users/:name"),
+                    (Method::GET, "/users/{id:int}"),
+                ],
+                request_method: Method::GET,
+                request_path: "/users/alice".to_string(),
+                expected: Some(("/users/:name".to_string(), vec![("name", "alice")])),
+            },
+            TestCase {
+                name: "typed constraint uuid".to_string(),
+                routes: vec![(Method::GET, "/orders/{id:uuid}")],
+                request_method: Method::GET,
+                request_path: "/orders/67e55044-10b1-426f-9247-bb680e5fe0c8".to_string(),
+                expected: Some((
+                    "/orders/{id:uuid}".to_string(),
+                    vec![("id", "67e55044-10b1-426f-9247-bb680e5fe0c8")],
+                )),
             },
         ];
 
         for test_case in test_cases.iter() {
-            let result = is_route_matching_request(
-                test_case.route_path.clone(),
-                test_case.request_path.as_str(),
+            let router = make_router(&test_case.routes);
+
+            let result = router
+                .find(&test_case.request_method, test_case.request_path.as_str())
+                .map(|found| (found.route.route_path.clone(), found.path_parameters));
+
+            let expected = test_case.expected.as_ref().map(|(route_path, params)| {
+                (
+                    route_path.clone(),
+                    params
+                        .iter()
+                        .map(|(key, value)| (key.to_string(), value.to_string()))
+                        .collect::<HashMap<_, _>>(),
+                )
+            });
+
+            assert_eq!(
+                result, expected,
+                "TC name: {}, request_path: {}",
+                test_case.name, test_case.request_path
             );
+        }
+    }
+
+    #[test]
+    fn test_router_lookup() {
+        #[derive(Debug, PartialEq)]
+        enum Expected {
+            Found(String),
+            MethodNotAllowed(Vec<Method>),
+            NotFound,
+        }
+
+        struct TestCase {
+            name: String,
+            request_method: Method,
+            request_path: String,
+            expected: Expected,
+        }
+
+        let mut router = Router::default();
+        for (method, route_path) in [
+            (Method::GET, "/users/:id"),
+            (Method::DELETE, "/users/:id"),
+            (Method::POST, "/users"),
+            (Method::OPTIONS, "/custom"),
+        ] {
+            router.insert(route_entry(method, route_path));
+        }
+
+        let test_cases = [
+            TestCase {
+                name: "method and path match".to_string(),
+                request_method: Method::DELETE,
+                request_path: "/users/1".to_string(),
+                expected: Expected::Found("/users/:id".to_string()),
+            },
+            TestCase {
+                name: "path does not exist".to_string(),
+                request_method: Method::GET,
+                request_path: "/posts".to_string(),
+                expected: Expected::NotFound,
+            },
+            TestCase {
+                name: "method is not allowed".to_string(),
+                request_method: Method::PUT,
+                request_path: "/users/1".to_string(),
+                expected: Expected::MethodNotAllowed(vec![
+                    Method::GET,
+                    Method::DELETE,
+                    Method::HEAD,
+                    Method::OPTIONS,
+                ]),
+            },
+            TestCase {
+                name: "HEAD is not allowed without GET route".to_string(),
+                request_method: Method::HEAD,
+                request_path: "/users".to_string(),
+                expected: Expected::MethodNotAllowed(vec![Method::POST, Method::OPTIONS]),
+            },
+            TestCase {
+                name: "HEAD is answered by GET route".to_string(),
+                request_method: Method::HEAD,
+                request_path: "/users/1".to_string(),
+                expected: Expected::Found("/users/:id".to_string()),
+            },
+            TestCase {
+                name: "OPTIONS without route returns allowed methods".to_string(),
+                request_method: Method::OPTIONS,
+                request_path: "/users".to_string(),
+                expected: Expected::MethodNotAllowed(vec![Method::POST, Method::OPTIONS]),
+            },
+            TestCase {
+                name: "OPTIONS route registered explicitly".to_string(),
+                request_method: Method::OPTIONS,
+                request_path: "/custom".to_string(),
+                expected: Expected::Found("/custom".to_string()),
+            },
+        ];
+
+        for test_case in test_cases.iter() {
+            let result = match router.lookup(&test_case.request_method, &test_case.request_path) {
+                RouteLookup::Found(found) => Expected::Found(found.route.route_path.clone()),
+                RouteLookup::MethodNotAllowed(methods) => Expected::MethodNotAllowed(methods),
+                RouteLookup::NotFound => Expected::NotFound,
+            };
+
+            assert_eq!(result, test_case.expected, "TC name: {}", test_case.name);
+        }
+    }
+
+    #[test]
+    fn test_router_invalid_route_path() {
+        struct TestCase {
+            name: String,
+            route_path: &'static str,
+            expected_panic: Option<&'static str>,
+        }
+
+        let test_cases = [
+            TestCase {
+                name: "catch-all followed by a segment".to_string(),
+                route_path: "/files/*path/edit",
+                expected_panic: Some("catch-all segment must be the last segment"),
+            },
+            TestCase {
+                name: "braced catch-all followed by a segment".to_string(),
+                route_path: "/files/{*path}/edit",
+                expected_panic: Some("catch-all segment must be the last segment"),
+            },
+            TestCase {
+                name: "regex containing a slash".to_string(),
+                route_path: r"/files/:path([a-z]+/[a-z]+)",
+                expected_panic: Some("can't contain '/'"),
+            },
+            TestCase {
+                name: "typed constraint containing a slash".to_string(),
+                route_path: "/files/{path:.+/.+}",
+                expected_panic: Some("can't contain '/'"),
+            },
+            TestCase {
+                name: "catch-all at the end".to_string(),
+                route_path: "/files/*path",
+                expected_panic: None,
+            },
+            TestCase {
+                name: "regex with braces".to_string(),
+                route_path: "/countries/{code:[A-Z]{3}}/cities",
+                expected_panic: None,
+            },
+        ];
+
+        for test_case in test_cases {
+            let result = std::panic::catch_unwind(|| {
+                Router::default().insert(route_entry(Method::GET, test_case.route_path));
+            });
+
+            let panic_message = result
+                .err()
+                .map(|panic| crate::core::error_handler::panic_message(panic.as_ref()));
 
+            match test_case.expected_panic {
+                Some(expected) => assert!(
+                    panic_message
+                        .as_deref()
+                        .is_some_and(|message| message.contains(expected)),
+                    "TC name: {}, panic: {:?}",
+                    test_case.name,
+                    panic_message
+                ),
+                None => assert_eq!(panic_message, None, "TC name: {}", test_case.name),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_path_segment() {
+        struct TestCase {
+            segment: &'static str,
+            expected: PathSegment<'static>,
+        }
+
+        let test_cases = [
+            TestCase {
+                segment: "users",
+                expected: PathSegment::Static("users"),
+            },
+            TestCase {
+                segment: ":id",
+                expected: PathSegment::Param {
+                    name: "id",
+                    pattern: None,
+                    optional: false,
+                },
+            },
+            TestCase {
+                segment: ":id?",
+                expected: PathSegment::Param {
+                    name: "id",
+                    pattern: None,
+                    optional: true,
+                },
+            },
+            TestCase {
+                segment: r":id(\d+)",
+                expected: PathSegment::Param {
+                    name: "id",
+                    pattern: Some(r"\d+".to_string()),
+                    optional: false,
+                },
+            },
+            TestCase {
+                segment: "{id}",
+                expected: PathSegment::Param {
+                    name: "id",
+                    pattern: None,
+                    optional: false,
+                },
+            },
+            TestCase {
+                segment: "{id?:uint}",
+                expected: PathSegment::Param {
+                    name: "id",
+                    pattern: Some("[0-9]+".to_string()),
+                    optional: true,
+                },
+            },
+            TestCase {
+                segment: "{code:[A-Z]{3}}",
+                expected: PathSegment::Param {
+                    name: "code",
+                    pattern: Some("[A-Z]{3}".to_string()),
+                    optional: false,
+                },
+            },
+            TestCase {
+                segment: "*rest",
+                expected: PathSegment::CatchAll { name: "rest" },
+            },
+            TestCase {
+                segment: "{*rest}",
+                expected: PathSegment::CatchAll { name: "rest" },
+            },
+        ];
+
+        for test_case in test_cases.iter() {
             assert_eq!(
-                result, test_case.expected,
-                "TC name: {}, route_path: {}, request_path: {}",
-                test_case.name, test_case.route_path, test_case.request_path
+                parse_path_segment(test_case.segment),
+                test_case.expected,
+                "segment: {}",
+                test_case.segment
+            );
+        }
+    }
+
+    #[test]
+    fn test_path_parameter_constraint() {
+        struct TestCase {
+            route_path: &'static str,
+            name: &'static str,
+            expected: (Option<String>, Option<&'static str>),
+        }
+
+        let test_cases = [
+            TestCase {
+                route_path: "/users/:id",
+                name: "id",
+                expected: (None, None),
+            },
+            TestCase {
+                route_path: r"/users/:id(\d+)",
+                name: "id",
+                expected: (Some(r"^(?:\d+)$".to_string()), None),
+            },
+            TestCase {
+                route_path: "/users/{id?:uint}/posts",
+                name: "id",
+                expected: (Some("^(?:[0-9]+)$".to_string()), Some("int64")),
+            },
+            TestCase {
+                route_path: "/flags/{flag:bool}",
+                name: "flag",
+                expected: (Some("^(?:true|false)$".to_string()), None),
+            },
+            TestCase {
+                route_path: "/countries/{code:[A-Z]{3}}",
+                name: "code",
+                expected: (Some("^(?:[A-Z]{3})$".to_string()), None),
+            },
+            TestCase {
+                route_path: "/users/{id:int}",
+                name: "unknown",
+                expected: (None, None),
+            },
+        ];
+
+        for test_case in test_cases.iter() {
+            assert_eq!(
+                path_parameter_constraint(test_case.route_path, test_case.name),
+                test_case.expected,
+                "route path: {}",
+                test_case.route_path
             );
         }
     }
@@ -177,7 +1126,7 @@ mod tests {
             expected: String,
         }
 
-        let test_cases = vec![
+        let test_cases = [
             TestCase {
                 name: "prefix and path are empty".to_string(),
                 prefix: "".to_string(),
@@ -222,7 +1171,7 @@ mod tests {
     }
 
     #[test]
-    fn test_find_route() {
+    fn test_router_from_module() {
         use crate as rupring;
 
         #[derive(Debug, Clone, Copy)]
@@ -233,26 +1182,26 @@ mod tests {
             middlewares=[]
         )]
         pub struct RootModule {}
-        
+
         #[derive(Debug, Clone)]
         #[rupring::Controller(prefix=/, routes=[hello, echo])]
         pub struct HomeController {}
-        
+
         #[rupring::Get(path = /)]
         pub fn hello(_request: rupring::Request) -> rupring::Response {
             rupring::Response::new().redirect("https://naver.com")
         }
-        
+
         #[rupring::Get(path = /user)]
         pub fn get_user(_: rupring::Request, _: rupring::Response) -> rupring::Response {
             rupring::Response::new().text("asdf")
         }
-        
+
         #[rupring::Get(path = /echo)]
         pub fn echo(request: rupring::Request, _: rupring::Response) -> rupring::Response {
             rupring::Response::new().text(request.body)
         }
-      
+
         #[derive(Debug, Clone, Copy)]
         #[rupring::Module(
             controllers=[UserController{}],
@@ -262,7 +1211,7 @@ mod tests {
             middlewares=[]
         )]
         pub struct UserModule {}
-        
+
         #[derive(Debug, Clone)]
         #[crate::Controller(prefix=/, routes=[get_user], middlewares=[])]
         pub struct UserController {}
@@ -303,6 +1252,14 @@ mod tests {
                 },
                 expected: None,
             },
+            TestCase {
+                name: "route found in child module (/user)".to_string(),
+                argument: Argument {
+                    request_path: "/user".to_string(),
+                    request_method: Method::GET,
+                },
+                expected: Some("/user".to_string()),
+            },
             TestCase {
                 name: "route found (echo)".to_string(),
                 argument: Argument {
@@ -313,15 +1270,122 @@ mod tests {
             },
         ];
 
+        let router = Router::from_module(Box::new(RootModule {}), &Default::default());
+
         for test_case in test_cases.iter() {
-            let result = find_route(
-                Box::new(RootModule {}),
-                test_case.argument.request_path.as_str(),
-                &test_case.argument.request_method,
-            )
-            .map(|(_, route_path, _)| route_path);
+            let result = router
+                .find(
+                    &test_case.argument.request_method,
+                    test_case.argument.request_path.as_str(),
+                )
+                .map(|found| found.route.route_path.clone());
 
             assert_eq!(result, test_case.expected, "TC name: {}", test_case.name,);
         }
     }
+
+    #[test]
+    fn test_router_cors() {
+        use crate as rupring;
+        use crate::http::cors::CorsConfig;
+
+        #[derive(Debug, Clone, Copy)]
+        #[rupring::Module(
+            controllers=[HomeController{}, AdminController{}],
+            modules=[],
+            providers=[],
+            middlewares=[]
+        )]
+        pub struct RootModule {}
+
+        #[derive(Debug, Clone)]
+        #[rupring::Controller(prefix=/, routes=[hello])]
+        pub struct HomeController {}
+
+        #[derive(Debug, Clone)]
+        #[rupring::Controller(prefix=/admin, routes=[list_users], cors=admin_cors)]
+        pub struct AdminController {}
+
+        pub fn admin_cors() -> CorsConfig {
+            CorsConfig::new().allowed_origins(["https://admin.example.com"])
+        }
+
+        #[rupring::Get(path = /)]
+        pub fn hello(_request: rupring::Request) -> rupring::Response {
+            rupring::Response::new().text("hello")
+        }
+
+        #[rupring::Get(path = /users)]
+        pub fn list_users(_request: rupring::Request) -> rupring::Response {
+            rupring::Response::new().text("[]")
+        }
+
+        struct TestCase {
+            name: String,
+            path: String,
+            origin: String,
+            request_method: String,
+            expected_status: Option<u16>,
+        }
+
+        let router = Router::from_module(Box::new(RootModule {}), &Default::default());
+        let application_cors = CorsConfig::new().allowed_origins(["https://example.com"]);
+
+        let test_cases = vec![
+            TestCase {
+                name: "애플리케이션 설정 사용".to_string(),
+                path: "/".to_string(),
+                origin: "https://example.com".to_string(),
+                request_method: "GET".to_string(),
+                expected_status: Some(204),
+            },
+            TestCase {
+                name: "컨트롤러 설정으로 덮어쓰기".to_string(),
+                path: "/admin/users".to_string(),
+                origin: "https://example.com".to_string(),
+                request_method: "GET".to_string(),
+                expected_status: Some(403),
+            },
+            TestCase {
+                name: "컨트롤러 설정의 origin 허용".to_string(),
+                path: "/admin/users".to_string(),
+                origin: "https://admin.example.com".to_string(),
+                request_method: "GET".to_string(),
+                expected_status: Some(204),
+            },
+            TestCase {
+                name: "요청한 메서드의 라우트가 없으면 preflight 처리하지 않음".to_string(),
+                path: "/admin/users".to_string(),
+                origin: "https://admin.example.com".to_string(),
+                request_method: "DELETE".to_string(),
+                expected_status: None,
+            },
+        ];
+
+        for tc in test_cases {
+            let mut headers = hyper::HeaderMap::new();
+            headers.insert(
+                crate::header::ORIGIN,
+                tc.origin.parse().expect("invalid origin"),
+            );
+            headers.insert(
+                crate::header::ACCESS_CONTROL_REQUEST_METHOD,
+                tc.request_method.parse().expect("invalid method"),
+            );
+
+            let response = crate::core::cors_preflight_response(
+                &router,
+                &application_cors,
+                &headers,
+                &tc.path,
+            );
+
+            assert_eq!(
+                response.map(|response| response.status),
+                tc.expected_status,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
 }
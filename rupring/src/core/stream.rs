@@ -10,22 +10,26 @@ use crate::{error::Errors, http::sse::Event};
 
 pub type StreamChannelType = Result<Frame<Bytes>, Infallible>;
 
+// Frames that can be queued before `send_bytes` waits for the client to read them.
+pub(crate) const STREAM_CHANNEL_CAPACITY: usize = 16;
+
 /// Handler for managing streams.
 #[derive(Debug, Clone)]
 pub struct StreamHandler {
-    sender: tokio::sync::mpsc::UnboundedSender<StreamChannelType>,
+    sender: tokio::sync::mpsc::Sender<StreamChannelType>,
     closed: Arc<AtomicBool>,
 }
 
 impl StreamHandler {
     pub(crate) fn new(
-        sender: tokio::sync::mpsc::UnboundedSender<StreamChannelType>,
+        sender: tokio::sync::mpsc::Sender<StreamChannelType>,
         closed: Arc<AtomicBool>,
     ) -> Self {
         Self { sender, closed }
     }
 
     /// Send bytes to the stream.
+    /// Waits while the client is behind, so that a fast sender does not pile up the whole body in memory.
     /// Returns an error if the stream is closed or if sending fails.
     pub async fn send_bytes(&self, bytes: &[u8]) -> Result<(), Errors> {
         if self.closed.load(std::sync::atomic::Ordering::SeqCst) {
@@ -36,6 +40,7 @@ impl StreamHandler {
         let frame = Frame::data(bytes);
         self.sender
             .send(Ok(frame))
+            .await
             .map_err(|e| Errors::StreamSendError(e.to_string()))?;
 
         Ok(())
@@ -49,6 +54,10 @@ impl StreamHandler {
         self.send_bytes(sse_data.as_bytes()).await
     }
 
+    pub(crate) fn closed(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.closed)
+    }
+
     /// Check if the stream is closed.
     /// This can be used to stop sending data and stop tasks when the client disconnects.
     pub fn is_closed(&self) -> bool {
@@ -222,16 +222,134 @@ fn inject_counter_service(something: SomethingRepository) -> CounterService {
 )]
 pub struct RootModule {}
 ```
+
+## Primary and Qualifier
+- If several providers exist for the same type, the first one is used by default.
+- A provider with `primary = true` is used instead, and the other providers of the type are not provided.
+- A provider with `qualifier = "name"` is selected by the name, with [DIContext::get_qualified] or `#[qualifier = "name"]` on the parameter.
+```rust
+use std::sync::Arc;
+
+pub trait ICache {
+    fn name(&self) -> String;
+}
+
+pub struct RedisCache {}
+impl ICache for RedisCache {
+    fn name(&self) -> String { "redis".to_string() }
+}
+
+pub struct MemoryCache {}
+impl ICache for MemoryCache {
+    fn name(&self) -> String { "memory".to_string() }
+}
+
+#[rupring::Injectable(primary = true, qualifier = "redis")]
+fn inject_redis_cache() -> Arc<dyn ICache> {
+    Arc::new(RedisCache {})
+}
+
+#[rupring::Injectable(qualifier = "memory")]
+fn inject_memory_cache() -> Arc<dyn ICache> {
+    Arc::new(MemoryCache {})
+}
+
+pub struct SessionStore {
+    pub cache: Arc<dyn ICache>,
+}
+
+#[rupring::Injectable]
+fn inject_session_store(#[qualifier = "memory"] cache: Arc<dyn ICache>) -> SessionStore {
+    SessionStore { cache }
+}
+
+#[rupring::Get(path = /cache)]
+pub fn get_cache(request: rupring::Request) -> rupring::Response {
+    // primary provider
+    let cache = request.get_provider::<Arc<dyn ICache>>().unwrap();
+    assert_eq!(cache.name(), "redis");
+
+    let cache = request.get_qualified_provider::<Arc<dyn ICache>>("memory").unwrap();
+    assert_eq!(cache.name(), "memory");
+
+    rupring::Response::new()
+}
+```
+
+## Override and Slice
+- [DIContext::override_value] and [DIContext::override_provider] replace the providers of the modules for the type, which are not provided at all. (ex. a fake repository per environment, or a mock in tests)
+- The providers that depend on the type receive the overriding value.
+- [DIContext::initialize_slice] provides only the providers declared in the given modules, like `@WebMvcTest` of Spring.
+```rust
+use std::sync::Arc;
+
+pub trait IUserRepository {
+    fn name(&self) -> String;
+}
+
+pub struct FakeUserRepository {}
+impl IUserRepository for FakeUserRepository {
+    fn name(&self) -> String { "fake".to_string() }
+}
+
+pub struct UserService {
+    pub repository: Arc<dyn IUserRepository>,
+}
+
+#[rupring::Injectable]
+fn inject_user_service(repository: Arc<dyn IUserRepository>) -> UserService {
+    UserService { repository }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[rupring::Module(controllers=[], modules=[], providers=[inject_user_service{}], middlewares=[])]
+pub struct UserModule {}
+
+let mut di_context = rupring::DIContext::new();
+
+let repository: Arc<dyn IUserRepository> = Arc::new(FakeUserRepository {});
+di_context.override_value(repository);
+di_context.initialize_slice(vec![Box::new(UserModule {})]);
+
+let user_service = di_context.get::<UserService>().unwrap();
+assert_eq!(user_service.repository.name(), "fake");
+```
+- [crate::test::TestClient] provides the same features for tests.
 */
 
 use std::any::Any;
+use std::collections::HashSet;
 use std::panic::RefUnwindSafe;
 use std::{any::TypeId, collections::HashMap};
 
 #[derive(Default)]
 pub struct DIContext {
     pub containers: HashMap<TypeId, Box<dyn Any>>,
-    wait_list: Vec<Box<dyn IProvider + 'static>>,
+    // Values of the providers with a qualifier. (see [IProvider::qualifier])
+    qualified_containers: HashMap<(TypeId, String), Box<dyn Any>>,
+    // Qualifier of the primary provider, whose value is in `containers`.
+    primary_qualifiers: HashMap<TypeId, String>,
+    // Types whose value is not replaced by other providers anymore. (primary or override)
+    fixed_types: HashSet<TypeId>,
+    overridden_types: HashSet<TypeId>,
+    overridden_qualified_types: HashSet<(TypeId, String)>,
+    wait_list: Vec<PendingProvider>,
+}
+
+struct PendingProvider {
+    provider: Box<dyn IProvider + 'static>,
+    is_override: bool,
+}
+
+impl PendingProvider {
+    // Primary and overriding providers are provided before the dependents of the type.
+    fn is_preferred(&self) -> bool {
+        if self.is_override {
+            return self.provider.qualifier().is_none();
+        }
+
+        self.provider.primary()
+    }
 }
 
 unsafe impl Send for DIContext {}
@@ -242,6 +360,7 @@ impl std::fmt::Debug for DIContext {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("DIContext")
             .field("containers", &self.containers)
+            .field("qualified_containers", &self.qualified_containers)
             .field("wait_list.len", &self.wait_list.len())
             .finish()
     }
@@ -262,7 +381,50 @@ impl DIContext {
     }
 
     pub fn register_lazy<T: 'static>(&mut self, injectable: Box<dyn IProvider>) {
-        self.wait_list.push(injectable);
+        self.wait_list.push(PendingProvider {
+            provider: injectable,
+            is_override: false,
+        });
+    }
+
+    /// Replace the value of the type. The providers of the modules for the type are not provided. (ex. mock)
+    /// - It should be called before [DIContext::initialize].
+    pub fn override_value<T: 'static>(&mut self, value: T) {
+        let type_id = TypeId::of::<T>();
+
+        self.containers.insert(type_id, Box::new(value));
+        self.fixed_types.insert(type_id);
+        self.overridden_types.insert(type_id);
+    }
+
+    /// Replace the value of the type with the qualifier. (see [DIContext::override_value])
+    pub fn override_qualified_value<T: 'static>(&mut self, qualifier: impl ToString, value: T) {
+        let key = (TypeId::of::<T>(), qualifier.to_string());
+
+        self.qualified_containers
+            .insert(key.clone(), Box::new(value));
+        self.overridden_qualified_types.insert(key);
+    }
+
+    /// Replace the providers of the modules with the given provider, which is provided with its dependencies.
+    /// - The replaced type is known from [IProvider::provided_type]. (generated by [crate::Injectable])
+    /// - It should be called before [DIContext::initialize].
+    pub fn override_provider(&mut self, provider: impl IProvider + 'static) {
+        if let Some(type_id) = provider.provided_type() {
+            match provider.qualifier() {
+                Some(qualifier) => {
+                    self.overridden_qualified_types.insert((type_id, qualifier));
+                }
+                None => {
+                    self.overridden_types.insert(type_id);
+                }
+            }
+        }
+
+        self.wait_list.push(PendingProvider {
+            provider: Box::new(provider),
+            is_override: true,
+        });
     }
 
     pub fn get<T: 'static>(&self) -> Option<&T> {
@@ -271,53 +433,194 @@ impl DIContext {
             None => None,
         }
     }
-}
 
-impl DIContext {
-    fn import_from_modules(&mut self, root_module: Box<dyn crate::IModule>) {
-        let providers = root_module.providers();
+    /// Returns the value of the provider with the qualifier. (see [IProvider::qualifier])
+    pub fn get_qualified<T: 'static>(&self, qualifier: &str) -> Option<&T> {
+        let type_id = TypeId::of::<T>();
+
+        if let Some(value) = self
+            .qualified_containers
+            .get(&(type_id, qualifier.to_string()))
+        {
+            return value.downcast_ref::<T>();
+        }
 
-        for provider in providers {
-            self.wait_list.push(provider);
+        match self.primary_qualifiers.get(&type_id) {
+            Some(primary_qualifier) if primary_qualifier == qualifier => self.get::<T>(),
+            _ => None,
         }
+    }
+}
 
+impl DIContext {
+    fn import_from_modules(&mut self, root_module: Box<dyn crate::IModule>) {
         let child_modules = root_module.child_modules();
 
+        self.import_providers(root_module);
+
         for child_module in child_modules {
             self.import_from_modules(child_module);
         }
     }
 
+    fn import_providers(&mut self, module: Box<dyn crate::IModule>) {
+        for provider in module.providers() {
+            self.wait_list.push(PendingProvider {
+                provider,
+                is_override: false,
+            });
+        }
+    }
+
     pub fn initialize(&mut self, root_module: Box<dyn crate::IModule>) {
         self.import_from_modules(root_module);
 
+        self.provide_all();
+    }
+
+    /// Initialize with the providers declared in the given modules only. (not in their child modules)
+    /// - The dependencies outside the slice should be given with [DIContext::override_value]. (ex. `@WebMvcTest` of Spring)
+    pub fn initialize_slice(&mut self, modules: Vec<Box<dyn crate::IModule>>) {
+        for module in modules {
+            self.import_providers(module);
+        }
+
+        self.provide_all();
+    }
+
+    fn provide_all(&mut self) {
+        // The providers replaced by the overrides are not provided at all.
+        let wait_list = std::mem::take(&mut self.wait_list);
+        self.wait_list = wait_list
+            .into_iter()
+            .filter(|pending| pending.is_override || !self.is_overridden(pending))
+            .collect();
+
         while !self.wait_list.is_empty() {
-            let mut has_ready_provider = false;
+            let index = self
+                .wait_list
+                .iter()
+                .position(|pending| self.is_ready(pending));
 
-            for (i, provider) in self.wait_list.iter().enumerate() {
-                let dependencies = provider.dependencies();
+            let Some(index) = index else {
+                panic!("No provider is ready");
+            };
 
-                let mut is_ready = true;
+            let pending = self.wait_list.remove(index);
 
-                for dependency in dependencies {
-                    if !self.containers.contains_key(&dependency) {
-                        is_ready = false;
-                        break;
+            // A primary provider of the type is already provided.
+            if !pending.is_preferred() && pending.provider.qualifier().is_none() {
+                if let Some(type_id) = pending.provider.provided_type() {
+                    if self.fixed_types.contains(&type_id) {
+                        continue;
                     }
                 }
+            }
+
+            let provided_value = pending.provider.provide(self);
+            self.store(pending, provided_value);
+        }
+    }
+
+    fn is_overridden(&self, pending: &PendingProvider) -> bool {
+        let Some(type_id) = pending.provider.provided_type() else {
+            return false;
+        };
+
+        match pending.provider.qualifier() {
+            Some(qualifier) => self
+                .overridden_qualified_types
+                .contains(&(type_id, qualifier)),
+            None => self.overridden_types.contains(&type_id),
+        }
+    }
+
+    fn has_pending_preferred(&self, type_id: TypeId) -> bool {
+        self.wait_list.iter().any(|pending| {
+            pending.is_preferred() && pending.provider.provided_type() == Some(type_id)
+        })
+    }
+
+    fn is_ready(&self, pending: &PendingProvider) -> bool {
+        // The other providers of the type wait for the primary one, and are skipped after it.
+        if !pending.is_preferred() && pending.provider.qualifier().is_none() {
+            if let Some(type_id) = pending.provider.provided_type() {
+                if self.has_pending_preferred(type_id) {
+                    return false;
+                }
+            }
+        }
 
-                if is_ready {
-                    let provided_value = provider.provide(self);
-                    self.register(provided_value);
-                    self.wait_list.remove(i);
-                    has_ready_provider = true;
+        let dependencies_ready = pending
+            .provider
+            .dependencies()
+            .into_iter()
+            .all(|dependency| {
+                self.containers.contains_key(&dependency) && !self.has_pending_preferred(dependency)
+            });
+
+        let qualified_dependencies_ready = pending
+            .provider
+            .qualified_dependencies()
+            .into_iter()
+            .all(|(dependency, qualifier)| {
+                self.qualified_containers
+                    .contains_key(&(dependency, qualifier.clone()))
+                    || self.primary_qualifiers.get(&dependency) == Some(&qualifier)
+            });
+
+        dependencies_ready && qualified_dependencies_ready
+    }
+
+    fn store(&mut self, pending: PendingProvider, value: Box<dyn Any>) {
+        let type_id = (*value).type_id();
+        let primary = pending.provider.primary();
 
-                    break;
+        if pending.is_override {
+            match pending.provider.qualifier() {
+                Some(qualifier) => {
+                    self.qualified_containers
+                        .insert((type_id, qualifier), value);
+                }
+                None => {
+                    self.containers.insert(type_id, value);
+                    self.fixed_types.insert(type_id);
                 }
             }
 
-            if !has_ready_provider {
-                panic!("No provider is ready");
+            return;
+        }
+
+        match pending.provider.qualifier() {
+            // A primary provider with a qualifier is also the default value of the type.
+            Some(qualifier) if primary && !self.fixed_types.contains(&type_id) => {
+                self.containers.insert(type_id, value);
+                self.fixed_types.insert(type_id);
+                self.primary_qualifiers.insert(type_id, qualifier);
+            }
+            Some(qualifier) => {
+                self.qualified_containers
+                    .entry((type_id, qualifier))
+                    .or_insert(value);
+            }
+            None if primary => {
+                if self.fixed_types.contains(&type_id) {
+                    if self.overridden_types.contains(&type_id) {
+                        return;
+                    }
+
+                    panic!("Multiple primary providers for the same type");
+                }
+
+                self.containers.insert(type_id, value);
+                self.fixed_types.insert(type_id);
+            }
+            None => {
+                if self.fixed_types.contains(&type_id) {
+                    return;
+                }
+
+                self.register(value);
             }
         }
     }
@@ -328,5 +631,25 @@ pub trait IProvider {
         vec![]
     }
 
+    /// Dependencies with a qualifier. (see [IProvider::qualifier])
+    fn qualified_dependencies(&self) -> Vec<(TypeId, String)> {
+        vec![]
+    }
+
+    /// Type of the provided value. It is used to resolve the primary and overriding providers before they are provided.
+    fn provided_type(&self) -> Option<TypeId> {
+        None
+    }
+
+    /// Name to select one of several providers of the same type. ([DIContext::get_qualified])
+    fn qualifier(&self) -> Option<String> {
+        None
+    }
+
+    /// If true, this provider is selected when several providers exist for the same type.
+    fn primary(&self) -> bool {
+        false
+    }
+
     fn provide(&self, di_context: &DIContext) -> Box<dyn Any>;
 }
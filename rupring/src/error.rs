@@ -1,7 +1,130 @@
+/*!
+# About Error Handler
+- Errors that occur while processing a request are passed to the error handlers registered in the module.
+- An error handler can be keyed by status code, or by error type.
+- If no error handler handles the error, the default response of the framework is used.
+
+```rust
+#[derive(Debug)]
+pub struct UserNotFound {
+    pub id: i32,
+}
+
+impl std::fmt::Display for UserNotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "user {} not found", self.id)
+    }
+}
+
+impl std::error::Error for UserNotFound {}
+
+// Handles the UserNotFound error returned by route handlers.
+#[rupring::ErrorHandler]
+pub fn handle_user_not_found(error: &UserNotFound) -> rupring::Response {
+    rupring::Response::new().status(404).json(rupring::serde_json::json!({
+        "message": error.to_string(),
+    }))
+}
+
+// Handles every error with status 404. (ex. no matching route)
+#[rupring::ErrorHandler(status = 404)]
+pub fn handle_not_found(error: &rupring::error::HttpError) -> rupring::Response {
+    rupring::Response::new().status(404).json(rupring::serde_json::json!({
+        "message": "Not Found",
+        "path": error.path,
+    }))
+}
+
+#[rupring::Get(path = /users/:id)]
+pub fn get_user(#[PathVariable="id"] id: i32) -> Result<rupring::Response, UserNotFound> {
+    Err(UserNotFound { id })
+}
+
+#[derive(Debug, Clone)]
+#[rupring::Controller(prefix=/, routes=[get_user])]
+pub struct UserController {}
+
+#[derive(Debug, Clone, Copy)]
+#[rupring::Module(
+    controllers=[UserController{}],
+    modules=[],
+    providers=[],
+    middlewares=[],
+    error_handlers=[handle_user_not_found, handle_not_found]
+)]
+pub struct RootModule {}
+```
+
+- A route handler can return `Result<Response, E>`. `E` is passed to the error handlers. (It should be convertible into [anyhow::Error])
+- You can also attach an error to a response with [crate::Response::error].
+- Error handlers registered in a module apply to the routes of that module and its child modules. The handlers of the child module are tried first.
+- Errors that occur before a route is found (ex. 404, 405, 414) are passed to the error handlers of the root module.
+- The errors raised by the framework itself are [Errors] values, so they can also be handled by type.
+- An error handler without `status` that receives [HttpError] handles every error.
+
+# Problem Details
+- If `server.error.format=problem-json` is set in application.properties, the default error responses of the framework are returned in the RFC 7807 format. (`application/problem+json`)
+- The errors returned from handlers are also returned in the same format, if no error handler handles them.
+- The `detail` member is only filled for 4xx errors, so that internal error messages are not exposed.
+
+```json
+{
+    "type": "about:blank",
+    "title": "Not Found",
+    "status": 404,
+    "instance": "/asdf"
+}
+```
+
+- [crate::request::Request::bind] fails with [Errors::BindFailed] (or [Errors::ValidationFailed]), so the bind error can be returned as `400 Bad Request` with `?`.
+- For [Errors::ValidationFailed], the field errors are added as the `errors` member.
+```rust
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct CreateUserRequest {
+    pub name: String,
+}
+
+impl rupring::request::BindFromRequest for CreateUserRequest {
+    fn bind(request: rupring::Request) -> rupring::anyhow::Result<Self> {
+        Ok(rupring::serde_json::from_str(&request.body)?)
+    }
+}
+
+#[rupring::Post(path = /users)]
+pub fn create_user(request: rupring::Request) -> rupring::anyhow::Result<rupring::Response> {
+    let body = request.bind::<CreateUserRequest>()?;
+
+    Ok(rupring::Response::new().text(body.name))
+}
+```
+*/
+
+use std::sync::Arc;
+
+use crate::{validation::ValidationErrors, Method, Response};
+
 #[derive(Debug)]
 pub enum Errors {
     StreamClosed,
     StreamSendError(String),
+    NotFound,
+    MethodNotAllowed(Vec<Method>),
+    PayloadTooLarge,
+    UnsupportedContentEncoding(String),
+    DecompressionFailed(String),
+    RequestBodyReadFailed(String),
+    MultipartFailed(String),
+    FileTooLarge(String),
+    UriTooLong,
+    HeaderSizeTooBig,
+    HeaderFieldsTooLarge,
+    RequestTimeout,
+    BindFailed(String),
+    ValidationFailed(ValidationErrors),
+    WebSocketHandshakeFailed(String),
+    WebSocketError(String),
+    Panic(String),
+    Unknown(String),
 }
 
 impl std::fmt::Display for Errors {
@@ -9,8 +132,193 @@ impl std::fmt::Display for Errors {
         match self {
             Errors::StreamClosed => write!(f, "Stream is closed"),
             Errors::StreamSendError(msg) => write!(f, "Failed to send to stream: {}", msg),
+            Errors::NotFound => write!(f, "Not Found"),
+            Errors::MethodNotAllowed(_) => write!(f, "Method Not Allowed"),
+            Errors::PayloadTooLarge => write!(f, "Payload Too Large"),
+            Errors::UnsupportedContentEncoding(encoding) => {
+                write!(f, "Unsupported Content-Encoding: {}", encoding)
+            }
+            Errors::DecompressionFailed(msg) => {
+                write!(f, "Failed to decompress request body: {}", msg)
+            }
+            Errors::RequestBodyReadFailed(msg) => {
+                write!(f, "Failed to read request body: {}", msg)
+            }
+            Errors::MultipartFailed(msg) => write!(f, "Invalid multipart body: {}", msg),
+            Errors::FileTooLarge(name) => write!(f, "File Too Large: {}", name),
+            Errors::UriTooLong => write!(f, "URI Too Long"),
+            Errors::HeaderSizeTooBig => write!(f, "Header Size Too Big"),
+            Errors::HeaderFieldsTooLarge => write!(f, "Request Header Fields Too Large"),
+            Errors::RequestTimeout => write!(f, "Request Timeout"),
+            Errors::BindFailed(msg) => write!(f, "Bad Request: {}", msg),
+            Errors::ValidationFailed(errors) => write!(f, "Validation Failed: {}", errors),
+            Errors::WebSocketHandshakeFailed(msg) => {
+                write!(f, "WebSocket Handshake Failed: {}", msg)
+            }
+            Errors::WebSocketError(msg) => write!(f, "WebSocket Error: {}", msg),
+            Errors::Panic(msg) => write!(f, "Internal Server Error: {}", msg),
+            Errors::Unknown(msg) => write!(f, "Internal Server Error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Errors {}
+
+impl Errors {
+    /// Status code of the default response for the error.
+    pub fn status(&self) -> u16 {
+        match self {
+            Errors::NotFound => 404,
+            Errors::MethodNotAllowed(_) => 405,
+            Errors::PayloadTooLarge => 413,
+            Errors::UnsupportedContentEncoding(_) => 415,
+            Errors::DecompressionFailed(_) => 400,
+            Errors::RequestBodyReadFailed(_) => 400,
+            Errors::MultipartFailed(_) => 400,
+            Errors::FileTooLarge(_) => 413,
+            Errors::UriTooLong => 414,
+            Errors::HeaderSizeTooBig => 400,
+            Errors::HeaderFieldsTooLarge => 431,
+            Errors::BindFailed(_) => 400,
+            Errors::ValidationFailed(_) => 400,
+            Errors::WebSocketHandshakeFailed(_) => 400,
+            _ => 500,
         }
     }
 }
 
 pub type Result<T> = std::result::Result<T, Errors>;
+
+/// Error passed to error handlers.
+#[derive(Debug, Clone)]
+pub struct HttpError {
+    /// Status code of the default response for the error.
+    pub status: u16,
+    pub method: Method,
+    pub path: String,
+    pub error: Arc<anyhow::Error>,
+}
+
+impl HttpError {
+    pub fn new(status: u16, method: Method, path: impl ToString, error: anyhow::Error) -> Self {
+        HttpError {
+            status,
+            method,
+            path: path.to_string(),
+            error: Arc::new(error),
+        }
+    }
+
+    /// Returns the error as the given type, if it is.
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: std::fmt::Display + std::fmt::Debug + Send + Sync + 'static,
+    {
+        self.error.downcast_ref::<E>()
+    }
+}
+
+/// Error handler interface
+/// - Returns `None` if the handler does not handle the error.
+pub trait IErrorHandler {
+    fn handle(&self, error: &HttpError) -> Option<Response>;
+
+    /// Responses documented on every route the error handler applies to.
+    fn swagger_responses(&self) -> Vec<crate::swagger::macros::SwaggerResponseInfo> {
+        vec![]
+    }
+}
+
+/// Error handler type registered in modules
+pub type ErrorHandlerFunction = Box<dyn IErrorHandler + Send + Sync + 'static>;
+
+// The first error handler that handles the error wins.
+pub(crate) fn find_error_response(
+    error_handlers: &[ErrorHandlerFunction],
+    error: &HttpError,
+) -> Option<Response> {
+    error_handlers
+        .iter()
+        .find_map(|error_handler| error_handler.handle(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct UserNotFound {}
+
+    impl std::fmt::Display for UserNotFound {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "user not found")
+        }
+    }
+
+    impl std::error::Error for UserNotFound {}
+
+    struct StatusErrorHandler(u16);
+
+    impl IErrorHandler for StatusErrorHandler {
+        fn handle(&self, error: &HttpError) -> Option<Response> {
+            if error.status != self.0 {
+                return None;
+            }
+
+            Some(Response::new().status(self.0).text("status"))
+        }
+    }
+
+    struct UserNotFoundHandler {}
+
+    impl IErrorHandler for UserNotFoundHandler {
+        fn handle(&self, error: &HttpError) -> Option<Response> {
+            error.downcast_ref::<UserNotFound>()?;
+
+            Some(Response::new().status(404).text("user"))
+        }
+    }
+
+    #[test]
+    fn test_find_error_response() {
+        struct TestCase {
+            name: String,
+            error: HttpError,
+            expected: Option<(u16, String)>,
+        }
+
+        let error_handlers: Vec<ErrorHandlerFunction> = vec![
+            Box::new(UserNotFoundHandler {}),
+            Box::new(StatusErrorHandler(404)),
+        ];
+
+        let test_cases = [
+            TestCase {
+                name: "에러 타입으로 처리".to_string(),
+                error: HttpError::new(500, Method::GET, "/users/1", UserNotFound {}.into()),
+                expected: Some((404, "user".to_string())),
+            },
+            TestCase {
+                name: "상태 코드로 처리".to_string(),
+                error: HttpError::new(404, Method::GET, "/asdf", Errors::NotFound.into()),
+                expected: Some((404, "status".to_string())),
+            },
+            TestCase {
+                name: "처리할 핸들러 없음".to_string(),
+                error: HttpError::new(414, Method::GET, "/asdf", Errors::UriTooLong.into()),
+                expected: None,
+            },
+        ];
+
+        for tc in test_cases {
+            let result = find_error_response(&error_handlers, &tc.error).map(|response| {
+                (
+                    response.status,
+                    String::from_utf8(response.data.into_bytes()).unwrap(),
+                )
+            });
+
+            assert_eq!(result, tc.expected, "{} failed", tc.name);
+        }
+    }
+}
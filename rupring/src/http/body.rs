@@ -0,0 +1,343 @@
+/*!
+# About Request Body Streams
+- By default, the request body is fully read into `Request::raw_body` before the handler is called.
+- [BodyStream] reads the incoming body chunk by chunk instead, so that large bodies are not held in memory. (ex. [crate::http::multipart::Multipart])
+- The next chunk is read from the connection only when it is requested, so a slow handler slows down the client. (backpressure)
+- The size limit is checked for each chunk, and exceeding it fails with [crate::error::Errors::PayloadTooLarge].
+
+## Streaming Request Body
+- A route with `stream_body = true` does not read the body before the handler, and the handler takes it with [crate::Request::body_stream].
+- The limit is `server.request.body.max-length`, and a request whose Content-Length is larger than it is rejected before the handler.
+- The body is passed as it is received, without decompression. If `server.request.body.decompression-enabled` is true (the default), a request with a `Content-Encoding` other than `identity` is rejected with `415 Unsupported Media Type`.
+
+```rust
+#[rupring::Post(path = /events, stream_body = true)]
+pub async fn ingest(request: rupring::Request) -> rupring::Response {
+    let mut body = match request.body_stream() {
+        Ok(body) => body,
+        Err(error) => return rupring::Response::new().error(error),
+    };
+
+    // NDJSON: one JSON document per line
+    let mut count = 0;
+    loop {
+        match body.next_line().await {
+            Ok(Some(line)) => {
+                println!("event: {}", String::from_utf8_lossy(&line));
+                count += 1;
+            }
+            Ok(None) => break,
+            Err(error) => return rupring::Response::new().error(error),
+        }
+    }
+
+    rupring::Response::new().text(format!("{count} events"))
+}
+```
+
+- [BodyStream] also implements `futures::Stream`, so it can be used with stream combinators.
+*/
+
+use std::{
+    fmt::Debug,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+
+use crate::error::Errors;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+type ChunkStream = dyn Stream<Item = Result<Bytes, BoxError>> + Send;
+
+/// Stream of the incoming request body.
+pub struct BodyStream {
+    inner: Pin<Box<ChunkStream>>,
+    limit: Option<usize>,
+    received: usize,
+    // Bytes read ahead of the line that was returned last. (see [BodyStream::next_line])
+    pending: Bytes,
+    done: bool,
+}
+
+impl Debug for BodyStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BodyStream")
+            .field("limit", &self.limit)
+            .field("received", &self.received)
+            .finish()
+    }
+}
+
+impl BodyStream {
+    pub(crate) fn new(
+        stream: impl Stream<Item = Result<Bytes, BoxError>> + Send + 'static,
+        limit: Option<usize>,
+    ) -> Self {
+        Self {
+            inner: Box::pin(stream),
+            limit,
+            received: 0,
+            pending: Bytes::new(),
+            done: false,
+        }
+    }
+
+    /// Body stream of the bytes that are already read. (ex. AWS Lambda)
+    pub(crate) fn from_bytes(bytes: impl Into<Bytes>, limit: Option<usize>) -> Self {
+        let bytes: Bytes = bytes.into();
+
+        Self::new(futures_util::stream::iter([Ok(bytes)]), limit)
+    }
+
+    /// Receive the next chunk of the body. Returns `None` at the end of the body.
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>, Errors> {
+        self.next().await.transpose()
+    }
+
+    /// Receive the next line of the body, without the line ending. (`\n` or `\r\n`)
+    /// - The last line is returned even if it does not end with a line ending.
+    pub async fn next_line(&mut self) -> Result<Option<Bytes>, Errors> {
+        let mut line = Vec::new();
+
+        loop {
+            let chunk = match self.chunk().await? {
+                Some(chunk) => chunk,
+                None if line.is_empty() => return Ok(None),
+                None => return Ok(Some(line.into())),
+            };
+
+            match chunk.iter().position(|e| *e == b'\n') {
+                Some(index) => {
+                    line.extend_from_slice(&chunk[..index]);
+                    self.pending = chunk.slice(index + 1..);
+
+                    if line.last() == Some(&b'\r') {
+                        line.pop();
+                    }
+
+                    return Ok(Some(line.into()));
+                }
+                None => line.extend_from_slice(&chunk),
+            }
+        }
+    }
+
+    /// Read the whole remaining body.
+    pub async fn collect(mut self) -> Result<Vec<u8>, Errors> {
+        let mut body = Vec::new();
+
+        while let Some(chunk) = self.chunk().await? {
+            body.extend_from_slice(&chunk);
+        }
+
+        Ok(body)
+    }
+
+    /// The number of bytes received so far.
+    pub fn received(&self) -> usize {
+        self.received
+    }
+}
+
+impl Stream for BodyStream {
+    type Item = Result<Bytes, Errors>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if !self.pending.is_empty() {
+            return Poll::Ready(Some(Ok(std::mem::take(&mut self.pending))));
+        }
+
+        loop {
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            let chunk = match self.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => chunk,
+                Poll::Ready(Some(Err(error))) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(Errors::RequestBodyReadFailed(
+                        error.to_string(),
+                    ))));
+                }
+                Poll::Ready(None) => {
+                    self.done = true;
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+
+            // Empty chunks are skipped.
+            if chunk.is_empty() {
+                continue;
+            }
+
+            self.received += chunk.len();
+
+            if let Some(limit) = self.limit {
+                if self.received > limit {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(Errors::PayloadTooLarge)));
+                }
+            }
+
+            return Poll::Ready(Some(Ok(chunk)));
+        }
+    }
+}
+
+/// Value shared by the clones of a request, which can be taken only once. (ex. the body stream)
+pub(crate) struct TakeOnce<T>(Arc<Mutex<Option<T>>>);
+
+impl<T> TakeOnce<T> {
+    pub(crate) fn new(value: T) -> Self {
+        Self(Arc::new(Mutex::new(Some(value))))
+    }
+
+    pub(crate) fn take(&self) -> Option<T> {
+        self.0.lock().ok().and_then(|mut value| value.take())
+    }
+}
+
+impl<T> Default for TakeOnce<T> {
+    fn default() -> Self {
+        Self(Arc::new(Mutex::new(None)))
+    }
+}
+
+impl<T> Clone for TakeOnce<T> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Debug for TakeOnce<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let available = self.0.lock().map(|e| e.is_some()).unwrap_or_default();
+
+        f.debug_struct("TakeOnce")
+            .field("available", &available)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunked_body(chunks: &[&str], limit: Option<usize>) -> BodyStream {
+        let chunks = chunks
+            .iter()
+            .map(|e| Ok(Bytes::copy_from_slice(e.as_bytes())))
+            .collect::<Vec<_>>();
+
+        BodyStream::new(futures_util::stream::iter(chunks), limit)
+    }
+
+    #[test]
+    fn test_body_stream_chunk() {
+        struct TestCase {
+            name: String,
+            chunks: Vec<&'static str>,
+            limit: Option<usize>,
+            expected: Vec<Result<String, String>>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "빈 청크는 건너뜀".to_string(),
+                chunks: vec!["abc", "", "def"],
+                limit: None,
+                expected: vec![Ok("abc".to_string()), Ok("def".to_string())],
+            },
+            TestCase {
+                name: "제한 이하".to_string(),
+                chunks: vec!["abc", "def"],
+                limit: Some(6),
+                expected: vec![Ok("abc".to_string()), Ok("def".to_string())],
+            },
+            TestCase {
+                name: "제한 초과 이후에는 종료".to_string(),
+                chunks: vec!["abc", "def", "ghi"],
+                limit: Some(5),
+                expected: vec![Ok("abc".to_string()), Err("Payload Too Large".to_string())],
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            let mut body = chunked_body(&tc.chunks, tc.limit);
+
+            let got = runtime.block_on(async {
+                let mut got = vec![];
+
+                while let Some(chunk) = body.next().await {
+                    got.push(
+                        chunk
+                            .map(|e| String::from_utf8(e.to_vec()).unwrap())
+                            .map_err(|e| e.to_string()),
+                    );
+                }
+
+                got
+            });
+
+            assert_eq!(got, tc.expected, "{} failed", tc.name);
+        }
+    }
+
+    #[test]
+    fn test_body_stream_next_line() {
+        struct TestCase {
+            name: String,
+            chunks: Vec<&'static str>,
+            expected: Vec<String>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "한 청크에 여러 줄".to_string(),
+                chunks: vec!["{\"a\":1}\n{\"a\":2}\n"],
+                expected: vec!["{\"a\":1}".to_string(), "{\"a\":2}".to_string()],
+            },
+            TestCase {
+                name: "청크에 걸친 줄".to_string(),
+                chunks: vec!["{\"a\"", ":1}\r", "\n{\"a\":2}"],
+                expected: vec!["{\"a\":1}".to_string(), "{\"a\":2}".to_string()],
+            },
+            TestCase {
+                name: "빈 줄".to_string(),
+                chunks: vec!["a\n\nb\n"],
+                expected: vec!["a".to_string(), "".to_string(), "b".to_string()],
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            let mut body = chunked_body(&tc.chunks, None);
+
+            let got = runtime.block_on(async {
+                let mut got = vec![];
+
+                while let Some(line) = body.next_line().await.unwrap() {
+                    got.push(String::from_utf8(line.to_vec()).unwrap());
+                }
+
+                got
+            });
+
+            assert_eq!(got, tc.expected, "{} failed", tc.name);
+        }
+    }
+}
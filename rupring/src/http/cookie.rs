@@ -114,3 +114,31 @@ pub(crate) fn parse_cookie_header(cookie_header: &str) -> HashMap<String, String
 
     cookies
 }
+
+// Parses a Set-Cookie header value of a response. (ex. "name=value; Path=/; HttpOnly")
+pub(crate) fn parse_set_cookie_header(set_cookie_header: &str) -> Option<Cookie> {
+    let mut parts = set_cookie_header.split(';').map(|e| e.trim());
+
+    let (name, value) = parts.next()?.split_once('=')?;
+    let mut cookie = Cookie::new(name.trim(), value.trim());
+
+    for attribute in parts {
+        let (key, value) = match attribute.split_once('=') {
+            Some((key, value)) => (key.trim(), Some(value.trim())),
+            None => (attribute, None),
+        };
+
+        match (key.to_ascii_lowercase().as_str(), value) {
+            ("expires", Some(value)) => cookie.expires = Some(value.to_string()),
+            ("max-age", Some(value)) => cookie.max_age = Some(value.to_string()),
+            ("domain", Some(value)) => cookie.domain = Some(value.to_string()),
+            ("path", Some(value)) => cookie.path = Some(value.to_string()),
+            ("samesite", Some(value)) => cookie.same_site = Some(value.to_string()),
+            ("secure", _) => cookie.secure = Some(true),
+            ("httponly", _) => cookie.http_only = Some(true),
+            _ => {}
+        }
+    }
+
+    Some(cookie)
+}
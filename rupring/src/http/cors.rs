@@ -0,0 +1,555 @@
+/*!
+# About CORS
+- CORS is enabled for the whole application with `server.cors.*` in application.properties. (see [crate::application_properties])
+- Preflight requests (`OPTIONS` with `Origin` and `Access-Control-Request-Method`) are answered before the route handlers run, with the CORS configuration of the requested route.
+- For the other requests, the CORS headers are added to the response if the origin is allowed.
+
+```properties
+server.cors.enabled=true
+server.cors.allowed-origins=https://example.com,https://api-*.example.com
+server.cors.allowed-methods=GET,POST,PUT,DELETE
+server.cors.allowed-headers=*
+server.cors.exposed-headers=x-total-count
+server.cors.allow-credentials=true
+server.cors.max-age=3600
+```
+
+A controller can override the configuration with the `cors` attribute.
+The configuration of the controller replaces the application configuration for its routes.
+```rust
+use rupring::http::cors::CorsConfig;
+
+pub fn admin_cors() -> CorsConfig {
+    CorsConfig::new()
+        .allowed_origins(["https://admin.example.com"])
+        .allowed_methods(["GET", "POST"])
+        .allow_credentials(true)
+}
+
+#[rupring::Get(path = /admin/users)]
+pub fn list_users(_request: rupring::Request) -> rupring::Response {
+    rupring::Response::new().text("[]")
+}
+
+#[derive(Debug, Clone)]
+#[rupring::Controller(prefix=/, routes=[list_users], cors=admin_cors)]
+pub struct AdminController {}
+```
+
+- Origin patterns can contain `*`. (ex. `https://api-*.example.com`, `http://localhost:*`)
+- `*` alone can't be used with `allow-credentials=true`, because every website could then send credentialed requests and read the responses. The application panics at startup for this combination, so list the origins or use patterns instead.
+- If the origin of a preflight request is not allowed, the response is `403 Forbidden`.
+*/
+
+use crate::{header, HeaderName, Response};
+
+/// CORS configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorsConfig {
+    pub enabled: bool,
+    /// Allowed origins. (`*` for every origin, or patterns such as `https://*.example.com`)
+    pub allowed_origins: Vec<String>,
+    /// Allowed methods. (`*` for every method)
+    pub allowed_methods: Vec<String>,
+    /// Allowed request headers. (`*` for every header)
+    pub allowed_headers: Vec<String>,
+    /// Response headers that can be read by the browser.
+    pub exposed_headers: Vec<String>,
+    pub allow_credentials: bool,
+    /// How long the result of a preflight request can be cached. (in seconds)
+    pub max_age: Option<u64>,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        CorsConfig {
+            enabled: false,
+            allowed_origins: vec![],
+            allowed_methods: vec!["GET".to_string(), "HEAD".to_string(), "POST".to_string()],
+            allowed_headers: vec!["*".to_string()],
+            exposed_headers: vec![],
+            allow_credentials: false,
+            max_age: Some(1800),
+        }
+    }
+}
+
+impl CorsConfig {
+    /// Create an enabled CORS configuration. (No origin is allowed until [Self::allowed_origins] is set)
+    pub fn new() -> Self {
+        CorsConfig {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    /// Set whether CORS is enabled.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Set the allowed origins.
+    /// - Panics if `*` is used with credentials. (see [Self::allow_credentials])
+    pub fn allowed_origins<T: ToString>(mut self, origins: impl IntoIterator<Item = T>) -> Self {
+        self.allowed_origins = origins.into_iter().map(|e| e.to_string()).collect();
+        self.assert_valid();
+        self
+    }
+
+    /// Set the allowed methods.
+    pub fn allowed_methods<T: ToString>(mut self, methods: impl IntoIterator<Item = T>) -> Self {
+        self.allowed_methods = methods.into_iter().map(|e| e.to_string()).collect();
+        self
+    }
+
+    /// Set the allowed request headers.
+    pub fn allowed_headers<T: ToString>(mut self, headers: impl IntoIterator<Item = T>) -> Self {
+        self.allowed_headers = headers.into_iter().map(|e| e.to_string()).collect();
+        self
+    }
+
+    /// Set the exposed response headers.
+    pub fn exposed_headers<T: ToString>(mut self, headers: impl IntoIterator<Item = T>) -> Self {
+        self.exposed_headers = headers.into_iter().map(|e| e.to_string()).collect();
+        self
+    }
+
+    /// Set whether credentials (cookies, authorization headers) are allowed.
+    /// - Panics if the allowed origins contain `*`. List the origins, or use patterns such as `https://*.example.com`.
+    pub fn allow_credentials(mut self, allow_credentials: bool) -> Self {
+        self.allow_credentials = allow_credentials;
+        self.assert_valid();
+        self
+    }
+
+    /// Set the max age of the preflight result. (in seconds)
+    pub fn max_age(mut self, max_age: u64) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    // "*" with credentials would let every website send credentialed requests and read the responses. (same as Spring)
+    pub(crate) fn assert_valid(&self) {
+        if self.allow_credentials && self.has_wildcard_origin() {
+            panic!(
+                "CORS: allowed origins can't be \"*\" when credentials are allowed. List the origins, or use patterns such as \"https://*.example.com\"."
+            );
+        }
+    }
+
+    fn has_wildcard_origin(&self) -> bool {
+        self.allowed_origins.iter().any(|e| e.trim() == "*")
+    }
+
+    /// Check if the origin is allowed.
+    /// - With credentials, only explicit origins and patterns are matched. (`*` is ignored)
+    pub fn is_origin_allowed(&self, origin: &str) -> bool {
+        self.allowed_origins
+            .iter()
+            .map(|pattern| pattern.trim())
+            .filter(|pattern| !(self.allow_credentials && *pattern == "*"))
+            .any(|pattern| match_pattern(pattern, origin))
+    }
+
+    fn is_method_allowed(&self, method: &str) -> bool {
+        self.allowed_methods
+            .iter()
+            .any(|e| e == "*" || e.eq_ignore_ascii_case(method))
+    }
+
+    fn is_header_allowed(&self, header: &str) -> bool {
+        self.allowed_headers
+            .iter()
+            .any(|e| e == "*" || e.eq_ignore_ascii_case(header))
+    }
+
+    // "*" can't be used with credentials, so the matched origin is returned as it is.
+    fn allow_origin_value(&self, origin: &str) -> String {
+        if !self.allow_credentials && self.has_wildcard_origin() {
+            "*".to_string()
+        } else {
+            origin.to_string()
+        }
+    }
+
+    /// Response of a preflight request.
+    /// - `request_headers` is the value of the `Access-Control-Request-Headers` header.
+    pub fn preflight_response(
+        &self,
+        origin: &str,
+        request_method: &str,
+        request_headers: Option<&str>,
+    ) -> Response {
+        let request_headers = request_headers
+            .unwrap_or_default()
+            .split(',')
+            .map(|e| e.trim())
+            .filter(|e| !e.is_empty())
+            .collect::<Vec<_>>();
+
+        let mut response = Response::new().header(
+            header::VARY,
+            "Origin, Access-Control-Request-Method, Access-Control-Request-Headers",
+        );
+
+        if !self.is_origin_allowed(origin)
+            || !self.is_method_allowed(request_method)
+            || !request_headers.iter().all(|e| self.is_header_allowed(e))
+        {
+            return response.status(403).text("Invalid CORS request");
+        }
+
+        let allow_methods = if self.allowed_methods.iter().any(|e| e == "*") {
+            request_method.to_string()
+        } else {
+            self.allowed_methods.join(", ")
+        };
+
+        response = response
+            .status(204)
+            .header(
+                header::ACCESS_CONTROL_ALLOW_ORIGIN,
+                self.allow_origin_value(origin),
+            )
+            .header(header::ACCESS_CONTROL_ALLOW_METHODS, allow_methods);
+
+        if !request_headers.is_empty() {
+            let allow_headers = if self.allowed_headers.iter().any(|e| e == "*") {
+                request_headers.join(", ")
+            } else {
+                self.allowed_headers.join(", ")
+            };
+
+            response = response.header(header::ACCESS_CONTROL_ALLOW_HEADERS, allow_headers);
+        }
+
+        if self.allow_credentials {
+            response = response.header(header::ACCESS_CONTROL_ALLOW_CREDENTIALS, "true");
+        }
+
+        if let Some(max_age) = self.max_age {
+            response = response.header(header::ACCESS_CONTROL_MAX_AGE, max_age);
+        }
+
+        response
+    }
+
+    /// Add the CORS headers to the response of an actual request, if the origin is allowed.
+    pub fn apply(&self, origin: &str, mut response: Response) -> Response {
+        response = response.header(header::VARY, "Origin");
+
+        if !self.is_origin_allowed(origin) {
+            return response;
+        }
+
+        // overwrite the default value of SSE responses
+        response.headers.insert(
+            HeaderName::from_static(header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            vec![self.allow_origin_value(origin)],
+        );
+
+        if self.allow_credentials {
+            response = response.header(header::ACCESS_CONTROL_ALLOW_CREDENTIALS, "true");
+        }
+
+        if !self.exposed_headers.is_empty() {
+            response = response.header(
+                header::ACCESS_CONTROL_EXPOSE_HEADERS,
+                self.exposed_headers.join(", "),
+            );
+        }
+
+        response
+    }
+}
+
+// "*" matches any characters. (case-insensitive)
+fn match_pattern(pattern: &str, origin: &str) -> bool {
+    let pattern = pattern.to_ascii_lowercase();
+    let origin = origin.to_ascii_lowercase();
+
+    let mut parts = pattern.split('*');
+
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = origin.strip_prefix(first) else {
+        return false;
+    };
+
+    let mut parts = parts.collect::<Vec<_>>();
+    let Some(last) = parts.pop() else {
+        // no wildcard
+        return rest.is_empty();
+    };
+
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header_of(response: &Response, name: &'static str) -> Option<String> {
+        response
+            .headers
+            .get(&HeaderName::from_static(name))
+            .map(|e| e.join(", "))
+    }
+
+    #[test]
+    fn test_match_pattern() {
+        struct TestCase {
+            name: String,
+            pattern: String,
+            origin: String,
+            expected: bool,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "정확히 일치".to_string(),
+                pattern: "https://example.com".to_string(),
+                origin: "https://example.com".to_string(),
+                expected: true,
+            },
+            TestCase {
+                name: "대소문자 무시".to_string(),
+                pattern: "https://example.com".to_string(),
+                origin: "https://EXAMPLE.com".to_string(),
+                expected: true,
+            },
+            TestCase {
+                name: "다른 origin".to_string(),
+                pattern: "https://example.com".to_string(),
+                origin: "https://example.com.evil.com".to_string(),
+                expected: false,
+            },
+            TestCase {
+                name: "모든 origin".to_string(),
+                pattern: "*".to_string(),
+                origin: "http://localhost:3000".to_string(),
+                expected: true,
+            },
+            TestCase {
+                name: "서브도메인 패턴".to_string(),
+                pattern: "https://*.example.com".to_string(),
+                origin: "https://api.example.com".to_string(),
+                expected: true,
+            },
+            TestCase {
+                name: "서브도메인 패턴 - 다른 도메인".to_string(),
+                pattern: "https://*.example.com".to_string(),
+                origin: "https://api.example.org".to_string(),
+                expected: false,
+            },
+            TestCase {
+                name: "포트 패턴".to_string(),
+                pattern: "http://localhost:*".to_string(),
+                origin: "http://localhost:8080".to_string(),
+                expected: true,
+            },
+        ];
+
+        for tc in test_cases {
+            assert_eq!(
+                match_pattern(&tc.pattern, &tc.origin),
+                tc.expected,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_preflight_response() {
+        struct TestCase {
+            name: String,
+            config: CorsConfig,
+            origin: String,
+            request_method: String,
+            request_headers: Option<String>,
+            expected_status: u16,
+            expected_headers: Vec<(&'static str, Option<String>)>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "허용된 origin".to_string(),
+                config: CorsConfig::new()
+                    .allowed_origins(["https://example.com"])
+                    .allowed_methods(["GET", "POST"])
+                    .allow_credentials(true)
+                    .max_age(3600),
+                origin: "https://example.com".to_string(),
+                request_method: "POST".to_string(),
+                request_headers: Some("content-type, x-token".to_string()),
+                expected_status: 204,
+                expected_headers: vec![
+                    (
+                        header::ACCESS_CONTROL_ALLOW_ORIGIN,
+                        Some("https://example.com".to_string()),
+                    ),
+                    (
+                        header::ACCESS_CONTROL_ALLOW_METHODS,
+                        Some("GET, POST".to_string()),
+                    ),
+                    (
+                        header::ACCESS_CONTROL_ALLOW_HEADERS,
+                        Some("content-type, x-token".to_string()),
+                    ),
+                    (
+                        header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+                        Some("true".to_string()),
+                    ),
+                    (header::ACCESS_CONTROL_MAX_AGE, Some("3600".to_string())),
+                ],
+            },
+            TestCase {
+                name: "credentials 없이 모든 origin 허용".to_string(),
+                config: CorsConfig::new().allowed_origins(["*"]),
+                origin: "https://example.com".to_string(),
+                request_method: "GET".to_string(),
+                request_headers: None,
+                expected_status: 204,
+                expected_headers: vec![
+                    (header::ACCESS_CONTROL_ALLOW_ORIGIN, Some("*".to_string())),
+                    (header::ACCESS_CONTROL_ALLOW_HEADERS, None),
+                    (header::ACCESS_CONTROL_ALLOW_CREDENTIALS, None),
+                ],
+            },
+            TestCase {
+                // A config built without the builder is not checked, but "*" is still ignored.
+                name: "credentials가 있으면 *는 무시".to_string(),
+                config: CorsConfig {
+                    enabled: true,
+                    allowed_origins: vec!["*".to_string()],
+                    allow_credentials: true,
+                    ..Default::default()
+                },
+                origin: "https://evil.com".to_string(),
+                request_method: "GET".to_string(),
+                request_headers: None,
+                expected_status: 403,
+                expected_headers: vec![
+                    (header::ACCESS_CONTROL_ALLOW_ORIGIN, None),
+                    (header::ACCESS_CONTROL_ALLOW_CREDENTIALS, None),
+                ],
+            },
+            TestCase {
+                name: "credentials가 있으면 패턴과 일치하는 origin만 허용".to_string(),
+                config: CorsConfig {
+                    enabled: true,
+                    allowed_origins: vec!["*".to_string(), "https://*.example.com".to_string()],
+                    allow_credentials: true,
+                    ..Default::default()
+                },
+                origin: "https://api.example.com".to_string(),
+                request_method: "GET".to_string(),
+                request_headers: None,
+                expected_status: 204,
+                expected_headers: vec![
+                    (
+                        header::ACCESS_CONTROL_ALLOW_ORIGIN,
+                        Some("https://api.example.com".to_string()),
+                    ),
+                    (
+                        header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+                        Some("true".to_string()),
+                    ),
+                ],
+            },
+            TestCase {
+                name: "허용되지 않은 origin".to_string(),
+                config: CorsConfig::new().allowed_origins(["https://example.com"]),
+                origin: "https://evil.com".to_string(),
+                request_method: "GET".to_string(),
+                request_headers: None,
+                expected_status: 403,
+                expected_headers: vec![(header::ACCESS_CONTROL_ALLOW_ORIGIN, None)],
+            },
+            TestCase {
+                name: "허용되지 않은 메서드".to_string(),
+                config: CorsConfig::new().allowed_origins(["*"]),
+                origin: "https://example.com".to_string(),
+                request_method: "DELETE".to_string(),
+                request_headers: None,
+                expected_status: 403,
+                expected_headers: vec![(header::ACCESS_CONTROL_ALLOW_ORIGIN, None)],
+            },
+            TestCase {
+                name: "허용되지 않은 헤더".to_string(),
+                config: CorsConfig::new()
+                    .allowed_origins(["*"])
+                    .allowed_headers(["content-type"]),
+                origin: "https://example.com".to_string(),
+                request_method: "GET".to_string(),
+                request_headers: Some("content-type, x-token".to_string()),
+                expected_status: 403,
+                expected_headers: vec![(header::ACCESS_CONTROL_ALLOW_ORIGIN, None)],
+            },
+        ];
+
+        for tc in test_cases {
+            let response = tc.config.preflight_response(
+                &tc.origin,
+                &tc.request_method,
+                tc.request_headers.as_deref(),
+            );
+
+            assert_eq!(response.status, tc.expected_status, "{} failed", tc.name);
+
+            for (name, expected) in tc.expected_headers {
+                assert_eq!(
+                    header_of(&response, name),
+                    expected,
+                    "{} failed: {}",
+                    tc.name,
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "allowed origins can't be \"*\" when credentials are allowed")]
+    fn test_wildcard_origin_with_credentials() {
+        CorsConfig::new()
+            .allowed_origins(["*"])
+            .allow_credentials(true);
+    }
+
+    #[test]
+    fn test_apply() {
+        let config = CorsConfig::new()
+            .allowed_origins(["https://*.example.com"])
+            .exposed_headers(["x-total-count"])
+            .allow_credentials(true);
+
+        let response = config.apply("https://api.example.com", Response::new());
+        assert_eq!(
+            header_of(&response, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            Some("https://api.example.com".to_string())
+        );
+        assert_eq!(
+            header_of(&response, header::ACCESS_CONTROL_EXPOSE_HEADERS),
+            Some("x-total-count".to_string())
+        );
+        assert_eq!(
+            header_of(&response, header::VARY),
+            Some("Origin".to_string())
+        );
+
+        let response = config.apply("https://evil.com", Response::new());
+        assert_eq!(
+            header_of(&response, header::ACCESS_CONTROL_ALLOW_ORIGIN),
+            None
+        );
+    }
+}
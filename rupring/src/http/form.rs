@@ -0,0 +1,416 @@
+/*!
+`application/x-www-form-urlencoded` parsing. (used for query strings and form bodies)
+
+- Keys and values are percent-decoded, and `+` is decoded as a space.
+- `a[]=1&a[]=2` is the same as `a=1&a=2`.
+- Nested keys like `user[name]=foo` and `items[0][id]=1` can be deserialized into nested structs and `Vec`s.
+- If a single value is expected but there are multiple values, the first value is used.
+- An empty value (`a=`) of an `Option` field is `None`.
+
+```rust
+use std::collections::HashMap;
+
+#[derive(Debug, serde::Deserialize)]
+struct Address {
+    city: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct SignUpForm {
+    name: String,
+    age: Option<i32>,
+    tags: Vec<String>,
+    address: Address,
+}
+
+let fields = rupring::http::form::parse_form_urlencoded(b"name=John+Doe&age=&tags[]=a&tags[]=b&address[city]=Seoul");
+assert_eq!(fields["name"], vec!["John Doe".to_string()]);
+
+let form: SignUpForm = rupring::http::form::deserialize_form(&fields).unwrap();
+assert_eq!(form.name, "John Doe");
+assert_eq!(form.age, None);
+assert_eq!(form.tags, vec!["a".to_string(), "b".to_string()]);
+assert_eq!(form.address.city, "Seoul");
+```
+*/
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::de::{self, value::MapDeserializer, value::SeqDeserializer, IntoDeserializer};
+
+/// Parse the urlencoded string into a map of key and values.
+pub fn parse_form_urlencoded(raw: &[u8]) -> HashMap<String, Vec<String>> {
+    let mut fields = HashMap::<String, Vec<String>>::new();
+
+    for (key, value) in form_urlencoded::parse(raw) {
+        if key.is_empty() {
+            continue;
+        }
+
+        // a[]=1&a[]=2 => a: [1, 2]
+        let key = key.strip_suffix("[]").unwrap_or(&key).to_string();
+
+        fields.entry(key).or_default().push(value.into_owned());
+    }
+
+    fields
+}
+
+/// Deserialize the parsed fields into the given type.
+pub fn deserialize_form<T: de::DeserializeOwned>(
+    fields: &HashMap<String, Vec<String>>,
+) -> Result<T, FormError> {
+    let mut root = BTreeMap::new();
+
+    for (key, values) in fields {
+        insert_field(&mut root, &split_key(key), values);
+    }
+
+    T::deserialize(FormValueDeserializer(FormValue::Map(root)))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormError(String);
+
+impl std::fmt::Display for FormError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for FormError {}
+
+impl de::Error for FormError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        FormError(msg.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum FormValue {
+    Values(Vec<String>),
+    Map(BTreeMap<String, FormValue>),
+}
+
+// user[address][city] => ["user", "address", "city"]
+fn split_key(key: &str) -> Vec<String> {
+    let Some(index) = key.find('[') else {
+        return vec![key.to_string()];
+    };
+
+    if !key.ends_with(']') {
+        return vec![key.to_string()];
+    }
+
+    let mut path = vec![key[..index].to_string()];
+    path.extend(
+        key[index + 1..key.len() - 1]
+            .split("][")
+            .map(|e| e.to_string()),
+    );
+
+    path
+}
+
+fn insert_field(map: &mut BTreeMap<String, FormValue>, path: &[String], values: &[String]) {
+    let (name, rest) = path.split_first().expect("path should not be empty");
+
+    if rest.is_empty() {
+        match map.get_mut(name) {
+            Some(FormValue::Values(existing)) => existing.extend(values.iter().cloned()),
+            Some(FormValue::Map(_)) => {}
+            None => {
+                map.insert(name.clone(), FormValue::Values(values.to_vec()));
+            }
+        }
+
+        return;
+    }
+
+    let child = map
+        .entry(name.clone())
+        .or_insert_with(|| FormValue::Map(BTreeMap::new()));
+
+    if let FormValue::Values(_) = child {
+        *child = FormValue::Map(BTreeMap::new());
+    }
+
+    if let FormValue::Map(child) = child {
+        insert_field(child, rest, values);
+    }
+}
+
+struct FormValueDeserializer(FormValue);
+
+impl<'de> IntoDeserializer<'de, FormError> for FormValue {
+    type Deserializer = FormValueDeserializer;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        FormValueDeserializer(self)
+    }
+}
+
+impl FormValueDeserializer {
+    fn first_value(self) -> Result<String, FormError> {
+        match self.0 {
+            FormValue::Values(values) => values
+                .into_iter()
+                .next()
+                .ok_or_else(|| FormError("missing value".to_string())),
+            FormValue::Map(_) => Err(FormError(
+                "expected a value, found nested fields".to_string(),
+            )),
+        }
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let value = self.first_value()?;
+
+                match value.parse() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(error) => Err(FormError(format!("{error}"))),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for FormValueDeserializer {
+    type Error = FormError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            FormValue::Values(values) if values.len() == 1 => {
+                visitor.visit_string(values.into_iter().next().unwrap_or_default())
+            }
+            FormValue::Values(values) => visitor.visit_seq(SeqDeserializer::new(
+                values.into_iter().map(|e| FormValue::Values(vec![e])),
+            )),
+            FormValue::Map(map) => visitor.visit_map(MapDeserializer::new(map.into_iter())),
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_str<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.first_value()?)
+    }
+
+    fn deserialize_string<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.first_value()?)
+    }
+
+    fn deserialize_bytes<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_byte_buf(self.first_value()?.into_bytes())
+    }
+
+    fn deserialize_byte_buf<V: de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_byte_buf(self.first_value()?.into_bytes())
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match &self.0 {
+            FormValue::Values(values) if values.first().is_none_or(|e| e.is_empty()) => {
+                visitor.visit_none()
+            }
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            FormValue::Values(values) => visitor.visit_seq(SeqDeserializer::new(
+                values.into_iter().map(|e| FormValue::Values(vec![e])),
+            )),
+            // items[0][id]=1&items[1][id]=2
+            FormValue::Map(map) => {
+                let mut items = map
+                    .into_iter()
+                    .map(|(key, value)| match key.parse::<usize>() {
+                        Ok(index) => Ok((index, value)),
+                        Err(_) => Err(FormError(format!("invalid index: {key}"))),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                items.sort_by_key(|(index, _)| *index);
+
+                visitor.visit_seq(SeqDeserializer::new(
+                    items.into_iter().map(|(_, value)| value),
+                ))
+            }
+        }
+    }
+
+    fn deserialize_tuple<V: de::Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            FormValue::Map(map) => visitor.visit_map(MapDeserializer::new(map.into_iter())),
+            FormValue::Values(_) => Err(FormError(
+                "expected nested fields, found a value".to_string(),
+            )),
+        }
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+
+    // Only unit variants are supported. (ex. status=ACTIVE)
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.first_value()?.into_deserializer())
+    }
+
+    fn deserialize_identifier<V: de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_ignored_any<V: de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Item {
+        id: i32,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Form {
+        name: String,
+        age: Option<u8>,
+        agree: bool,
+        tags: Vec<String>,
+        items: Vec<Item>,
+    }
+
+    #[test]
+    fn test_deserialize_form() {
+        struct TestCase {
+            name: String,
+            raw: &'static str,
+            expected: Result<Form, FormError>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "중첩 키와 배열".to_string(),
+                raw: "name=%EA%B0%80+%ED%9E%A3&age=20&agree=true&tags[]=a&tags[]=b&items[1][id]=2&items[0][id]=1",
+                expected: Ok(Form {
+                    name: "가 힣".to_string(),
+                    age: Some(20),
+                    agree: true,
+                    tags: vec!["a".to_string(), "b".to_string()],
+                    items: vec![Item { id: 1 }, Item { id: 2 }],
+                }),
+            },
+            TestCase {
+                name: "단일 값 배열과 빈 Option".to_string(),
+                raw: "name=a&name=b&age=&agree=false&tags=x&items[0][id]=1",
+                expected: Ok(Form {
+                    name: "a".to_string(),
+                    age: None,
+                    agree: false,
+                    tags: vec!["x".to_string()],
+                    items: vec![Item { id: 1 }],
+                }),
+            },
+            TestCase {
+                name: "타입이 맞지 않음".to_string(),
+                raw: "name=a&agree=yes&tags=x&items[0][id]=1",
+                expected: Err(FormError(
+                    "provided string was not `true` or `false`".to_string(),
+                )),
+            },
+            TestCase {
+                name: "필수 필드 누락".to_string(),
+                raw: "name=a&agree=true&tags=x",
+                expected: Err(FormError("missing field `items`".to_string())),
+            },
+        ];
+
+        for tc in test_cases {
+            let fields = parse_form_urlencoded(tc.raw.as_bytes());
+            let result = deserialize_form::<Form>(&fields);
+
+            assert_eq!(result, tc.expected, "{} failed", tc.name);
+        }
+    }
+}
@@ -17,10 +17,32 @@ pub const CONTENT_DISPOSITION: &str = "content-disposition";
 pub const CACHE_CONTROL: &str = "cache-control";
 pub const COOKIE: &str = "cookie";
 pub const ACCESS_CONTROL_ALLOW_ORIGIN: &str = "access-control-allow-origin";
+pub const ACCESS_CONTROL_ALLOW_METHODS: &str = "access-control-allow-methods";
+pub const ACCESS_CONTROL_ALLOW_HEADERS: &str = "access-control-allow-headers";
+pub const ACCESS_CONTROL_ALLOW_CREDENTIALS: &str = "access-control-allow-credentials";
+pub const ACCESS_CONTROL_EXPOSE_HEADERS: &str = "access-control-expose-headers";
+pub const ACCESS_CONTROL_MAX_AGE: &str = "access-control-max-age";
+pub const ACCESS_CONTROL_REQUEST_METHOD: &str = "access-control-request-method";
+pub const ACCESS_CONTROL_REQUEST_HEADERS: &str = "access-control-request-headers";
+pub const ORIGIN: &str = "origin";
+pub const VARY: &str = "vary";
 pub const KEEP_ALIVE: &str = "keep-alive";
+pub const UPGRADE: &str = "upgrade";
+pub const SEC_WEBSOCKET_KEY: &str = "sec-websocket-key";
+pub const SEC_WEBSOCKET_VERSION: &str = "sec-websocket-version";
+pub const RANGE: &str = "range";
+pub const IF_RANGE: &str = "if-range";
+pub const IF_NONE_MATCH: &str = "if-none-match";
+pub const IF_MODIFIED_SINCE: &str = "if-modified-since";
 
 // response only headers
 pub const LOCATION: &str = "location";
+pub const ALLOW: &str = "allow";
+pub const SEC_WEBSOCKET_ACCEPT: &str = "sec-websocket-accept";
+pub const ETAG: &str = "etag";
+pub const LAST_MODIFIED: &str = "last-modified";
+pub const ACCEPT_RANGES: &str = "accept-ranges";
+pub const CONTENT_RANGE: &str = "content-range";
 
 // custum headers
 pub const REQUEST_ID: &str = "request-id";
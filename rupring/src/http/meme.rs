@@ -3,6 +3,8 @@ pub const TEXT: &str = "text/plain";
 pub const HTML: &str = "text/html";
 pub const CSS: &str = "text/css";
 pub const JAVASCRIPT: &str = "text/javascript";
+pub const CSV: &str = "text/csv";
+pub const MARKDOWN: &str = "text/markdown";
 
 // image 계열 MEME 타입
 pub const PNG: &str = "image/png";
@@ -11,6 +13,7 @@ pub const GIF: &str = "image/gif";
 pub const SVG: &str = "image/svg+xml";
 pub const ICO: &str = "image/x-icon";
 pub const WEBP: &str = "image/webp";
+pub const AVIF: &str = "image/avif";
 pub const BMP: &str = "image/bmp";
 pub const TIF: &str = "image/tiff";
 pub const PSD: &str = "image/vnd.adobe.photoshop";
@@ -19,6 +22,7 @@ pub const DXF: &str = "image/vnd.dxf";
 
 // application 계열 MEME 타입
 pub const JSON: &str = "application/json";
+pub const PROBLEM_JSON: &str = "application/problem+json"; // RFC 7807
 pub const PDF: &str = "application/pdf";
 pub const ZIP: &str = "application/zip";
 pub const TAR: &str = "application/x-tar";
@@ -28,6 +32,8 @@ pub const BZIP2: &str = "application/x-bzip2";
 pub const XML: &str = "application/xml";
 pub const FORM: &str = "application/x-www-form-urlencoded";
 pub const OCTET_STREAM: &str = "application/octet-stream";
+pub const WASM: &str = "application/wasm";
+pub const MANIFEST: &str = "application/manifest+json";
 
 // font 계열 MEME 타입
 pub const WOFF: &str = "font/woff";
@@ -46,3 +52,47 @@ pub const OGG: &str = "audio/ogg";
 
 // special MEME 타입
 pub const EVENT_STREAM: &str = "text/event-stream"; // for Server-Sent Events (SSE)
+
+// Infer the MEME type from the file extension. (case-insensitive, without the dot)
+pub fn from_extension(extension: &str) -> &'static str {
+    match extension.to_ascii_lowercase().as_str() {
+        "txt" => TEXT,
+        "html" | "htm" => HTML,
+        "css" => CSS,
+        "js" | "mjs" => JAVASCRIPT,
+        "csv" => CSV,
+        "md" => MARKDOWN,
+        "png" => PNG,
+        "jpg" | "jpeg" => JPEG,
+        "gif" => GIF,
+        "svg" => SVG,
+        "ico" => ICO,
+        "webp" => WEBP,
+        "avif" => AVIF,
+        "bmp" => BMP,
+        "tif" | "tiff" => TIF,
+        "psd" => PSD,
+        "dwg" => DWG,
+        "dxf" => DXF,
+        "json" | "map" => JSON,
+        "webmanifest" => MANIFEST,
+        "pdf" => PDF,
+        "zip" => ZIP,
+        "tar" => TAR,
+        "gz" => GZIP,
+        "bz" => BZIP,
+        "bz2" => BZIP2,
+        "xml" => XML,
+        "wasm" => WASM,
+        "woff" => WOFF,
+        "woff2" => WOFF2,
+        "ttf" => TTF,
+        "otf" => OTF,
+        "mp4" => MP4,
+        "webm" => WEBM,
+        "mp3" => MP3,
+        "wav" => WAV,
+        "ogg" => OGG,
+        _ => OCTET_STREAM,
+    }
+}
@@ -1,6 +1,11 @@
+pub mod body;
 pub mod cache;
 pub mod cookie;
+pub mod cors;
+pub mod form;
 pub mod header;
 pub mod meme;
 pub mod multipart;
 pub mod sse;
+pub mod static_files;
+pub mod websocket;
@@ -1,3 +1,57 @@
+/*!
+# About Multipart
+- By default, `multipart/form-data` bodies are fully read and parsed into `Request::files`. (`server.multipart.auto-parsing-enabled`)
+- For large uploads, a route with `stream_multipart = true` receives the parts as they arrive instead, with [Multipart].
+
+```rust
+use rupring::http::multipart::MultipartPart;
+
+#[rupring::Post(path = /upload, stream_multipart = true)]
+pub async fn upload(request: rupring::Request) -> rupring::Response {
+    let mut multipart = match request.multipart() {
+        Ok(multipart) => multipart,
+        Err(error) => return rupring::Response::new().error(error),
+    };
+
+    loop {
+        match multipart.next_part().await {
+            Ok(Some(MultipartPart::Field(field))) => println!("{} = {}", field.name, field.value),
+            Ok(Some(MultipartPart::File(file))) => {
+                // Large files are spooled to a temp file, which is removed when the file is dropped.
+                let path = format!("./uploads/{}", file.filename);
+
+                if let Err(error) = file.persist(path).await {
+                    return rupring::Response::new().status(500).text(error.to_string());
+                }
+            }
+            Ok(None) => break,
+            Err(error) => return rupring::Response::new().error(error),
+        }
+    }
+
+    rupring::Response::new().text("uploaded")
+}
+```
+
+- Text fields (parts without a filename) are [MultipartField], and files are [UploadedFile].
+- Files larger than `server.multipart.file-size-threshold` are spooled to `server.multipart.location`. (the temp directory by default)
+- A file larger than `server.multipart.max-file-size` fails with [crate::error::Errors::FileTooLarge], and a body larger than `server.multipart.max-request-size` fails with [crate::error::Errors::PayloadTooLarge]. (`server.request.body.max-length` does not apply)
+- The streamed body is not decompressed. If `server.request.body.decompression-enabled` is true (the default), a request with a `Content-Encoding` other than `identity` is rejected with `415 Unsupported Media Type`.
+- [Multipart::collect] reads all parts into a [MultipartForm], whose text fields can be bound to a RupringDto with [MultipartForm::bind].
+*/
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use tokio::io::AsyncWriteExt;
+
+use crate::{
+    application_properties, error::Errors, header, http::body::BodyStream,
+    request::BindFromRequest, Request,
+};
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct MultipartFile {
     pub name: String,
@@ -142,6 +196,437 @@ pub fn parse_multipart(raw_body: &[u8], boundary: &str) -> anyhow::Result<Vec<Mu
     Ok(files)
 }
 
+/// Text field of a multipart body. (a part without a filename)
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultipartField {
+    pub name: String,
+    pub value: String,
+    /// Headers of the part. (lowercase names)
+    pub headers: HashMap<String, String>,
+}
+
+/// File of a multipart body.
+/// - Small files are kept in memory, and large files are spooled to a temp file.
+#[derive(Debug)]
+pub struct UploadedFile {
+    pub name: String,
+    pub filename: String,
+    pub content_type: String,
+    /// Headers of the part. (lowercase names)
+    pub headers: HashMap<String, String>,
+    pub size: usize,
+    data: FileData,
+}
+
+#[derive(Debug)]
+enum FileData {
+    Memory(Vec<u8>),
+    Disk(TempFile),
+}
+
+// The temp file is removed when it is dropped, unless it is persisted.
+#[derive(Debug)]
+struct TempFile {
+    path: PathBuf,
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+impl UploadedFile {
+    /// Returns true if the file is kept in memory. (not spooled to a temp file)
+    pub fn is_in_memory(&self) -> bool {
+        matches!(self.data, FileData::Memory(_))
+    }
+
+    /// Path of the temp file, if the file is spooled to disk.
+    pub fn path(&self) -> Option<&Path> {
+        match &self.data {
+            FileData::Memory(_) => None,
+            FileData::Disk(temp_file) => Some(&temp_file.path),
+        }
+    }
+
+    /// Read the whole file into memory.
+    pub async fn bytes(&self) -> std::io::Result<Vec<u8>> {
+        match &self.data {
+            FileData::Memory(data) => Ok(data.clone()),
+            FileData::Disk(temp_file) => tokio::fs::read(&temp_file.path).await,
+        }
+    }
+
+    /// Save the file to the given path. (The temp file is moved if possible)
+    pub async fn persist(self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+
+        match &self.data {
+            FileData::Memory(data) => tokio::fs::write(path, data).await,
+            FileData::Disk(temp_file) => {
+                // rename fails across file systems.
+                if tokio::fs::rename(&temp_file.path, path).await.is_err() {
+                    tokio::fs::copy(&temp_file.path, path).await?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Part of a multipart body.
+#[derive(Debug)]
+pub enum MultipartPart {
+    Field(MultipartField),
+    File(UploadedFile),
+}
+
+/// All parts of a multipart body. (see [Multipart::collect])
+#[derive(Debug, Default)]
+pub struct MultipartForm {
+    /// Text fields by name.
+    pub fields: HashMap<String, Vec<String>>,
+    pub files: Vec<UploadedFile>,
+}
+
+impl MultipartForm {
+    /// Returns the first file of the given field name.
+    pub fn file(&self, name: &str) -> Option<&UploadedFile> {
+        self.files.iter().find(|e| e.name == name)
+    }
+
+    /// Deserialize the text fields, in the same way as a form body. (see [crate::http::form])
+    pub fn deserialize<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<T, crate::http::form::FormError> {
+        crate::http::form::deserialize_form(&self.fields)
+    }
+
+    /// Bind the text fields to a RupringDto, with the path parameters, query parameters and headers of the request.
+    pub fn bind<T: BindFromRequest + Default>(&self, request: &Request) -> anyhow::Result<T> {
+        let mut request = request.clone();
+
+        // The text fields are bound as a form body.
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+        for (name, values) in &self.fields {
+            for value in values {
+                serializer.append_pair(name, value);
+            }
+        }
+
+        request.raw_body = serializer.finish().into_bytes();
+        request.headers.insert(
+            header::CONTENT_TYPE.to_string(),
+            crate::http::meme::FORM.to_string(),
+        );
+
+        request.bind::<T>()
+    }
+}
+
+/// Streaming parser of a `multipart/form-data` body.
+/// - The body is read only as much as the parts are requested.
+#[derive(Debug)]
+pub struct Multipart {
+    body: BodyStream,
+    // "\r\n--{boundary}"
+    delimiter: Vec<u8>,
+    buffer: Vec<u8>,
+    config: application_properties::Multipart,
+    state: MultipartState,
+}
+
+#[derive(Debug, PartialEq)]
+enum MultipartState {
+    // Before the first boundary
+    Preamble,
+    // After a boundary, at the headers of a part
+    Part,
+    // After the closing boundary
+    Done,
+}
+
+impl Multipart {
+    pub(crate) fn new(
+        body: BodyStream,
+        boundary: &str,
+        config: application_properties::Multipart,
+    ) -> Self {
+        let boundary = boundary.trim_matches('"');
+
+        Self {
+            body,
+            delimiter: format!("\r\n--{boundary}").into_bytes(),
+            buffer: vec![],
+            config,
+            state: MultipartState::Preamble,
+        }
+    }
+
+    /// Read the next part. Returns `None` after the last part.
+    pub async fn next_part(&mut self) -> Result<Option<MultipartPart>, Errors> {
+        if self.state == MultipartState::Preamble {
+            self.read_preamble().await?;
+        }
+
+        if self.state == MultipartState::Done {
+            return Ok(None);
+        }
+
+        // 1. Headers
+        let headers = self.read_headers().await?;
+
+        let content_disposition = headers
+            .get(header::CONTENT_DISPOSITION)
+            .cloned()
+            .unwrap_or_default();
+        let name = disposition_param(&content_disposition, "name").unwrap_or_default();
+        let filename = disposition_param(&content_disposition, "filename");
+        let content_type = headers
+            .get(header::CONTENT_TYPE)
+            .cloned()
+            .unwrap_or_default();
+
+        // 2. Body
+        let mut memory = vec![];
+        let mut temp_file: Option<(TempFile, tokio::fs::File)> = None;
+        let mut size = 0;
+
+        while let Some(data) = self.read_part_data().await? {
+            size += data.len();
+
+            if size > self.config.max_file_size {
+                return Err(Errors::FileTooLarge(name));
+            }
+
+            // Text fields are always kept in memory.
+            if filename.is_some() && temp_file.is_none() && size > self.config.file_size_threshold {
+                temp_file = Some(create_temp_file(self.config.location.as_deref()).await?);
+
+                if let Some((_, file)) = temp_file.as_mut() {
+                    write_all(file, &memory).await?;
+                }
+                memory = vec![];
+            }
+
+            match temp_file.as_mut() {
+                Some((_, file)) => write_all(file, &data).await?,
+                None => memory.extend_from_slice(&data),
+            }
+        }
+
+        // 3. Closing boundary or the next part
+        self.read_boundary_end().await?;
+
+        let part = match filename {
+            None => MultipartPart::Field(MultipartField {
+                name,
+                value: String::from_utf8_lossy(&memory).into_owned(),
+                headers,
+            }),
+            Some(filename) => {
+                let data = match temp_file {
+                    Some((temp_file, mut file)) => {
+                        file.flush()
+                            .await
+                            .map_err(|e| Errors::MultipartFailed(e.to_string()))?;
+
+                        FileData::Disk(temp_file)
+                    }
+                    None => FileData::Memory(memory),
+                };
+
+                MultipartPart::File(UploadedFile {
+                    name,
+                    filename,
+                    content_type,
+                    headers,
+                    size,
+                    data,
+                })
+            }
+        };
+
+        Ok(Some(part))
+    }
+
+    /// Read all the remaining parts.
+    pub async fn collect(mut self) -> Result<MultipartForm, Errors> {
+        let mut form = MultipartForm::default();
+
+        while let Some(part) = self.next_part().await? {
+            match part {
+                MultipartPart::Field(field) => {
+                    form.fields.entry(field.name).or_default().push(field.value);
+                }
+                MultipartPart::File(file) => form.files.push(file),
+            }
+        }
+
+        Ok(form)
+    }
+
+    // Read more bytes into the buffer.
+    async fn fill_buffer(&mut self) -> Result<(), Errors> {
+        match self.body.chunk().await? {
+            Some(chunk) => {
+                self.buffer.extend_from_slice(&chunk);
+                Ok(())
+            }
+            None => Err(Errors::MultipartFailed(
+                "unexpected end of multipart body".to_string(),
+            )),
+        }
+    }
+
+    // Skip to the end of the first boundary. ("--{boundary}")
+    async fn read_preamble(&mut self) -> Result<(), Errors> {
+        // The first boundary may not be preceded by CRLF.
+        let boundary = self.delimiter[2..].to_vec();
+
+        loop {
+            if let Some(index) = find(&self.buffer, &boundary) {
+                self.buffer.drain(..index + boundary.len());
+                return self.read_boundary_end().await;
+            }
+
+            // Keep the bytes that can be the start of the boundary.
+            let keep = boundary.len().saturating_sub(1).min(self.buffer.len());
+            self.buffer.drain(..self.buffer.len() - keep);
+
+            self.fill_buffer().await?;
+        }
+    }
+
+    // After a boundary: "--" for the closing boundary, CRLF for the next part.
+    async fn read_boundary_end(&mut self) -> Result<(), Errors> {
+        while self.buffer.len() < 2 {
+            self.fill_buffer().await?;
+        }
+
+        if self.buffer.starts_with(b"--") {
+            self.state = MultipartState::Done;
+            // The epilogue is ignored.
+            self.buffer.clear();
+            return Ok(());
+        }
+
+        // Transport padding (spaces) is allowed before CRLF.
+        loop {
+            if let Some(index) = find(&self.buffer, b"\r\n") {
+                if self.buffer[..index]
+                    .iter()
+                    .any(|e| *e != b' ' && *e != b'\t')
+                {
+                    return Err(Errors::MultipartFailed("invalid boundary".to_string()));
+                }
+
+                self.buffer.drain(..index + 2);
+                self.state = MultipartState::Part;
+                return Ok(());
+            }
+
+            self.fill_buffer().await?;
+        }
+    }
+
+    async fn read_headers(&mut self) -> Result<HashMap<String, String>, Errors> {
+        // The header section of a part is limited, the same as the request headers.
+        const MAX_HEADER_SIZE: usize = 16 * 1024;
+
+        // A part without headers starts with CRLF directly.
+        let header_end = loop {
+            if self.buffer.starts_with(b"\r\n") {
+                break 0;
+            }
+
+            if let Some(index) = find(&self.buffer, b"\r\n\r\n") {
+                break index + 2;
+            }
+
+            if self.buffer.len() > MAX_HEADER_SIZE {
+                return Err(Errors::MultipartFailed(
+                    "too large part headers".to_string(),
+                ));
+            }
+
+            self.fill_buffer().await?;
+        };
+
+        let headers = String::from_utf8_lossy(&self.buffer[..header_end])
+            .split("\r\n")
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+            .collect();
+
+        self.buffer.drain(..header_end + 2);
+
+        Ok(headers)
+    }
+
+    // Returns the data of the current part until the delimiter. (None at the delimiter)
+    async fn read_part_data(&mut self) -> Result<Option<Vec<u8>>, Errors> {
+        loop {
+            if let Some(index) = find(&self.buffer, &self.delimiter) {
+                if index == 0 {
+                    self.buffer.drain(..self.delimiter.len());
+                    return Ok(None);
+                }
+
+                return Ok(Some(self.buffer.drain(..index).collect()));
+            }
+
+            // The bytes that can be the start of the delimiter are kept in the buffer.
+            let available = self.buffer.len().saturating_sub(self.delimiter.len() - 1);
+
+            if available > 0 {
+                return Ok(Some(self.buffer.drain(..available).collect()));
+            }
+
+            self.fill_buffer().await?;
+        }
+    }
+}
+
+async fn create_temp_file(location: Option<&str>) -> Result<(TempFile, tokio::fs::File), Errors> {
+    let directory = match location {
+        Some(location) => PathBuf::from(location),
+        None => std::env::temp_dir(),
+    };
+
+    let path = directory.join(format!("rupring-upload-{}", uuid::Uuid::new_v4()));
+
+    let file = tokio::fs::File::create(&path)
+        .await
+        .map_err(|e| Errors::MultipartFailed(e.to_string()))?;
+
+    Ok((TempFile { path }, file))
+}
+
+async fn write_all(file: &mut tokio::fs::File, data: &[u8]) -> Result<(), Errors> {
+    file.write_all(data)
+        .await
+        .map_err(|e| Errors::MultipartFailed(e.to_string()))
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+// form-data; name="file"; filename="a.txt" => name: file, filename: a.txt
+fn disposition_param(content_disposition: &str, param: &str) -> Option<String> {
+    content_disposition
+        .split(';')
+        .skip(1)
+        .filter_map(|e| e.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case(param))
+        .map(|(_, value)| value.trim().trim_matches('"').to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -241,4 +726,232 @@ mod tests {
             assert_eq!(result, expected, "test case: {:?}", tc.name);
         }
     }
+
+    fn chunked_body(body: &[u8], chunk_size: usize, limit: Option<usize>) -> BodyStream {
+        let chunks = body
+            .chunks(chunk_size)
+            .map(|e| Ok(bytes::Bytes::copy_from_slice(e)))
+            .collect::<Vec<_>>();
+
+        BodyStream::new(futures_util::stream::iter(chunks), limit)
+    }
+
+    #[test]
+    fn test_multipart_stream() {
+        let body = concat!(
+            "preamble\r\n",
+            "--XBOUNDARY\r\n",
+            "Content-Disposition: form-data; name=\"title\"\r\n",
+            "\r\n",
+            "hello\r\nworld\r\n",
+            "--XBOUNDARY\r\n",
+            "Content-Disposition: form-data; name=\"file\"; filename=\"a.txt\"\r\n",
+            "Content-Type: text/plain\r\n",
+            "X-Custom: 1\r\n",
+            "\r\n",
+            "0123456789--XBOUNDAR0123456789\r\n",
+            "--XBOUNDARY--\r\n",
+            "epilogue"
+        )
+        .as_bytes();
+
+        #[derive(Debug, PartialEq)]
+        struct Part {
+            name: String,
+            value: String,
+            filename: Option<String>,
+            in_memory: bool,
+        }
+
+        struct TestCase {
+            name: String,
+            body: Vec<u8>,
+            chunk_size: usize,
+            config: application_properties::Multipart,
+            limit: Option<usize>,
+            expected: Result<Vec<Part>, String>,
+        }
+
+        let expected_parts = |in_memory: bool| {
+            vec![
+                Part {
+                    name: "title".to_string(),
+                    value: "hello\r\nworld".to_string(),
+                    filename: None,
+                    in_memory: true,
+                },
+                Part {
+                    name: "file".to_string(),
+                    value: "0123456789--XBOUNDAR0123456789".to_string(),
+                    filename: Some("a.txt".to_string()),
+                    in_memory,
+                },
+            ]
+        };
+
+        let test_cases = vec![
+            TestCase {
+                name: "한 번에 수신".to_string(),
+                body: body.to_vec(),
+                chunk_size: body.len(),
+                config: Default::default(),
+                limit: None,
+                expected: Ok(expected_parts(true)),
+            },
+            TestCase {
+                name: "1바이트씩 수신".to_string(),
+                body: body.to_vec(),
+                chunk_size: 1,
+                config: Default::default(),
+                limit: None,
+                expected: Ok(expected_parts(true)),
+            },
+            TestCase {
+                name: "임계값을 넘으면 디스크에 저장".to_string(),
+                body: body.to_vec(),
+                chunk_size: 7,
+                config: application_properties::Multipart {
+                    file_size_threshold: 10,
+                    ..Default::default()
+                },
+                limit: None,
+                expected: Ok(expected_parts(false)),
+            },
+            TestCase {
+                name: "파일 크기 제한".to_string(),
+                body: body.to_vec(),
+                chunk_size: 7,
+                config: application_properties::Multipart {
+                    max_file_size: 20,
+                    ..Default::default()
+                },
+                limit: None,
+                expected: Err("File Too Large: file".to_string()),
+            },
+            TestCase {
+                name: "전체 크기 제한".to_string(),
+                body: body.to_vec(),
+                chunk_size: 7,
+                config: Default::default(),
+                limit: Some(100),
+                expected: Err("Payload Too Large".to_string()),
+            },
+            TestCase {
+                name: "닫는 바운더리 없이 끝남".to_string(),
+                body: body[..body.len() - 30].to_vec(),
+                chunk_size: 7,
+                config: Default::default(),
+                limit: None,
+                expected: Err(
+                    "Invalid multipart body: unexpected end of multipart body".to_string()
+                ),
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            let mut multipart = Multipart::new(
+                chunked_body(&tc.body, tc.chunk_size, tc.limit),
+                "XBOUNDARY",
+                tc.config,
+            );
+
+            let got = runtime.block_on(async {
+                let mut parts = vec![];
+
+                while let Some(part) = multipart.next_part().await.map_err(|e| e.to_string())? {
+                    let part = match part {
+                        MultipartPart::Field(field) => {
+                            assert!(field.headers.contains_key("content-disposition"));
+
+                            Part {
+                                name: field.name,
+                                value: field.value,
+                                filename: None,
+                                in_memory: true,
+                            }
+                        }
+                        MultipartPart::File(file) => {
+                            assert_eq!(file.content_type, "text/plain");
+                            assert_eq!(file.headers.get("x-custom"), Some(&"1".to_string()));
+
+                            let bytes = file.bytes().await.unwrap();
+                            assert_eq!(file.size, bytes.len());
+
+                            Part {
+                                name: file.name.clone(),
+                                value: String::from_utf8(bytes).unwrap(),
+                                filename: Some(file.filename.clone()),
+                                in_memory: file.is_in_memory(),
+                            }
+                        }
+                    };
+
+                    parts.push(part);
+                }
+
+                Ok::<_, String>(parts)
+            });
+
+            assert_eq!(got, tc.expected, "{} failed", tc.name);
+        }
+    }
+
+    #[test]
+    fn test_uploaded_file_spooling() {
+        let body = concat!(
+            "--XBOUNDARY\r\n",
+            "Content-Disposition: form-data; name=\"file\"; filename=\"a.bin\"\r\n",
+            "\r\n",
+            "0123456789abcdef\r\n",
+            "--XBOUNDARY--\r\n",
+        )
+        .as_bytes();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let config = application_properties::Multipart {
+                file_size_threshold: 0,
+                ..Default::default()
+            };
+
+            // The temp file is removed when the file is dropped.
+            let mut multipart =
+                Multipart::new(chunked_body(body, 4, None), "XBOUNDARY", config.clone());
+            let Some(MultipartPart::File(file)) = multipart.next_part().await.unwrap() else {
+                panic!("file expected");
+            };
+
+            let temp_path = file.path().unwrap().to_path_buf();
+            assert!(temp_path.exists());
+
+            drop(file);
+            assert!(!temp_path.exists());
+
+            // The temp file is moved when the file is persisted.
+            let multipart = Multipart::new(chunked_body(body, 4, None), "XBOUNDARY", config);
+            let form = multipart.collect().await.unwrap();
+            assert!(form.fields.is_empty());
+
+            let destination =
+                std::env::temp_dir().join(format!("rupring-test-{}", uuid::Uuid::new_v4()));
+            let file = form.files.into_iter().next().unwrap();
+            let temp_path = file.path().unwrap().to_path_buf();
+
+            file.persist(&destination).await.unwrap();
+
+            assert!(!temp_path.exists());
+            assert_eq!(std::fs::read(&destination).unwrap(), b"0123456789abcdef");
+
+            std::fs::remove_file(destination).unwrap();
+        });
+    }
 }
@@ -0,0 +1,689 @@
+/*!
+# About Static Files
+- Files in a directory can be served with `server.static.*` in application.properties. (see [crate::application_properties])
+- Static files are only served for `GET` and `HEAD` requests that do not match any route. (Routes take precedence)
+
+```properties
+server.static.enabled=true
+server.static.path-prefix=/
+server.static.location=./dist
+server.static.spa-fallback=true
+server.static.cache-control=max-age=3600
+```
+
+With the above configuration, `GET /assets/app.js` is answered with `./dist/assets/app.js`, and `GET /users/1` is answered with `./dist/index.html`.
+
+- The content type is inferred from the file extension. (see [crate::http::meme::from_extension])
+- `ETag` and `Last-Modified` are sent, and conditional requests (`If-None-Match`, `If-Modified-Since`) are answered with `304 Not Modified`.
+- A single range of `Range: bytes=...` is answered with `206 Partial Content`. (`If-Range` is also supported)
+- The file is streamed in chunks, so large files are not loaded into memory.
+- If the client accepts it, a precompressed sibling (`app.js.br`, `app.js.gz`) is served instead, with `Content-Encoding`.
+- A request for a directory is answered with its index file. (`index.html` by default)
+- Paths that escape the directory (ex. `..`) are not served.
+*/
+
+use std::{
+    io::SeekFrom,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use crate::{application_properties::StaticFiles, header, http::meme, Response};
+
+// Precompressed siblings, in order of preference.
+const PRECOMPRESSED: [(&str, &str); 2] = [("br", "br"), ("gzip", "gz")];
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Serve the static file of the request.
+/// Returns `None` if the request is not for a static file, so that it can be answered as `404 Not Found`.
+pub(crate) fn serve_static_file(
+    config: &StaticFiles,
+    method: &hyper::Method,
+    request_path: &str,
+    headers: &hyper::HeaderMap,
+) -> Option<Response> {
+    if !config.enabled || (method != hyper::Method::GET && method != hyper::Method::HEAD) {
+        return None;
+    }
+
+    let root = Path::new(&config.location).canonicalize().ok()?;
+    let file_path = resolve_file_path(config, &root, request_path)?;
+
+    let header_value = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+    // 1. Precompressed sibling
+    let accept_encoding = header_value(header::ACCEPT_ENCODING).unwrap_or_default();
+    let (variant_path, content_encoding) = PRECOMPRESSED
+        .iter()
+        .filter(|(encoding, _)| accepts_encoding(accept_encoding, encoding))
+        .find_map(|(encoding, extension)| {
+            let path = file_in_root(&root, &sibling_path(&file_path, extension))?;
+
+            Some((path, Some(*encoding)))
+        })
+        .unwrap_or((file_path.clone(), None));
+
+    let metadata = std::fs::metadata(&variant_path).ok()?;
+    let length = metadata.len();
+    let modified = metadata.modified().ok();
+
+    let etag = {
+        let modified = modified
+            .and_then(|e| e.duration_since(UNIX_EPOCH).ok())
+            .unwrap_or_default();
+
+        format!("\"{:x}-{:x}\"", modified.as_nanos(), length)
+    };
+    let last_modified = modified.map(httpdate::fmt_http_date);
+
+    let mut response = Response::new()
+        .header(header::ETAG, &etag)
+        .header(header::ACCEPT_RANGES, "bytes")
+        .header(header::VARY, "Accept-Encoding");
+
+    if let Some(last_modified) = &last_modified {
+        response = response.header(header::LAST_MODIFIED, last_modified);
+    }
+
+    if let Some(cache_control) = &config.cache_control {
+        response = response.header(header::CACHE_CONTROL, cache_control);
+    }
+
+    // 2. Conditional requests
+    let not_modified = match header_value(header::IF_NONE_MATCH) {
+        Some(if_none_match) => if_none_match
+            .split(',')
+            .map(|e| e.trim().trim_start_matches("W/"))
+            .any(|e| e == "*" || e == etag),
+        None => match (header_value(header::IF_MODIFIED_SINCE), modified) {
+            // HTTP dates have a resolution of seconds.
+            (Some(if_modified_since), Some(modified)) => {
+                let seconds = |time: std::time::SystemTime| {
+                    time.duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_secs()
+                };
+
+                httpdate::parse_http_date(if_modified_since)
+                    .is_ok_and(|since| seconds(modified) <= seconds(since))
+            }
+            _ => false,
+        },
+    };
+
+    if not_modified {
+        return Some(response.status(304));
+    }
+
+    response = response.header(
+        header::CONTENT_TYPE,
+        meme::from_extension(
+            file_path
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or_default(),
+        ),
+    );
+
+    if let Some(content_encoding) = content_encoding {
+        response = response.header(header::CONTENT_ENCODING, content_encoding);
+    }
+
+    // 3. Range requests
+    let range = header_value(header::RANGE).filter(|_| match header_value(header::IF_RANGE) {
+        Some(if_range) => if_range == etag || Some(if_range) == last_modified.as_deref(),
+        None => true,
+    });
+
+    if let Some(range) = range {
+        match parse_range(range, length) {
+            Some(Ok((start, end))) => {
+                let response = response.status(206).header(
+                    header::CONTENT_RANGE,
+                    format!("bytes {start}-{end}/{length}"),
+                );
+
+                return Some(stream_file(response, variant_path, start, end - start + 1));
+            }
+            Some(Err(())) => {
+                return Some(
+                    response
+                        .status(416)
+                        .header(header::CONTENT_RANGE, format!("bytes */{length}")),
+                );
+            }
+            // Unsupported ranges are ignored.
+            None => {}
+        }
+    }
+
+    Some(stream_file(response, variant_path, 0, length))
+}
+
+// Send `length` bytes of the file from `start`, a chunk at a time.
+fn stream_file(response: Response, path: PathBuf, start: u64, length: u64) -> Response {
+    response
+        .header(header::CONTENT_LENGTH, length.to_string())
+        .stream(move |stream_handler| {
+            let path = path.clone();
+
+            async move {
+                let Ok(mut file) = tokio::fs::File::open(&path).await else {
+                    return;
+                };
+
+                if file.seek(SeekFrom::Start(start)).await.is_err() {
+                    return;
+                }
+
+                let mut file = file.take(length);
+                let mut chunk = vec![0; CHUNK_SIZE.min(length as usize)];
+
+                loop {
+                    match file.read(&mut chunk).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(read) => {
+                            if stream_handler.send_bytes(&chunk[..read]).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        })
+}
+
+// Map the request path to a file in the directory.
+// - Returns None for paths outside the prefix or the directory.
+fn resolve_file_path(config: &StaticFiles, root: &Path, request_path: &str) -> Option<PathBuf> {
+    let prefix = config.path_prefix.trim_end_matches('/');
+    let relative_path = request_path.strip_prefix(prefix)?;
+
+    // "/static" should not match "/staticx"
+    if !relative_path.is_empty() && !relative_path.starts_with('/') {
+        return None;
+    }
+
+    let relative_path = percent_encoding::percent_decode_str(relative_path)
+        .decode_utf8()
+        .ok()?;
+
+    let mut segments = vec![];
+    for segment in relative_path.split('/') {
+        match segment {
+            "" | "." => continue,
+            ".." => return None,
+            _ if segment.contains(['\\', '\0']) || Path::new(segment).has_root() => return None,
+            _ => segments.push(segment),
+        }
+    }
+
+    let mut file_path = root.to_path_buf();
+    file_path.extend(&segments);
+
+    if file_path.is_dir() {
+        file_path.push(&config.index);
+    }
+
+    if !file_path.is_file() {
+        // SPA fallback: paths without an extension are routes of the frontend.
+        let is_route = segments.last().is_none_or(|e| !e.contains('.'));
+
+        if !config.spa_fallback || !is_route {
+            return None;
+        }
+
+        file_path = root.join(&config.index);
+    }
+
+    file_in_root(root, &file_path)
+}
+
+// Symbolic links that escape the directory are not served either.
+fn file_in_root(root: &Path, path: &Path) -> Option<PathBuf> {
+    let path = path.canonicalize().ok()?;
+
+    if !path.starts_with(root) || !path.is_file() {
+        return None;
+    }
+
+    Some(path)
+}
+
+// app.js + gz => app.js.gz
+fn sibling_path(file_path: &Path, extension: &str) -> PathBuf {
+    let mut path = file_path.as_os_str().to_owned();
+    path.push(".");
+    path.push(extension);
+
+    PathBuf::from(path)
+}
+
+// "gzip, deflate, br;q=0" => gzip: true, br: false
+fn accepts_encoding(accept_encoding: &str, encoding: &str) -> bool {
+    accept_encoding.split(',').any(|e| {
+        let mut parts = e.split(';').map(|e| e.trim());
+
+        let name = parts.next().unwrap_or_default();
+        let rejected = parts.any(|e| {
+            e.strip_prefix("q=")
+                .and_then(|q| q.parse::<f32>().ok())
+                .is_some_and(|q| q == 0.0)
+        });
+
+        (name.eq_ignore_ascii_case(encoding) || name == "*") && !rejected
+    })
+}
+
+// bytes=0-99, bytes=100-, bytes=-100 => inclusive range
+// - None: unsupported range (ex. multiple ranges), so the whole file is sent.
+// - Some(Err): unsatisfiable range
+fn parse_range(range: &str, length: u64) -> Option<Result<(u64, u64), ()>> {
+    let range = range.trim().strip_prefix("bytes=")?;
+
+    if range.contains(',') {
+        return None;
+    }
+
+    let (start, end) = range.split_once('-')?;
+    let (start, end) = (start.trim(), end.trim());
+
+    let range = match (start.is_empty(), end.is_empty()) {
+        // suffix range
+        (true, false) => {
+            let suffix = end.parse::<u64>().ok()?;
+
+            if suffix == 0 || length == 0 {
+                return Some(Err(()));
+            }
+
+            (length.saturating_sub(suffix), length - 1)
+        }
+        (false, _) => {
+            let start = start.parse::<u64>().ok()?;
+            let end = match end.is_empty() {
+                true => length.saturating_sub(1),
+                false => end.parse::<u64>().ok()?.min(length.saturating_sub(1)),
+            };
+
+            if start >= length || start > end {
+                return Some(Err(()));
+            }
+
+            (start, end)
+        }
+        (true, true) => return None,
+    };
+
+    Some(Ok(range))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{core::stream::StreamHandler, response::ResponseData, HeaderName};
+
+    fn header_of(response: &Response, name: &'static str) -> Option<String> {
+        response
+            .headers
+            .get(&HeaderName::from_static(name))
+            .map(|e| e.join(", "))
+    }
+
+    fn body_of(response: Response) -> Vec<u8> {
+        let stream = match response.data {
+            ResponseData::Immediate(body) => return body,
+            ResponseData::Stream(stream) => stream.stream.unwrap(),
+            ResponseData::WebSocket(_) => panic!("unexpected websocket response"),
+        };
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async move {
+            // A small channel, so that the file is read while the body is received.
+            let (sender, mut receiver) = tokio::sync::mpsc::channel(1);
+            let stream_handler = StreamHandler::new(sender, Default::default());
+
+            let receive = async move {
+                let mut body = vec![];
+                while let Some(Ok(frame)) = receiver.recv().await {
+                    body.extend_from_slice(&frame.into_data().unwrap());
+                }
+                body
+            };
+
+            futures_util::future::join(stream(stream_handler), receive)
+                .await
+                .1
+        })
+    }
+
+    #[test]
+    fn test_parse_range() {
+        struct TestCase {
+            name: String,
+            range: String,
+            expected: Option<Result<(u64, u64), ()>>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "시작-끝".to_string(),
+                range: "bytes=0-9".to_string(),
+                expected: Some(Ok((0, 9))),
+            },
+            TestCase {
+                name: "끝 생략".to_string(),
+                range: "bytes=90-".to_string(),
+                expected: Some(Ok((90, 99))),
+            },
+            TestCase {
+                name: "suffix".to_string(),
+                range: "bytes=-10".to_string(),
+                expected: Some(Ok((90, 99))),
+            },
+            TestCase {
+                name: "끝이 길이를 넘으면 잘라냄".to_string(),
+                range: "bytes=50-200".to_string(),
+                expected: Some(Ok((50, 99))),
+            },
+            TestCase {
+                name: "범위를 벗어남".to_string(),
+                range: "bytes=100-".to_string(),
+                expected: Some(Err(())),
+            },
+            TestCase {
+                name: "여러 범위는 무시".to_string(),
+                range: "bytes=0-1, 5-6".to_string(),
+                expected: None,
+            },
+            TestCase {
+                name: "bytes 단위가 아님".to_string(),
+                range: "items=0-1".to_string(),
+                expected: None,
+            },
+        ];
+
+        for tc in test_cases {
+            assert_eq!(
+                parse_range(&tc.range, 100),
+                tc.expected,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_serve_static_file() {
+        let root = std::env::temp_dir().join(format!("rupring-static-{}", uuid::Uuid::new_v4()));
+        let location = root.join("public");
+
+        std::fs::create_dir_all(location.join("assets")).unwrap();
+        std::fs::write(location.join("index.html"), "<html></html>").unwrap();
+        std::fs::write(location.join("assets/app.js"), "console.log(1);").unwrap();
+        std::fs::write(location.join("assets/app.js.gz"), "gzipped").unwrap();
+        std::fs::write(location.join("assets/my file.css"), "body {}").unwrap();
+        std::fs::write(location.join("large.txt"), "0123456789".repeat(20_000)).unwrap();
+        std::fs::write(root.join("secret.txt"), "secret").unwrap();
+
+        // A precompressed sibling that escapes the directory
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(root.join("secret.txt"), location.join("assets/app.js.br"))
+            .unwrap();
+
+        let config = StaticFiles {
+            enabled: true,
+            path_prefix: "/static".to_string(),
+            location: location.to_string_lossy().to_string(),
+            spa_fallback: true,
+            cache_control: Some("max-age=60".to_string()),
+            ..Default::default()
+        };
+
+        let etag_of = |path: &str| {
+            let response =
+                serve_static_file(&config, &hyper::Method::GET, path, &hyper::HeaderMap::new())
+                    .unwrap();
+
+            header_of(&response, header::ETAG).unwrap()
+        };
+        let app_js_etag = etag_of("/static/assets/app.js");
+
+        struct TestCase {
+            name: String,
+            method: hyper::Method,
+            path: String,
+            headers: Vec<(&'static str, String)>,
+            expected_status: Option<u16>,
+            expected_body: String,
+            expected_headers: Vec<(&'static str, Option<String>)>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "파일 제공".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/assets/app.js".to_string(),
+                headers: vec![],
+                expected_status: Some(200),
+                expected_body: "console.log(1);".to_string(),
+                expected_headers: vec![
+                    (header::CONTENT_TYPE, Some(meme::JAVASCRIPT.to_string())),
+                    (header::CACHE_CONTROL, Some("max-age=60".to_string())),
+                    (header::CONTENT_LENGTH, Some("15".to_string())),
+                    (header::CONTENT_ENCODING, None),
+                ],
+            },
+            TestCase {
+                name: "퍼센트 인코딩된 경로".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/assets/my%20file.css".to_string(),
+                headers: vec![],
+                expected_status: Some(200),
+                expected_body: "body {}".to_string(),
+                expected_headers: vec![(header::CONTENT_TYPE, Some(meme::CSS.to_string()))],
+            },
+            TestCase {
+                name: "미리 압축된 파일".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/assets/app.js".to_string(),
+                headers: vec![(header::ACCEPT_ENCODING, "br, gzip".to_string())],
+                expected_status: Some(200),
+                expected_body: "gzipped".to_string(),
+                expected_headers: vec![
+                    (header::CONTENT_TYPE, Some(meme::JAVASCRIPT.to_string())),
+                    (header::CONTENT_ENCODING, Some("gzip".to_string())),
+                ],
+            },
+            TestCase {
+                name: "디렉토리를 벗어나는 미리 압축된 파일은 무시".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/assets/app.js".to_string(),
+                headers: vec![(header::ACCEPT_ENCODING, "br".to_string())],
+                expected_status: Some(200),
+                expected_body: "console.log(1);".to_string(),
+                expected_headers: vec![(header::CONTENT_ENCODING, None)],
+            },
+            TestCase {
+                name: "청크보다 큰 파일".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/large.txt".to_string(),
+                headers: vec![],
+                expected_status: Some(200),
+                expected_body: "0123456789".repeat(20_000),
+                expected_headers: vec![(header::CONTENT_LENGTH, Some("200000".to_string()))],
+            },
+            TestCase {
+                name: "청크 경계를 넘는 Range".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/large.txt".to_string(),
+                headers: vec![(header::RANGE, "bytes=65530-65549".to_string())],
+                expected_status: Some(206),
+                expected_body: "01234567890123456789".to_string(),
+                expected_headers: vec![(header::CONTENT_LENGTH, Some("20".to_string()))],
+            },
+            TestCase {
+                name: "If-None-Match 일치".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/assets/app.js".to_string(),
+                headers: vec![(header::IF_NONE_MATCH, app_js_etag.clone())],
+                expected_status: Some(304),
+                expected_body: "".to_string(),
+                expected_headers: vec![(header::ETAG, Some(app_js_etag.clone()))],
+            },
+            TestCase {
+                name: "If-Modified-Since".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/assets/app.js".to_string(),
+                headers: vec![(
+                    header::IF_MODIFIED_SINCE,
+                    httpdate::fmt_http_date(
+                        std::time::SystemTime::now() + std::time::Duration::from_secs(60),
+                    ),
+                )],
+                expected_status: Some(304),
+                expected_body: "".to_string(),
+                expected_headers: vec![],
+            },
+            TestCase {
+                name: "Range 요청".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/assets/app.js".to_string(),
+                headers: vec![(header::RANGE, "bytes=0-6".to_string())],
+                expected_status: Some(206),
+                expected_body: "console".to_string(),
+                expected_headers: vec![(header::CONTENT_RANGE, Some("bytes 0-6/15".to_string()))],
+            },
+            TestCase {
+                name: "If-Range가 다르면 전체 응답".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/assets/app.js".to_string(),
+                headers: vec![
+                    (header::RANGE, "bytes=0-6".to_string()),
+                    (header::IF_RANGE, "\"old\"".to_string()),
+                ],
+                expected_status: Some(200),
+                expected_body: "console.log(1);".to_string(),
+                expected_headers: vec![(header::CONTENT_RANGE, None)],
+            },
+            TestCase {
+                name: "만족할 수 없는 Range".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/assets/app.js".to_string(),
+                headers: vec![(header::RANGE, "bytes=100-".to_string())],
+                expected_status: Some(416),
+                expected_body: "".to_string(),
+                expected_headers: vec![(header::CONTENT_RANGE, Some("bytes */15".to_string()))],
+            },
+            TestCase {
+                name: "디렉토리는 index 파일".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/".to_string(),
+                headers: vec![],
+                expected_status: Some(200),
+                expected_body: "<html></html>".to_string(),
+                expected_headers: vec![(header::CONTENT_TYPE, Some(meme::HTML.to_string()))],
+            },
+            TestCase {
+                name: "SPA fallback".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/users/1".to_string(),
+                headers: vec![],
+                expected_status: Some(200),
+                expected_body: "<html></html>".to_string(),
+                expected_headers: vec![],
+            },
+            TestCase {
+                name: "확장자가 있는 경로의 없는 파일은 404".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/assets/unknown.js".to_string(),
+                headers: vec![],
+                expected_status: None,
+                expected_body: "".to_string(),
+                expected_headers: vec![],
+            },
+            TestCase {
+                name: "상위 디렉토리 접근 차단".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/../secret.txt".to_string(),
+                headers: vec![],
+                expected_status: None,
+                expected_body: "".to_string(),
+                expected_headers: vec![],
+            },
+            TestCase {
+                name: "인코딩된 상위 디렉토리 접근 차단".to_string(),
+                method: hyper::Method::GET,
+                path: "/static/%2e%2e/secret.txt".to_string(),
+                headers: vec![],
+                expected_status: None,
+                expected_body: "".to_string(),
+                expected_headers: vec![],
+            },
+            TestCase {
+                name: "prefix가 다름".to_string(),
+                method: hyper::Method::GET,
+                path: "/staticx/assets/app.js".to_string(),
+                headers: vec![],
+                expected_status: None,
+                expected_body: "".to_string(),
+                expected_headers: vec![],
+            },
+            TestCase {
+                name: "GET, HEAD 외의 메서드".to_string(),
+                method: hyper::Method::POST,
+                path: "/static/assets/app.js".to_string(),
+                headers: vec![],
+                expected_status: None,
+                expected_body: "".to_string(),
+                expected_headers: vec![],
+            },
+        ];
+
+        for tc in test_cases {
+            let mut headers = hyper::HeaderMap::new();
+            for (name, value) in tc.headers {
+                headers.insert(name, value.parse().unwrap());
+            }
+
+            let response = serve_static_file(&config, &tc.method, &tc.path, &headers);
+
+            assert_eq!(
+                response.as_ref().map(|e| e.status),
+                tc.expected_status,
+                "{} failed",
+                tc.name
+            );
+
+            let Some(response) = response else {
+                continue;
+            };
+
+            for (name, expected) in tc.expected_headers {
+                assert_eq!(
+                    header_of(&response, name),
+                    expected,
+                    "{} failed: {}",
+                    tc.name,
+                    name
+                );
+            }
+
+            assert_eq!(
+                String::from_utf8(body_of(response)).unwrap(),
+                tc.expected_body,
+                "{} failed",
+                tc.name
+            );
+        }
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
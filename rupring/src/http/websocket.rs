@@ -0,0 +1,532 @@
+/*!
+# About WebSocket
+- A WebSocket route is a `GET` route that performs the HTTP/1.1 upgrade handshake. (RFC 6455)
+- The handler is called with a [WebSocket] after the handshake, and the connection is closed when the handler returns.
+- Middlewares of the module and controller are applied before the handshake, so you can reject the request there. (ex. authentication)
+
+```rust
+use rupring::http::websocket::{Message, WebSocket};
+
+#[rupring::WebSocket(path = /ws/echo)]
+pub async fn echo(request: rupring::Request, mut socket: WebSocket) {
+    while let Some(Ok(message)) = socket.recv().await {
+        match message {
+            Message::Text(text) => {
+                if socket.send_text(format!("echo: {text}")).await.is_err() {
+                    break;
+                }
+            }
+            Message::Binary(bytes) => {
+                let _ = socket.send(Message::Binary(bytes)).await;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+#[rupring::Controller(prefix=/, routes=[echo])]
+pub struct ChatController {}
+```
+
+- Ping frames are answered with pong frames automatically, and they are also passed to the handler.
+- If the client closes the connection, or the server is shutting down gracefully, `recv` returns `None`. (The server sends a close frame with `1001 Going Away` before shutting down)
+- [crate::Response::websocket] can be used instead of the macro, if you want to decide in the handler whether to upgrade.
+- WebSocket over HTTP/2 (RFC 8441) is not supported.
+*/
+
+use std::{
+    future::Future,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use futures_util::{FutureExt, SinkExt, StreamExt};
+use hyper_util::rt::TokioIo;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_tungstenite::{
+    tungstenite::{self, protocol::frame::coding::CloseCode, protocol::Role},
+    WebSocketStream,
+};
+
+use crate::{core::error_handler::panic_message, error::Errors, Request};
+
+/// WebSocket message
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong(Vec<u8>),
+    Close(Option<CloseFrame>),
+}
+
+/// Close frame of a WebSocket connection. (ex. 1000 = Normal Closure, 1001 = Going Away)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CloseFrame {
+    pub code: u16,
+    pub reason: String,
+}
+
+impl From<tungstenite::Message> for Message {
+    fn from(message: tungstenite::Message) -> Self {
+        match message {
+            tungstenite::Message::Text(text) => Message::Text(text.to_string()),
+            tungstenite::Message::Binary(bytes) => Message::Binary(bytes.to_vec()),
+            tungstenite::Message::Ping(bytes) => Message::Ping(bytes.to_vec()),
+            tungstenite::Message::Pong(bytes) => Message::Pong(bytes.to_vec()),
+            tungstenite::Message::Close(frame) => Message::Close(frame.map(|frame| CloseFrame {
+                code: frame.code.into(),
+                reason: frame.reason.to_string(),
+            })),
+            // Raw frames are not returned when reading.
+            tungstenite::Message::Frame(frame) => Message::Binary(frame.into_payload().to_vec()),
+        }
+    }
+}
+
+impl From<Message> for tungstenite::Message {
+    fn from(message: Message) -> Self {
+        match message {
+            Message::Text(text) => tungstenite::Message::text(text),
+            Message::Binary(bytes) => tungstenite::Message::binary(bytes),
+            Message::Ping(bytes) => tungstenite::Message::Ping(bytes.into()),
+            Message::Pong(bytes) => tungstenite::Message::Pong(bytes.into()),
+            Message::Close(frame) => {
+                tungstenite::Message::Close(frame.map(|frame| tungstenite::protocol::CloseFrame {
+                    code: CloseCode::from(frame.code),
+                    reason: frame.reason.into(),
+                }))
+            }
+        }
+    }
+}
+
+pub(crate) trait WebSocketIo: AsyncRead + AsyncWrite + Send + Unpin {}
+
+impl<T: AsyncRead + AsyncWrite + Send + Unpin> WebSocketIo for T {}
+
+/// Server side of a WebSocket connection.
+pub struct WebSocket {
+    stream: WebSocketStream<Pin<Box<dyn WebSocketIo>>>,
+    // ConnectionContext::closed of the upgraded connection
+    closed: Arc<AtomicBool>,
+    // false while the server is shutting down gracefully
+    service_available: Arc<AtomicBool>,
+}
+
+impl std::fmt::Debug for WebSocket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocket")
+            .field("closed", &self.is_closed())
+            .finish()
+    }
+}
+
+impl WebSocket {
+    pub(crate) async fn new(
+        io: impl WebSocketIo + 'static,
+        closed: Arc<AtomicBool>,
+        service_available: Arc<AtomicBool>,
+    ) -> Self {
+        let io: Pin<Box<dyn WebSocketIo>> = Box::pin(io);
+
+        Self {
+            stream: WebSocketStream::from_raw_socket(io, Role::Server, None).await,
+            closed,
+            service_available,
+        }
+    }
+
+    /// Receive the next message.
+    /// Returns `None` if the connection is closed, or if the server is shutting down.
+    pub async fn recv(&mut self) -> Option<Result<Message, Errors>> {
+        if self.closed.load(Ordering::Acquire) {
+            return None;
+        }
+
+        let service_available = Arc::clone(&self.service_available);
+        let shutdown = Box::pin(async move {
+            while service_available.load(Ordering::Acquire) {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            }
+        });
+
+        let next = match futures_util::future::select(self.stream.next(), shutdown).await {
+            futures_util::future::Either::Left((next, _)) => next,
+            futures_util::future::Either::Right(_) => {
+                let _ = self.close(1001, "server is shutting down").await;
+                return None;
+            }
+        };
+
+        match next {
+            Some(Ok(message)) => {
+                let message = Message::from(message);
+
+                // The reply to the close frame is queued by the stream itself, so it only needs to be flushed.
+                if let Message::Close(_) = message {
+                    let _ = self.stream.flush().await;
+                    self.closed.store(true, Ordering::Release);
+                }
+
+                Some(Ok(message))
+            }
+            Some(Err(error)) => {
+                self.closed.store(true, Ordering::Release);
+
+                Some(Err(Errors::WebSocketError(error.to_string())))
+            }
+            None => {
+                self.closed.store(true, Ordering::Release);
+
+                None
+            }
+        }
+    }
+
+    /// Send a message.
+    /// Returns an error if the connection is closed or if sending fails.
+    pub async fn send(&mut self, message: Message) -> Result<(), Errors> {
+        if self.closed.load(Ordering::Acquire) {
+            return Err(Errors::StreamClosed);
+        }
+
+        self.stream
+            .send(message.into())
+            .await
+            .map_err(|error| Errors::StreamSendError(error.to_string()))
+    }
+
+    /// Send a text message.
+    pub async fn send_text(&mut self, text: impl Into<String>) -> Result<(), Errors> {
+        self.send(Message::Text(text.into())).await
+    }
+
+    /// Send a binary message.
+    pub async fn send_binary(&mut self, bytes: impl Into<Vec<u8>>) -> Result<(), Errors> {
+        self.send(Message::Binary(bytes.into())).await
+    }
+
+    /// Send a ping frame. The client answers with a pong frame.
+    pub async fn ping(&mut self, payload: impl Into<Vec<u8>>) -> Result<(), Errors> {
+        self.send(Message::Ping(payload.into())).await
+    }
+
+    /// Send a close frame and close the connection.
+    pub async fn close(&mut self, code: u16, reason: impl Into<String>) -> Result<(), Errors> {
+        let result = self
+            .send(Message::Close(Some(CloseFrame {
+                code,
+                reason: reason.into(),
+            })))
+            .await;
+
+        self.closed.store(true, Ordering::Release);
+
+        result
+    }
+
+    /// Check if the connection is closed, or the server is shutting down.
+    /// This can be used to stop tasks that only send messages.
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire) || !self.service_available.load(Ordering::Acquire)
+    }
+}
+
+type WebSocketFn = dyn Fn(WebSocket) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync;
+
+/// Handler of an upgraded connection. (see [crate::Response::websocket])
+#[derive(Clone)]
+pub struct WebSocketResponse {
+    pub(crate) handler: Arc<WebSocketFn>,
+}
+
+impl std::fmt::Debug for WebSocketResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocketResponse").finish()
+    }
+}
+
+impl WebSocketResponse {
+    pub(crate) fn new<F, Fut>(handler: F) -> Self
+    where
+        F: Fn(WebSocket) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        Self {
+            handler: Arc::new(move |socket| Box::pin(handler(socket))),
+        }
+    }
+}
+
+// Checks the opening handshake and returns the value of the Sec-WebSocket-Accept header.
+pub(crate) fn accept_key(request: &Request) -> Result<String, Errors> {
+    let header_contains = |name: &str, token: &str| {
+        request.headers.get(name).is_some_and(|value| {
+            value
+                .split(',')
+                .any(|e| e.trim().eq_ignore_ascii_case(token))
+        })
+    };
+
+    if request.method != crate::Method::GET {
+        return Err(Errors::WebSocketHandshakeFailed(
+            "method should be GET".to_string(),
+        ));
+    }
+
+    if !header_contains(crate::header::UPGRADE, "websocket") {
+        return Err(Errors::WebSocketHandshakeFailed(
+            "upgrade header should be websocket".to_string(),
+        ));
+    }
+
+    if !header_contains(crate::header::CONNECTION, "upgrade") {
+        return Err(Errors::WebSocketHandshakeFailed(
+            "connection header should contain upgrade".to_string(),
+        ));
+    }
+
+    if request
+        .headers
+        .get(crate::header::SEC_WEBSOCKET_VERSION)
+        .map(|e| e.trim())
+        != Some("13")
+    {
+        return Err(Errors::WebSocketHandshakeFailed(
+            "sec-websocket-version should be 13".to_string(),
+        ));
+    }
+
+    match request.headers.get(crate::header::SEC_WEBSOCKET_KEY) {
+        Some(key) => Ok(tungstenite::handshake::derive_accept_key(
+            key.trim().as_bytes(),
+        )),
+        None => Err(Errors::WebSocketHandshakeFailed(
+            "sec-websocket-key is missing".to_string(),
+        )),
+    }
+}
+
+// Runs the handler on the upgraded connection.
+// - The session is counted as a running task, so graceful shutdown waits for it.
+// - ConnectionContext::closed is set when the session ends.
+pub(crate) fn spawn_session(
+    on_upgrade: hyper::upgrade::OnUpgrade,
+    websocket_response: WebSocketResponse,
+    closed: Arc<AtomicBool>,
+    service_available: Arc<AtomicBool>,
+    running_task_count: Arc<AtomicU64>,
+) {
+    tokio::spawn(async move {
+        let upgraded = match on_upgrade.await {
+            Ok(upgraded) => upgraded,
+            Err(error) => {
+                log::debug!("WebSocket upgrade failed: {:?}", error);
+                closed.store(true, Ordering::Release);
+                return;
+            }
+        };
+
+        run_session(
+            TokioIo::new(upgraded),
+            websocket_response,
+            closed,
+            service_available,
+            running_task_count,
+        )
+        .await;
+    });
+}
+
+// A panic of the handler is logged, and the session still ends. (as the HTTP pipeline does)
+async fn run_session(
+    io: impl WebSocketIo + 'static,
+    websocket_response: WebSocketResponse,
+    closed: Arc<AtomicBool>,
+    service_available: Arc<AtomicBool>,
+    running_task_count: Arc<AtomicU64>,
+) {
+    running_task_count.fetch_add(1, Ordering::Release);
+
+    let socket = WebSocket::new(io, Arc::clone(&closed), service_available).await;
+
+    let result = AssertUnwindSafe((websocket_response.handler)(socket))
+        .catch_unwind()
+        .await;
+
+    if let Err(panic) = result {
+        log::error!(
+            "WebSocket handler panicked: {}",
+            panic_message(panic.as_ref())
+        );
+    }
+
+    closed.store(true, Ordering::Release);
+    running_task_count.fetch_sub(1, Ordering::Release);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_key() {
+        struct TestCase {
+            name: String,
+            headers: Vec<(&'static str, &'static str)>,
+            expected: Result<String, String>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "RFC 6455 예제".to_string(),
+                headers: vec![
+                    ("upgrade", "websocket"),
+                    ("connection", "keep-alive, Upgrade"),
+                    ("sec-websocket-version", "13"),
+                    ("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ=="),
+                ],
+                expected: Ok("s3pPLMBiTxaQ9kYGzzhZRbK+xOo=".to_string()),
+            },
+            TestCase {
+                name: "upgrade 헤더 없음".to_string(),
+                headers: vec![
+                    ("connection", "upgrade"),
+                    ("sec-websocket-version", "13"),
+                    ("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ=="),
+                ],
+                expected: Err(
+                    "WebSocket Handshake Failed: upgrade header should be websocket".to_string(),
+                ),
+            },
+            TestCase {
+                name: "지원하지 않는 버전".to_string(),
+                headers: vec![
+                    ("upgrade", "websocket"),
+                    ("connection", "upgrade"),
+                    ("sec-websocket-version", "8"),
+                    ("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ=="),
+                ],
+                expected: Err(
+                    "WebSocket Handshake Failed: sec-websocket-version should be 13".to_string(),
+                ),
+            },
+        ];
+
+        for tc in test_cases {
+            let request = tc.headers.iter().fold(
+                Request::for_test().with_path("/ws"),
+                |request, (name, value)| request.with_header(name, value),
+            );
+
+            let result = accept_key(&request).map_err(|e| e.to_string());
+
+            assert_eq!(result, tc.expected, "{} failed", tc.name);
+        }
+    }
+
+    #[test]
+    fn test_websocket_session() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let (server_io, client_io) = tokio::io::duplex(1024);
+            let closed = Arc::new(AtomicBool::new(false));
+            let service_available = Arc::new(AtomicBool::new(true));
+
+            let server = tokio::spawn({
+                let closed = Arc::clone(&closed);
+                let service_available = Arc::clone(&service_available);
+
+                async move {
+                    let mut socket = WebSocket::new(server_io, closed, service_available).await;
+                    let mut received = vec![];
+
+                    while let Some(Ok(message)) = socket.recv().await {
+                        if let Message::Text(text) = &message {
+                            socket.send_text(format!("echo: {text}")).await.unwrap();
+                        }
+
+                        received.push(message);
+                    }
+
+                    received
+                }
+            });
+
+            let mut client = WebSocketStream::from_raw_socket(client_io, Role::Client, None).await;
+
+            client
+                .send(tungstenite::Message::text("hello"))
+                .await
+                .unwrap();
+            let echo = client.next().await.unwrap().unwrap();
+            assert_eq!(echo, tungstenite::Message::text("echo: hello"));
+
+            client
+                .send(tungstenite::Message::binary(vec![1, 2, 3]))
+                .await
+                .unwrap();
+
+            // Graceful shutdown closes the connection with 1001 Going Away.
+            service_available.store(false, Ordering::Release);
+
+            let close = client.next().await.unwrap().unwrap();
+            let tungstenite::Message::Close(Some(frame)) = close else {
+                panic!("close frame expected, got {close:?}");
+            };
+            assert_eq!(u16::from(frame.code), 1001);
+
+            let received = server.await.unwrap();
+            assert_eq!(
+                received,
+                vec![
+                    Message::Text("hello".to_string()),
+                    Message::Binary(vec![1, 2, 3]),
+                ]
+            );
+            assert!(closed.load(Ordering::Acquire));
+        });
+    }
+
+    #[test]
+    fn test_websocket_session_panic() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            let (server_io, _client_io) = tokio::io::duplex(1024);
+            let closed = Arc::new(AtomicBool::new(false));
+            let running_task_count = Arc::new(AtomicU64::new(0));
+
+            let websocket_response = WebSocketResponse::new(|_socket| async {
+                panic!("handler failed");
+            });
+
+            run_session(
+                server_io,
+                websocket_response,
+                Arc::clone(&closed),
+                Arc::new(AtomicBool::new(true)),
+                Arc::clone(&running_task_count),
+            )
+            .await;
+
+            // Graceful shutdown does not wait for the panicked session.
+            assert!(closed.load(Ordering::Acquire));
+            assert_eq!(running_task_count.load(Ordering::Acquire), 0);
+        });
+    }
+}
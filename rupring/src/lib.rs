@@ -75,10 +75,32 @@ pub fn hello(_request: rupring::Request) -> rupring::Response {
 ```
 - Please refer to the corresponding [document](crate::response) for more details.
 
+# Async Handler
+- Route handlers can also be defined as `async fn`. They are driven by the tokio runtime, so you can await database or HTTP calls without blocking a worker thread.
+```rust
+#[rupring::Get(path = /slow)]
+pub async fn slow(_request: rupring::Request) -> rupring::Response {
+    rupring::tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+    rupring::Response::new().text("Hello, World!".to_string())
+}
+```
+- If the handler panics, the request is answered with `500 Internal Server Error`, the same as a synchronous handler.
+
 # Server Sent Event (SSE)
 - rupring provides Server Sent Event (SSE) features.
 - You can use SSE streaming directly through [crate::response::Response::sse_stream] method.
 
+# WebSocket
+- You can define a WebSocket endpoint with [WebSocket](macro@crate::WebSocket) annotation. (HTTP/1.1 upgrade)
+- The middlewares are applied before the handshake, so the request can be rejected there. (ex. authentication)
+- Please refer to the corresponding [document](crate::http::websocket) for more details.
+
+# Static Files
+- Files in a directory can be served with `server.static.*` in application.properties. (ex. frontend build output)
+- ETag, Range requests, precompressed files (`.br`, `.gz`) and SPA fallback are supported.
+- Please refer to the corresponding [document](crate::http::static_files) for more details.
+
 # Middleware
 rupring provides middleware features for common logic processing.
 
@@ -103,6 +125,21 @@ pub fn logger_middleware(
 The above function only records logs and forwards them to the next middleware or route function.
 If you want to return a response immediately without forwarding, just return the response without calling the next function.
 
+Middleware can also be an async function that receives [Next](crate::middleware::Next).
+`next.run(request).await` returns the downstream response, so you can run code after the handler or rewrite the response.
+```rust
+pub async fn timing_middleware(
+    request: rupring::Request,
+    next: rupring::Next,
+) -> rupring::Response {
+    let started_at = std::time::Instant::now();
+
+    let response = next.run(request).await;
+
+    response.header("x-elapsed-ms", started_at.elapsed().as_millis().to_string())
+}
+```
+
 
 And you can register the middleware function just defined in the module or controller unit.
 ```rust
@@ -156,6 +193,29 @@ The priorities in which middleware is applied are as follows:
 3. If the parent module's middleware and the child module's middleware exist at the same time, the parent module middleware is executed first.
 
 
+# Error Handler
+- Errors returned from route handlers and errors of the framework itself (404, 413, timeout, etc.) can be handled by error handlers registered in the module.
+```rust
+#[rupring::ErrorHandler(status = 404)]
+pub fn not_found(error: &rupring::error::HttpError) -> rupring::Response {
+    rupring::Response::new().status(404).json(rupring::serde_json::json!({
+        "message": "Not Found",
+        "path": error.path,
+    }))
+}
+
+#[derive(Debug, Clone, Copy)]
+#[rupring::Module(
+    controllers=[],
+    modules=[],
+    providers=[],
+    middlewares=[],
+    error_handlers=[not_found]
+)]
+pub struct RootModule {}
+```
+- Please refer to the corresponding [document](crate::error) for more details.
+
 # Dependency Injection
 - Rupring provides powerful DI features through macro and runtime support.
 ```rust
@@ -184,6 +244,11 @@ impl rupring::IProvider for HomeService {
 - rupring provides various execution options through a special configuration file called application.properties.
 - Please refer to the corresponding [document](crate::application_properties) for more details.
 
+# Testing
+- [TestClient](crate::test::TestClient) sends requests through the request pipeline without binding a port.
+- Providers can be replaced with mocks for the test.
+- Please refer to the corresponding [document](crate::test) for more details.
+
 # AWS Lambda
 - rupring provides the option to run on AWS Lambda.
 - Supported Lambda Runtimes
@@ -233,6 +298,8 @@ pub mod http;
 pub use http::header;
 
 mod logger;
+/// middleware module
+pub mod middleware;
 /// HTTP request module
 pub mod request;
 /// HTTP response module
@@ -243,7 +310,15 @@ pub mod swagger;
 /// error module
 pub mod error;
 
+/// request validation module
+pub mod validation;
+
+/// test client module
+pub mod test;
+
+use std::future::Future;
 use std::panic::UnwindSafe;
+use std::pin::Pin;
 
 use application_properties::load_application_properties_from_all;
 use application_properties::ApplicationProperties;
@@ -265,6 +340,8 @@ pub fn echo(request: rupring::Request) -> rupring::Response {
 #[rupring::Controller(prefix=/, routes=[hello, echo])]
 pub struct HomeController {}
 ```
+- `middlewares=[...]` applies middlewares to the routes of the controller.
+- `cors=function_name` overrides the CORS configuration for the routes of the controller. (see [crate::http::cors])
 */
 pub use rupring_macro::Controller;
 
@@ -349,6 +426,8 @@ pub fn hello(request: rupring::Request) -> rupring::Response {
     rupring::Response::new().text("Hello, World!".to_string())
 }
 ```
+- `stream_multipart = true`: The multipart body is not read before the handler, and the handler reads the parts as they arrive. (see [crate::http::multipart])
+- `stream_body = true`: The body is not read before the handler, and the handler reads it as a stream. (see [crate::http::body])
 */
 pub use rupring_macro::Post;
 
@@ -397,6 +476,35 @@ pub use rupring_macro::Delete;
 /// This is an alias for [Delete].
 pub use rupring_macro::DeleteMapping;
 
+/** WebSocket Route Annotation
+- A `GET` route that performs the WebSocket handshake. The handler is called with the upgraded connection.
+- The parameters of the handler are matched by type. ([Request] and [WebSocket](crate::http::websocket::WebSocket))
+- Please refer to the corresponding [document](crate::http::websocket) for more details.
+```rust
+#[rupring::WebSocket(path = /ws)]
+pub async fn chat(mut socket: rupring::http::websocket::WebSocket) {
+    while let Some(Ok(message)) = socket.recv().await {
+        println!("{:?}", message);
+    }
+}
+```
+*/
+pub use rupring_macro::WebSocket;
+
+/** Error Handler Annotation
+- Please refer to the corresponding [document](crate::error) for more details.
+```rust
+#[rupring::ErrorHandler(status = 404)]
+pub fn not_found(error: &rupring::error::HttpError) -> rupring::Response {
+    rupring::Response::new().status(404).text(format!("{} not found", error.path))
+}
+```
+*/
+pub use rupring_macro::ErrorHandler;
+
+/// This is an alias for [ErrorHandler].
+pub use rupring_macro::ExceptionHandler;
+
 /// HTTP method (from hyper crate)
 pub type Method = hyper::Method;
 
@@ -416,7 +524,7 @@ pub use request::Request;
 /// HTTP Response
 pub use response::Response;
 use swagger::json::SwaggerOperation;
-use swagger::macros::SwaggerRequestBody;
+use swagger::macros::{SwaggerRequestBody, SwaggerResponseInfo};
 use swagger::SwaggerSecurity;
 
 /// Application Properties
@@ -428,24 +536,53 @@ pub trait IModule {
     fn controllers(&self) -> Vec<Box<dyn IController>>;
     fn providers(&self) -> Vec<Box<dyn IProvider>>;
     fn middlewares(&self) -> Vec<MiddlewareFunction>;
+
+    fn error_handlers(&self) -> Vec<ErrorHandlerFunction> {
+        vec![]
+    }
 }
 
-/// Middleware function type
-pub type MiddlewareFunction =
-    Box<dyn Fn(Request, Response, NextFunction) -> Response + Send + Sync + UnwindSafe + 'static>;
+/// Error handler type registered in modules
+pub use error::ErrorHandlerFunction;
+/// Error handler interface
+pub use error::IErrorHandler;
+
+/// Middleware interface
+pub use middleware::IMiddleware;
+/// Conversion into [MiddlewareFunction]
+pub use middleware::IntoMiddleware;
+/// Middleware type registered in modules and controllers
+pub use middleware::MiddlewareFunction;
+/// Remaining part of the middleware chain
+pub use middleware::Next;
 
 /// Controller interface
 pub trait IController {
     fn prefix(&self) -> String;
     fn routes(&self) -> Vec<Box<dyn IRoute + Send + 'static>>;
     fn middlewares(&self) -> Vec<MiddlewareFunction>;
+
+    /// CORS configuration of the controller. (`None` to use `server.cors.*` of application.properties)
+    fn cors(&self) -> Option<http::cors::CorsConfig> {
+        None
+    }
+
+    /// Default security of the routes in the controller, for the swagger document. (`auth = ...`)
+    fn swagger_security_info(&self) -> Vec<SwaggerSecurity> {
+        vec![]
+    }
+
+    /// Prefix of the controller resolved with application.properties. (default: [IController::prefix])
+    fn resolve_prefix(&self, _application_properties: &ApplicationProperties) -> String {
+        self.prefix()
+    }
 }
 
 /// Route interface
 pub trait IRoute {
     fn method(&self) -> Method;
     fn path(&self) -> String;
-    fn handler(&self) -> Box<dyn IHandler + Send + 'static>;
+    fn handler(&self) -> Box<dyn IAsyncHandler + Send + Sync + 'static>;
 
     fn swagger(&self) -> SwaggerOperation {
         Default::default()
@@ -462,6 +599,20 @@ pub trait IRoute {
     fn swagger_security_info(&self) -> Vec<SwaggerSecurity> {
         vec![]
     }
+
+    fn swagger_responses(&self) -> Vec<SwaggerResponseInfo> {
+        vec![]
+    }
+
+    /// If true, the multipart body is not read before the handler, and the handler reads it with [Request::multipart]. (`stream_multipart = true`)
+    fn stream_multipart(&self) -> bool {
+        false
+    }
+
+    /// If true, the body is not read before the handler, and the handler reads it with [Request::body_stream]. (`stream_body = true`)
+    fn stream_body(&self) -> bool {
+        false
+    }
 }
 
 /// Handler interface
@@ -469,7 +620,24 @@ pub trait IHandler: UnwindSafe {
     fn handle(&self, request: Request, response: Response) -> Response;
 }
 
-/// Next function type for middleware
+/// Future type returned by async handlers
+pub type HandlerFuture = Pin<Box<dyn Future<Output = Response> + Send + 'static>>;
+
+/// Async handler interface
+/// - Every [IHandler] is also an [IAsyncHandler], so the pipeline only drives this one.
+pub trait IAsyncHandler {
+    fn handle(&self, request: Request, response: Response) -> HandlerFuture;
+}
+
+impl<T: IHandler> IAsyncHandler for T {
+    fn handle(&self, request: Request, response: Response) -> HandlerFuture {
+        let response = IHandler::handle(self, request, response);
+
+        Box::pin(async move { response })
+    }
+}
+
+/// Next function type for sync middleware
 pub type NextFunction = fn(Request, Response) -> Response;
 
 /// Rupring Factory for creating server
@@ -488,6 +656,20 @@ impl<T: IModule + Clone + Copy + Sync + Send + 'static> RupringFactory<T> {
         }
     }
 
+    /// It configures the swagger document and UI. (applied over `swagger.*` of application.properties)
+    /// ```rust,ignore
+    /// rupring::RupringFactory::create(RootModule {})
+    ///     .swagger(|swagger| swagger.title("Shop API").version("1.0.0").path("/api-docs"))
+    ///     .listen()
+    /// ```
+    pub fn swagger(
+        mut self,
+        configure: impl FnOnce(application_properties::Swagger) -> application_properties::Swagger,
+    ) -> Self {
+        self.application_properties.swagger = configure(self.application_properties.swagger);
+        self
+    }
+
     /// It receives the port number and runs the server.
     pub fn listen(self) -> anyhow::Result<()> {
         use tokio::runtime::Builder;
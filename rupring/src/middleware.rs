@@ -0,0 +1,289 @@
+/*!
+# About Middleware
+- A middleware receives the request and a [Next] handle to the rest of the chain.
+- `next.run(request).await` executes the remaining middlewares and the route handler, and returns the downstream response.
+- So you can run code after the handler, rewrite the response, or await anything in between.
+
+```rust
+pub async fn timing_middleware(
+    request: rupring::Request,
+    next: rupring::middleware::Next,
+) -> rupring::Response {
+    let started_at = std::time::Instant::now();
+    let path = request.path.clone();
+
+    let response = next.run(request).await;
+
+    println!("{} took {:?}", path, started_at.elapsed());
+
+    response.header("x-elapsed-ms", started_at.elapsed().as_millis().to_string())
+}
+
+#[derive(Debug, Clone, Copy)]
+#[rupring::Module(
+    controllers=[],
+    modules=[],
+    providers=[],
+    middlewares=[timing_middleware]
+)]
+pub struct RootModule {}
+```
+
+- If you want to return a response immediately without forwarding, just return the response without calling `next.run`.
+- Sync middleware functions in the form of `fn(Request, Response, NextFunction) -> Response` can be registered in the same `middlewares=[...]` list.
+- A type that implements [IMiddleware] directly can also be registered.
+*/
+
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::{HandlerFuture, IAsyncHandler, NextFunction, Request, Response};
+
+/// Middleware interface
+pub trait IMiddleware {
+    fn handle(&self, request: Request, next: Next) -> HandlerFuture;
+}
+
+/// Middleware type registered in modules and controllers
+pub type MiddlewareFunction = Box<dyn IMiddleware + Send + Sync + 'static>;
+
+/// Remaining part of the middleware chain, including the route handler.
+pub struct Next {
+    middlewares: Arc<Vec<MiddlewareFunction>>,
+    index: usize,
+    handler: Arc<dyn IAsyncHandler + Send + Sync + 'static>,
+    response: Response,
+}
+
+impl Next {
+    pub(crate) fn new(
+        middlewares: Arc<Vec<MiddlewareFunction>>,
+        handler: Arc<dyn IAsyncHandler + Send + Sync + 'static>,
+    ) -> Self {
+        Next {
+            middlewares,
+            index: 0,
+            handler,
+            response: Response::new(),
+        }
+    }
+
+    /// Response inherited from the previous middlewares.
+    pub fn response(&self) -> &Response {
+        &self.response
+    }
+
+    /// Run the remaining middlewares and the handler, and return the downstream response.
+    pub async fn run(self, request: Request) -> Response {
+        let response = self.response.clone();
+
+        self.run_with(request, response).await
+    }
+
+    /// Same as [Next::run], but passes the given response down the chain instead of the inherited one.
+    pub async fn run_with(self, request: Request, response: Response) -> Response {
+        match self.middlewares.get(self.index) {
+            Some(middleware) => {
+                let next = Next {
+                    middlewares: Arc::clone(&self.middlewares),
+                    index: self.index + 1,
+                    handler: Arc::clone(&self.handler),
+                    response,
+                };
+
+                middleware.handle(request, next).await
+            }
+            None => self.handler.handle(request, response).await,
+        }
+    }
+}
+
+/// Conversion into [MiddlewareFunction].
+/// - The `Marker` type parameter only exists to distinguish sync functions, async functions and [IMiddleware] types.
+pub trait IntoMiddleware<Marker> {
+    fn into_middleware(self) -> MiddlewareFunction;
+}
+
+impl<M> IntoMiddleware<()> for M
+where
+    M: IMiddleware + Send + Sync + 'static,
+{
+    fn into_middleware(self) -> MiddlewareFunction {
+        Box::new(self)
+    }
+}
+
+struct SyncMiddleware<F>(F);
+
+impl<F> IMiddleware for SyncMiddleware<F>
+where
+    F: Fn(Request, Response, NextFunction) -> Response + Send + Sync + 'static,
+{
+    fn handle(&self, request: Request, next: Next) -> HandlerFuture {
+        // The sync function cannot call the rest of the chain itself,
+        // so calling next only stashes (request, response) into Response::next.
+        let result = (self.0)(request, next.response.clone(), |request, response| {
+            let mut stashed = Response::new();
+            stashed.next = Some(Box::new((request, response)));
+
+            stashed
+        });
+
+        Box::pin(async move {
+            match result.next {
+                Some(stashed) => {
+                    let (request, response) = *stashed;
+
+                    next.run_with(request, response).await
+                }
+                None => result,
+            }
+        })
+    }
+}
+
+impl<F> IntoMiddleware<fn(Request, Response, NextFunction) -> Response> for F
+where
+    F: Fn(Request, Response, NextFunction) -> Response + Send + Sync + 'static,
+{
+    fn into_middleware(self) -> MiddlewareFunction {
+        Box::new(SyncMiddleware(self))
+    }
+}
+
+struct AsyncMiddleware<F>(F);
+
+impl<F, Fut> IMiddleware for AsyncMiddleware<F>
+where
+    F: Fn(Request, Next) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Response> + Send + 'static,
+{
+    fn handle(&self, request: Request, next: Next) -> HandlerFuture {
+        Box::pin((self.0)(request, next))
+    }
+}
+
+impl<F, Fut> IntoMiddleware<fn(Request, Next) -> Fut> for F
+where
+    F: Fn(Request, Next) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Response> + Send + 'static,
+{
+    fn into_middleware(self) -> MiddlewareFunction {
+        Box::new(AsyncMiddleware(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    struct EchoHandler {}
+
+    impl crate::IHandler for EchoHandler {
+        fn handle(&self, request: Request, response: Response) -> Response {
+            response.text(request.path)
+        }
+    }
+
+    static CALL_LOG: Mutex<Vec<&'static str>> = Mutex::new(vec![]);
+
+    fn sync_forward(request: Request, response: Response, next: NextFunction) -> Response {
+        CALL_LOG.lock().unwrap().push("sync");
+
+        next(request, response.header("x-sync", "1"))
+    }
+
+    fn sync_block(_: Request, response: Response, _: NextFunction) -> Response {
+        response.status(403).text("blocked")
+    }
+
+    async fn async_wrap(request: Request, next: Next) -> Response {
+        CALL_LOG.lock().unwrap().push("async before");
+
+        let response = next.run(request).await;
+
+        CALL_LOG.lock().unwrap().push("async after");
+
+        response.header("x-async", "1")
+    }
+
+    fn body_of(response: &Response) -> String {
+        match &response.data {
+            crate::response::ResponseData::Immediate(body) => {
+                String::from_utf8(body.clone()).unwrap()
+            }
+            _ => panic!("expected immediate body"),
+        }
+    }
+
+    #[test]
+    fn test_middleware_chain() {
+        struct TestCase {
+            name: String,
+            middlewares: Vec<MiddlewareFunction>,
+            expected_status: u16,
+            expected_body: String,
+            expected_headers: Vec<&'static str>,
+            expected_log: Vec<&'static str>,
+        }
+
+        let test_cases = [
+            TestCase {
+                name: "미들웨어 없음".to_string(),
+                middlewares: vec![],
+                expected_status: 200,
+                expected_body: "/users".to_string(),
+                expected_headers: vec![],
+                expected_log: vec![],
+            },
+            TestCase {
+                name: "동기 미들웨어 후 async 미들웨어, 핸들러 이후 코드 실행".to_string(),
+                middlewares: vec![sync_forward.into_middleware(), async_wrap.into_middleware()],
+                expected_status: 200,
+                expected_body: "/users".to_string(),
+                expected_headers: vec!["x-sync", "x-async"],
+                expected_log: vec!["sync", "async before", "async after"],
+            },
+            TestCase {
+                name: "동기 미들웨어에서 응답을 바로 반환".to_string(),
+                middlewares: vec![async_wrap.into_middleware(), sync_block.into_middleware()],
+                expected_status: 403,
+                expected_body: "blocked".to_string(),
+                expected_headers: vec!["x-async"],
+                expected_log: vec!["async before", "async after"],
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            CALL_LOG.lock().unwrap().clear();
+
+            let next = Next::new(Arc::new(tc.middlewares), Arc::new(EchoHandler {}));
+            let response = runtime.block_on(next.run(Request::for_test().with_path("/users")));
+
+            assert_eq!(response.status, tc.expected_status, "{} failed", tc.name);
+            assert_eq!(body_of(&response), tc.expected_body, "{} failed", tc.name);
+            for header in tc.expected_headers {
+                assert!(
+                    response
+                        .headers
+                        .contains_key(&crate::HeaderName::from_static(header)),
+                    "{} failed: missing header {}",
+                    tc.name,
+                    header
+                );
+            }
+            assert_eq!(
+                *CALL_LOG.lock().unwrap(),
+                tc.expected_log,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+}
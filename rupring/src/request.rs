@@ -58,6 +58,37 @@ pub fn echo(
 }
 ```
 
+A route path can also have the following segments.
+- `*name` or `{*name}`: matches the rest of the path. (ex. `js/app.js`) It must be the last segment.
+- `:name?` or `{name?}`: optional trailing segment. The parameter should be wrapped in `Option`.
+- `:name(regex)` or `{name:regex}`: the segment must match the regular expression.
+- `{name:type}`: typed constraint. `int`, `uint`, `float`, `bool`, `alpha` and `uuid` are supported.
+
+Static segments are matched first, then constrained parameters, unconstrained parameters, and catch-all segments.
+Since `\` cannot be written as a token, use a string literal for paths with regular expressions.
+```rust
+#[rupring::Get(path = "/users/:id(\\d+)")]
+pub fn get_user(
+    #[PathVariable="id"] id: i32
+) -> rupring::Response {
+    rupring::Response::new().text(id.to_string())
+}
+
+#[rupring::Get(path = /posts/{page?:int})]
+pub fn list_posts(
+    #[PathVariable="page"] page: Option<i32>
+) -> rupring::Response {
+    rupring::Response::new().text(format!("{:?}", page))
+}
+
+#[rupring::Get(path = /static/{*file})]
+pub fn get_static_file(
+    #[PathVariable="file"] file: String
+) -> rupring::Response {
+    rupring::Response::new().text(file)
+}
+```
+
 If you need Swagger documentation for the Path Param, you should add the `Description` annotation.
 `Description` can also be used as `Desc`, `desc`, etc.
 ```rust
@@ -84,6 +115,53 @@ impl rupring::ParamStringDeserializer<SomeCustomType> for rupring::ParamString {
     }
 }
 ```
+
+## Request: Extractors
+
+Other parts of the request can also be bound through annotations.
+- `#[RequestBody]`: deserializes the JSON body. (`Option` is `None` for an empty body)
+- `#[Query]`: deserializes the whole query string into a struct.
+- `#[Query="name"]`: a single query parameter. (QueryStringDeserializer)
+- `#[Header="x-foo"]`, `#[Cookie="sid"]`: a header or cookie value. (ParamStringDeserializer)
+- `#[Inject]`: a provider of the DI context. A reference is borrowed, any other type is cloned.
+
+If a value cannot be bound, the handler is not called and a `400 Bad Request` error is returned.
+Missing values of `Option` types are `None`.
+```rust
+#[derive(Debug, Clone, Default)]
+pub struct UserService {}
+
+#[derive(Debug, serde::Deserialize, rupring::RupringDto)]
+pub struct CreateUserRequest {
+    pub username: String,
+}
+
+#[derive(Debug, serde::Deserialize, rupring::RupringDto)]
+pub struct Paging {
+    pub page: Option<i32>,
+}
+
+#[rupring::Post(path = /users)]
+pub fn create_user(
+    #[RequestBody] body: CreateUserRequest,
+    #[Query] paging: Paging,
+    #[Query="dry_run"] dry_run: Option<bool>,
+    #[Header="x-request-id"] request_id: Option<String>,
+    #[Cookie="sid"] session_id: String,
+    #[Inject] user_service: &UserService,
+) -> rupring::Response {
+    rupring::Response::new().text(body.username)
+}
+```
+
+The parameters and the request body are also written to the swagger document. (The body and `#[Query]` struct should derive RupringDto)
+
+## Request: Form
+
+Query strings and `application/x-www-form-urlencoded` bodies are percent-decoded. (see [crate::http::form])
+- `request.form()` returns the fields of a form body.
+- `request.bind::<T>()` of RupringDto reads a form body if the content type is `application/x-www-form-urlencoded`, otherwise a JSON body.
+- `a[]=1&a[]=2` is the same as `a=1&a=2`, and nested keys like `user[name]` are bound to nested structs.
 */
 use std::{
     collections::HashMap,
@@ -95,21 +173,25 @@ use std::{
 use hyper::{header, Version};
 
 use crate::{
-    http::multipart::{parse_multipart, parse_multipart_boundary, MultipartFile},
+    error::Errors,
+    http::{
+        body::{BodyStream, TakeOnce},
+        multipart::{parse_multipart, parse_multipart_boundary, Multipart, MultipartFile},
+    },
     Method,
 };
 
 #[derive(Debug, Clone)]
 pub enum Protocol {
     HTTP1,
-    // HTTP2,
+    HTTP2,
 }
 
 impl From<Version> for Protocol {
     fn from(version: Version) -> Self {
         match version {
             Version::HTTP_10 | Version::HTTP_11 => Protocol::HTTP1,
-            // Version::HTTP_2 => Protocol::HTTP2,
+            Version::HTTP_2 => Protocol::HTTP2,
             _ => Protocol::HTTP1,
         }
     }
@@ -149,6 +231,10 @@ pub struct Request {
     pub path_parameters: HashMap<String, String>,
     pub metadata: Metadata,
     pub(crate) di_context: Arc<crate::DIContext>,
+    // The multipart body of a `stream_multipart` route, which is not read yet.
+    pub(crate) multipart: TakeOnce<Multipart>,
+    // The body of a `stream_body` route, which is not read yet.
+    pub(crate) body_stream: TakeOnce<BodyStream>,
 }
 
 impl Request {
@@ -182,11 +268,76 @@ impl Request {
 
         Err(anyhow::anyhow!("No multipart/form-data"))
     }
+
+    /// Returns the streaming parser of the multipart body. (see [crate::http::multipart])
+    /// - The route should be declared with `stream_multipart = true`, and the request should be `multipart/form-data`.
+    /// - The body can be taken only once.
+    pub fn multipart(&self) -> Result<Multipart, Errors> {
+        self.multipart.take().ok_or_else(|| {
+            Errors::MultipartFailed(
+                "not a streaming multipart request, or the body is already taken".to_string(),
+            )
+        })
+    }
+
+    /// Returns the stream of the request body. (see [crate::http::body])
+    /// - The route should be declared with `stream_body = true`.
+    /// - The body can be taken only once.
+    pub fn body_stream(&self) -> Result<BodyStream, Errors> {
+        self.body_stream.take().ok_or_else(|| {
+            Errors::RequestBodyReadFailed(
+                "not a streaming body request, or the body is already taken".to_string(),
+            )
+        })
+    }
 }
 
 impl Request {
+    /// Bind the request to the given type.
+    /// - The error is [crate::error::Errors::BindFailed] or [crate::error::Errors::ValidationFailed], and the default response for it is `400 Bad Request`.
     pub fn bind<T: BindFromRequest + Default>(&self) -> anyhow::Result<T> {
-        BindFromRequest::bind(self.clone())
+        BindFromRequest::bind(self.clone()).map_err(|error| {
+            if error.is::<crate::error::Errors>() {
+                return error;
+            }
+
+            crate::error::Errors::BindFailed(error.to_string()).into()
+        })
+    }
+}
+
+impl Request {
+    /// Returns true if the content type is `application/x-www-form-urlencoded`.
+    pub fn is_form(&self) -> bool {
+        self.headers
+            .get(header::CONTENT_TYPE.as_str())
+            .is_some_and(|content_type| content_type.starts_with(crate::http::meme::FORM))
+    }
+
+    /// Parse the `application/x-www-form-urlencoded` body.
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// #[rupring::Post(path = /login)]
+    /// pub fn login(request: rupring::Request) -> rupring::Response {
+    ///     let form: HashMap<String, Vec<String>> = request.form();
+    ///     let username = form.get("username").and_then(|e| e.first()).cloned().unwrap_or_default();
+    ///
+    ///     rupring::Response::new().text(username)
+    /// }
+    /// ```
+    pub fn form(&self) -> HashMap<String, Vec<String>> {
+        crate::http::form::parse_form_urlencoded(&self.raw_body)
+    }
+
+    /// Deserialize the body as a form if the content type is `application/x-www-form-urlencoded`, otherwise as JSON.
+    /// - This is used by [BindFromRequest] of RupringDto.
+    pub fn deserialize_body<T: serde::de::DeserializeOwned>(&self) -> anyhow::Result<T> {
+        if self.is_form() {
+            Ok(crate::http::form::deserialize_form(&self.form())?)
+        } else {
+            Ok(serde_json::from_slice(&self.raw_body)?)
+        }
     }
 }
 
@@ -202,6 +353,94 @@ impl Request {
     pub fn get_provider<T: 'static>(&self) -> Option<&T> {
         self.di_context.get::<T>()
     }
+
+    /// Returns the provider with the qualifier. (see [crate::di])
+    pub fn get_qualified_provider<T: 'static>(&self, qualifier: &str) -> Option<&T> {
+        self.di_context.get_qualified::<T>(qualifier)
+    }
+
+    /// Returns the DI context shared by all requests. (used by `#[Inject]` parameters)
+    pub fn di_context(&self) -> Arc<crate::DIContext> {
+        Arc::clone(&self.di_context)
+    }
+}
+
+// Requests for the handler tests. (ex. Request::for_test().with_path("/users"))
+#[cfg(test)]
+impl Request {
+    pub(crate) fn for_test() -> Self {
+        Request {
+            method: Method::GET,
+            path: String::new(),
+            body: String::new(),
+            raw_body: vec![],
+            files: vec![],
+            headers: HashMap::new(),
+            cookies: HashMap::new(),
+            query_parameters: HashMap::new(),
+            path_parameters: HashMap::new(),
+            metadata: Default::default(),
+            di_context: Arc::new(crate::DIContext::new()),
+            multipart: Default::default(),
+            body_stream: Default::default(),
+        }
+    }
+
+    pub(crate) fn with_method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    pub(crate) fn with_path(mut self, path: impl ToString) -> Self {
+        self.path = path.to_string();
+        self
+    }
+
+    // Both the body and the raw body
+    pub(crate) fn with_body(mut self, body: impl ToString) -> Self {
+        self.body = body.to_string();
+        self.raw_body = self.body.as_bytes().to_vec();
+        self
+    }
+
+    pub(crate) fn with_header(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub(crate) fn with_cookie(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.cookies.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub(crate) fn with_query(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.query_parameters
+            .entry(name.to_string())
+            .or_default()
+            .push(value.to_string());
+        self
+    }
+
+    pub(crate) fn with_path_parameter(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.path_parameters
+            .insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub(crate) fn with_di_context(mut self, di_context: crate::DIContext) -> Self {
+        self.di_context = Arc::new(di_context);
+        self
+    }
+
+    pub(crate) fn with_multipart(mut self, multipart: Multipart) -> Self {
+        self.multipart = TakeOnce::new(multipart);
+        self
+    }
+
+    pub(crate) fn with_body_stream(mut self, body_stream: BodyStream) -> Self {
+        self.body_stream = TakeOnce::new(body_stream);
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -572,3 +811,181 @@ impl ParamStringDeserializer<String> for ParamString {
         Ok(self.0.clone())
     }
 }
+
+/// Request body wrapper type for JsonBodyDeserializer. (`#[RequestBody]` parameter)
+#[derive(Debug, Clone)]
+pub struct JsonBody(pub Vec<u8>);
+
+pub trait JsonBodyDeserializer<T>: Sized {
+    type Error;
+
+    fn deserialize_json_body(&self) -> Result<T, Self::Error>;
+}
+
+impl<T: serde::de::DeserializeOwned> JsonBodyDeserializer<T> for JsonBody {
+    type Error = serde_json::Error;
+
+    fn deserialize_json_body(&self) -> Result<T, Self::Error> {
+        serde_json::from_slice(&self.0)
+    }
+}
+
+/// Query parameters wrapper type for QueryMapDeserializer. (`#[Query]` parameter without a name)
+/// - If a single value is expected, the first value of the key is used.
+/// - Nested keys (`user[name]`, `items[0][id]`) are deserialized into nested structs and `Vec`s. (see [crate::http::form])
+#[derive(Debug, Clone)]
+pub struct QueryMap(pub HashMap<String, Vec<String>>);
+
+pub trait QueryMapDeserializer<T>: Sized {
+    type Error;
+
+    fn deserialize_query_map(&self) -> Result<T, Self::Error>;
+}
+
+impl<T: serde::de::DeserializeOwned> QueryMapDeserializer<T> for QueryMap {
+    type Error = crate::http::form::FormError;
+
+    fn deserialize_query_map(&self) -> Result<T, Self::Error> {
+        crate::http::form::deserialize_form(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as rupring;
+
+    #[derive(Debug, Default, PartialEq, serde::Deserialize, rupring_macro::RupringDto)]
+    struct SignInRequest {
+        username: String,
+        remember: Option<bool>,
+    }
+
+    #[test]
+    fn test_bind_body() {
+        struct TestCase {
+            name: String,
+            content_type: &'static str,
+            body: &'static str,
+            expected: Option<SignInRequest>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "JSON body".to_string(),
+                content_type: "application/json",
+                body: r#"{"username":"foo","remember":true}"#,
+                expected: Some(SignInRequest {
+                    username: "foo".to_string(),
+                    remember: Some(true),
+                }),
+            },
+            TestCase {
+                name: "form body".to_string(),
+                content_type: "application/x-www-form-urlencoded; charset=utf-8",
+                body: "username=foo+bar%21&remember=",
+                expected: Some(SignInRequest {
+                    username: "foo bar!".to_string(),
+                    remember: None,
+                }),
+            },
+            TestCase {
+                name: "form body가 아니면 JSON으로 해석".to_string(),
+                content_type: "text/plain",
+                body: "username=foo",
+                expected: None,
+            },
+        ];
+
+        for tc in test_cases {
+            let request = Request {
+                method: Method::POST,
+                path: String::new(),
+                body: tc.body.to_string(),
+                raw_body: tc.body.as_bytes().to_vec(),
+                files: vec![],
+                headers: HashMap::from([("content-type".to_string(), tc.content_type.to_string())]),
+                cookies: HashMap::new(),
+                query_parameters: HashMap::new(),
+                path_parameters: HashMap::new(),
+                metadata: Default::default(),
+                di_context: Arc::new(crate::DIContext::new()),
+                multipart: Default::default(),
+                body_stream: Default::default(),
+            };
+
+            let result = request.bind::<SignInRequest>().ok();
+
+            assert_eq!(result, tc.expected, "{} failed", tc.name);
+        }
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct SearchQuery {
+        keyword: String,
+        page: i32,
+        size: Option<i32>,
+    }
+
+    #[test]
+    fn test_deserialize_query_map() {
+        struct TestCase {
+            name: String,
+            query_parameters: Vec<(&'static str, Vec<&'static str>)>,
+            expected: Option<SearchQuery>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "모든 필드 바인딩".to_string(),
+                query_parameters: vec![
+                    ("keyword", vec!["foo bar"]),
+                    ("page", vec!["2"]),
+                    ("size", vec!["10"]),
+                ],
+                expected: Some(SearchQuery {
+                    keyword: "foo bar".to_string(),
+                    page: 2,
+                    size: Some(10),
+                }),
+            },
+            TestCase {
+                name: "Option 필드 생략".to_string(),
+                query_parameters: vec![("keyword", vec!["foo"]), ("page", vec!["1", "2"])],
+                expected: Some(SearchQuery {
+                    keyword: "foo".to_string(),
+                    page: 1,
+                    size: None,
+                }),
+            },
+            TestCase {
+                name: "타입이 맞지 않음".to_string(),
+                query_parameters: vec![("keyword", vec!["foo"]), ("page", vec!["a"])],
+                expected: None,
+            },
+            TestCase {
+                name: "필수 필드 누락".to_string(),
+                query_parameters: vec![("page", vec!["1"])],
+                expected: None,
+            },
+        ];
+
+        for tc in test_cases {
+            let query_map = QueryMap(
+                tc.query_parameters
+                    .into_iter()
+                    .map(|(key, values)| {
+                        (
+                            key.to_string(),
+                            values.into_iter().map(|value| value.to_string()).collect(),
+                        )
+                    })
+                    .collect(),
+            );
+
+            let result: Option<SearchQuery> = query_map.deserialize_query_map().ok();
+
+            assert_eq!(result, tc.expected, "{} failed", tc.name);
+        }
+    }
+}
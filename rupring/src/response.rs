@@ -62,6 +62,21 @@ pub fn hello(_request: rupring::Request) -> rupring::Response {
 }
 ```
 This method automatically sets status to 302 unless you set it to 300-308.
+
+If you want to return an error in the RFC 7807 format (`application/problem+json`), you can use ProblemDetail.
+```rust
+use rupring::response::ProblemDetail;
+
+#[rupring::Get(path = /users/:id)]
+pub fn get_user(_request: rupring::Request) -> rupring::Response {
+    rupring::Response::new().problem(
+        ProblemDetail::new(404)
+            .type_("https://example.com/problems/user-not-found")
+            .detail("user 1 not found")
+            .extension("userId", 1),
+    )
+}
+```
 */
 
 use std::{
@@ -75,6 +90,7 @@ use crate::{
     http::{
         cookie::Cookie,
         meme::{self, EVENT_STREAM},
+        websocket::{WebSocket, WebSocketResponse},
     },
     HeaderName, Request,
 };
@@ -86,6 +102,7 @@ pub(crate) type BoxedResponseBody = http_body_util::combinators::BoxBody<Bytes,
 pub enum ResponseData {
     Immediate(Vec<u8>),
     Stream(StreamResponse),
+    WebSocket(WebSocketResponse),
 }
 
 impl ResponseData {
@@ -105,7 +122,8 @@ impl Default for ResponseData {
 
 type OnCloseFn = dyn Fn() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync;
 
-type StreamFn = dyn Fn(StreamHandler) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync;
+pub(crate) type StreamFn =
+    dyn Fn(StreamHandler) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync;
 
 #[derive(Default, Clone)]
 pub struct StreamResponse {
@@ -133,6 +151,7 @@ pub struct Response {
     pub data: ResponseData,
     pub headers: HashMap<HeaderName, Vec<String>>,
     pub(crate) next: Option<Box<(Request, Response)>>,
+    pub(crate) error: Option<Arc<anyhow::Error>>,
 }
 
 impl UnwindSafe for Response {}
@@ -149,6 +168,7 @@ impl Response {
             data: Default::default(),
             headers: Default::default(),
             next: None,
+            error: None,
         }
     }
 
@@ -353,6 +373,44 @@ impl Response {
         self
     }
 
+    /// Set an error to be handled by the error handlers registered in the module.
+    /// If no error handler handles it, the response becomes `500 Internal Server Error`.
+    /// (If the error is [crate::error::Errors], the status code of the error is used.)
+    /// ```
+    /// let response = rupring::Response::new().error(rupring::error!("user not found"));
+    /// assert_eq!(response.status, 500);
+    pub fn error(mut self, error: impl Into<anyhow::Error>) -> Self {
+        let error = error.into();
+
+        self.status = error
+            .downcast_ref::<crate::error::Errors>()
+            .map(|error| error.status())
+            .unwrap_or(500);
+        self.error = Some(Arc::new(error));
+        self
+    }
+
+    /// Set to return a RFC 7807 problem detail. (`application/problem+json`)
+    /// The status code of the response is set to the status of the problem detail.
+    /// ```
+    /// use rupring::response::ProblemDetail;
+    ///
+    /// let response = rupring::Response::new().problem(ProblemDetail::new(404).detail("user 1 not found"));
+    /// assert_eq!(response.status, 404);
+    /// assert_eq!(response.data.into_bytes(), r#"{"type":"about:blank","title":"Not Found","status":404,"detail":"user 1 not found"}"#.to_string().into_bytes());
+    /// ```
+    pub fn problem(self, problem: ProblemDetail) -> Self {
+        let status = problem.status;
+
+        let mut response = self.json(problem);
+        response.headers.insert(
+            crate::HeaderName::from_static(header::CONTENT_TYPE),
+            vec![meme::PROBLEM_JSON.into()],
+        );
+
+        response.status(status)
+    }
+
     /// Set a header.
     /// ```
     /// use rupring::HeaderName;
@@ -565,8 +623,147 @@ impl Response {
 
         self.stream(stream_fn)
     }
+
+    /// Accept the WebSocket handshake of the request, and run the handler on the upgraded connection.
+    /// If the request is not a valid handshake, the response becomes `400 Bad Request`. ([crate::error::Errors::WebSocketHandshakeFailed])
+    /// ```
+    /// use rupring::http::websocket::Message;
+    ///
+    /// #[rupring::Get(path = /ws)]
+    /// pub fn chat(request: rupring::Request) -> rupring::Response {
+    ///     rupring::Response::new().websocket(&request, |mut socket| async move {
+    ///         while let Some(Ok(Message::Text(text))) = socket.recv().await {
+    ///             let _ = socket.send_text(text).await;
+    ///         }
+    ///     })
+    /// }
+    /// ```
+    pub fn websocket<F, Fut>(self, request: &Request, handler: F) -> Self
+    where
+        F: Fn(WebSocket) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let accept_key = match crate::http::websocket::accept_key(request) {
+            Ok(accept_key) => accept_key,
+            Err(error) => return self.error(error),
+        };
+
+        let mut response = self
+            .status(101)
+            .header(header::UPGRADE, "websocket")
+            .header(header::CONNECTION, "Upgrade")
+            .header(header::SEC_WEBSOCKET_ACCEPT, accept_key);
+
+        response.data = ResponseData::WebSocket(WebSocketResponse::new(handler));
+
+        response
+    }
 }
 
 pub trait IntoResponse {
     fn into_response(self) -> Response;
 }
+
+impl IntoResponse for Response {
+    fn into_response(self) -> Response {
+        self
+    }
+}
+
+// The error is passed to the error handlers registered in the module.
+impl<T, E> IntoResponse for Result<T, E>
+where
+    T: IntoResponse,
+    E: Into<anyhow::Error>,
+{
+    fn into_response(self) -> Response {
+        match self {
+            Ok(value) => value.into_response(),
+            Err(error) => Response::new().error(error),
+        }
+    }
+}
+
+// For early returns in handlers that return `Result<Response, E>`.
+impl<E> From<Response> for Result<Response, E> {
+    fn from(response: Response) -> Self {
+        Ok(response)
+    }
+}
+
+/// RFC 7807 problem detail
+/// - `type`: URI reference that identifies the problem type. (default: `about:blank`)
+/// - `title`: Short summary of the problem type. (default: reason phrase of the status code)
+/// - `extensions`: Additional members serialized next to the standard members.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ProblemDetail {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub instance: Option<String>,
+    #[serde(flatten)]
+    pub extensions: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ProblemDetail {
+    pub fn new(status: u16) -> Self {
+        let title = hyper::StatusCode::from_u16(status)
+            .ok()
+            .and_then(|status| status.canonical_reason())
+            .unwrap_or_default();
+
+        Self {
+            type_: "about:blank".to_string(),
+            title: title.to_string(),
+            status,
+            detail: None,
+            instance: None,
+            extensions: Default::default(),
+        }
+    }
+
+    pub fn type_(mut self, type_: impl ToString) -> Self {
+        self.type_ = type_.to_string();
+        self
+    }
+
+    pub fn title(mut self, title: impl ToString) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    pub fn detail(mut self, detail: impl ToString) -> Self {
+        self.detail = Some(detail.to_string());
+        self
+    }
+
+    pub fn instance(mut self, instance: impl ToString) -> Self {
+        self.instance = Some(instance.to_string());
+        self
+    }
+
+    /// Add an extension member.
+    /// ```
+    /// use rupring::response::ProblemDetail;
+    ///
+    /// let problem = ProblemDetail::new(400).extension("errors", vec!["name is required"]);
+    /// assert_eq!(problem.extensions.get("errors").unwrap(), &rupring::serde_json::json!(["name is required"]));
+    /// ```
+    pub fn extension(mut self, key: impl ToString, value: impl serde::Serialize) -> Self {
+        if let Ok(value) = serde_json::to_value(value) {
+            self.extensions.insert(key.to_string(), value);
+        }
+
+        self
+    }
+}
+
+impl IntoResponse for ProblemDetail {
+    fn into_response(self) -> Response {
+        Response::new().problem(self)
+    }
+}
@@ -1,33 +1,159 @@
 use std::sync::{Arc, RwLock};
 
+use crate::application_properties::{ApplicationProperties, ErrorFormat, OpenApiVersion};
+use crate::core::route::{parse_path_segment, PathSegment};
+use crate::response::ProblemDetail;
 use crate::IModule;
 use crate::{self as rupring};
 
+use super::macros::SwaggerRequestBody;
+use super::openapi::{OpenApiSchema, OpenApiServer};
 use super::{
     json::{SwaggerPath, SwaggerSchema},
     SwaggerTags,
 };
 use super::{
-    SwaggerParameter, SwaggerParameterCategory, SwaggerReference, SwaggerResponse,
-    SwaggerTypeOrReference,
+    SwaggerHeader, SwaggerOperation, SwaggerParameter, SwaggerParameterCategory, SwaggerReference,
+    SwaggerResponse, SwaggerSecurityDefinition, SwaggerTypeOrReference,
 };
 
 #[derive(Debug, Clone, Default)]
 pub struct SwaggerContext {
+    // Swagger 2.0 (/docs/swagger.json)
     pub openapi_json: Arc<RwLock<String>>,
+    // OpenAPI 3.1 (/docs/openapi.json)
+    pub openapi_3_1_json: Arc<RwLock<String>>,
+    // The document version shown by the Swagger UI
+    pub ui_version: Arc<RwLock<OpenApiVersion>>,
+    // swagger.path (ex. /docs)
+    pub path: Arc<RwLock<String>>,
+    // If false, only the documents are served.
+    pub ui_enabled: Arc<RwLock<bool>>,
 }
 
 impl SwaggerContext {
+    // The documents with the default application properties.
     pub fn initialize_from_module(&self, module: impl IModule + Clone + 'static) {
+        self.initialize_from_module_with_properties(module, &ApplicationProperties::default());
+    }
+
+    // swagger.* (info, servers, security schemes) and server.error.format are applied to the documents.
+    pub fn initialize_from_module_with_properties(
+        &self,
+        module: impl IModule + Clone + 'static,
+        application_properties: &ApplicationProperties,
+    ) {
         let mut swagger = SwaggerSchema {
             tags: { SWAGGER_TAGS.0.clone() },
+            info: application_properties.swagger.info.clone(),
             ..Default::default()
         };
 
-        generate_swagger(&mut swagger, Box::new(module));
+        if let Some(server) = application_properties.swagger.servers.first() {
+            apply_server(&mut swagger, server);
+        }
+
+        for (name, definition) in application_properties.swagger.security_schemes.iter() {
+            swagger
+                .security_definitions
+                .insert(name.clone(), definition.clone());
+        }
+
+        generate_swagger(&mut swagger, Box::new(module), application_properties);
+
+        if application_properties.server.error.format == ErrorFormat::ProblemJson {
+            document_problem_detail(&mut swagger);
+        }
+
+        let mut openapi = OpenApiSchema::from(swagger.clone());
+
+        remove_cookies(&mut swagger);
 
         let mut openapi_json = self.openapi_json.write().unwrap();
         *openapi_json = serde_json::to_string(&swagger).unwrap();
+
+        // The first server is also the host of the 2.0 document, so the servers replace the derived ones.
+        if !application_properties.swagger.servers.is_empty() {
+            openapi.servers = application_properties
+                .swagger
+                .servers
+                .iter()
+                .map(OpenApiServer::new)
+                .collect();
+        }
+
+        let mut openapi_3_1_json = self.openapi_3_1_json.write().unwrap();
+        *openapi_3_1_json = serde_json::to_string(&openapi).unwrap();
+
+        let mut ui_version = self.ui_version.write().unwrap();
+        *ui_version = application_properties.swagger.openapi_version;
+
+        let mut path = self.path.write().unwrap();
+        *path = application_properties.swagger.path.clone();
+
+        let mut ui_enabled = self.ui_enabled.write().unwrap();
+        *ui_enabled = application_properties.swagger.ui_enabled;
+    }
+}
+
+// Swagger 2.0 has no cookie location, so cookie parameters and API keys are only written to the OpenAPI 3.1 document.
+fn remove_cookies(swagger: &mut SwaggerSchema) {
+    for path in swagger.paths.values_mut() {
+        for operation in path.values_mut() {
+            operation
+                .parameters
+                .retain(|parameter| parameter.in_ != SwaggerParameterCategory::Cookie);
+        }
+    }
+
+    let cookie_api_keys = swagger
+        .security_definitions
+        .iter()
+        .filter(|(_, definition)| {
+            matches!(definition, SwaggerSecurityDefinition::APIKey(api_key) if api_key.in_ == "cookie")
+        })
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+
+    if cookie_api_keys.is_empty() {
+        return;
+    }
+
+    for name in cookie_api_keys.iter() {
+        swagger.security_definitions.remove(name);
+    }
+
+    for path in swagger.paths.values_mut() {
+        for operation in path.values_mut() {
+            operation.security.retain(|requirement| {
+                !requirement
+                    .keys()
+                    .any(|name| cookie_api_keys.contains(name))
+            });
+        }
+    }
+}
+
+// https://api.example.com/v1 => schemes: [https], host: api.example.com, basePath: /v1
+fn apply_server(swagger: &mut SwaggerSchema, server: &str) {
+    let (scheme, rest) = match server.split_once("://") {
+        Some((scheme, rest)) => (Some(scheme), rest),
+        None => (None, server),
+    };
+
+    let (host, base_path) = match rest.find('/') {
+        Some(index) => (&rest[..index], rest[index..].trim_end_matches('/')),
+        None => (rest, ""),
+    };
+
+    if !host.is_empty() {
+        swagger.host = Some(host.to_string());
+    }
+
+    swagger.base_path = base_path.to_string();
+
+    if let Some(scheme) = scheme {
+        swagger.schemes = vec![scheme.to_string()];
     }
 }
 
@@ -53,38 +179,105 @@ fn to_string(method: hyper::Method) -> String {
 
 static SWAGGER_TAGS: SwaggerTags = SwaggerTags::new();
 
-fn generate_swagger(swagger: &mut SwaggerSchema, root_module: Box<dyn crate::IModule>) {
+fn generate_swagger(
+    swagger: &mut SwaggerSchema,
+    root_module: Box<dyn crate::IModule>,
+    application_properties: &ApplicationProperties,
+) {
+    generate_swagger_from_module(swagger, root_module, &[], application_properties);
+}
+
+// The responses of the error handlers are documented on the routes of the module and its child modules.
+fn generate_swagger_from_module(
+    swagger: &mut SwaggerSchema,
+    root_module: Box<dyn crate::IModule>,
+    parent_error_responses: &[(String, SwaggerResponse)],
+    application_properties: &ApplicationProperties,
+) {
+    // The error handlers of the child module are tried first.
+    let mut error_responses: Vec<(String, SwaggerResponse)> = vec![];
+
+    for error_handler in root_module.error_handlers() {
+        for response_info in error_handler.swagger_responses() {
+            if error_responses
+                .iter()
+                .any(|(status, _)| status == &response_info.status)
+            {
+                continue;
+            }
+
+            let response = make_response(swagger, &response_info.status, response_info.body);
+
+            error_responses.push((response_info.status, response));
+        }
+    }
+
+    for (status, response) in parent_error_responses {
+        if !error_responses.iter().any(|(e, _)| e == status) {
+            error_responses.push((status.clone(), response.clone()));
+        }
+    }
+
     for controller in root_module.controllers() {
-        let prefix = controller.prefix();
+        let prefix = controller.resolve_prefix(application_properties);
+        let controller_security = controller.swagger_security_info();
 
         for route in controller.routes() {
+            let mut operation = route.swagger();
+
+            // hidden = true
+            if operation.hidden {
+                continue;
+            }
+
             let normalized_path = crate::core::route::normalize_path(prefix.clone(), route.path());
             let normalized_path = swaggerize_url(normalized_path.as_str());
-            let mut operation = route.swagger();
 
-            for security in route.swagger_security_info() {
-                operation.security.push(security);
+            let mut security = route.swagger_security_info();
+
+            // The auth of the route takes precedence over the default of the controller.
+            if security.is_empty() && !operation.auth_disabled {
+                security = controller_security.clone();
+            }
+
+            for mut requirement in security {
+                // Only OAuth2 requirements have scopes.
+                for (name, scopes) in requirement.iter_mut() {
+                    if !matches!(
+                        swagger.security_definitions.get(name),
+                        Some(SwaggerSecurityDefinition::Oauth2(_))
+                    ) {
+                        scopes.clear();
+                    }
+                }
+
+                operation.security.push(requirement);
             }
 
             let request_info = route.swagger_request_info();
 
             if let Some(swagger_request_body) = request_info {
-                operation.parameters.push(SwaggerParameter {
-                    name: swagger_request_body
-                        .definition_name
-                        .split("::")
-                        .last()
-                        .unwrap_or("Request Body")
-                        .to_string(),
-                    in_: SwaggerParameterCategory::Body,
-                    description: "Request Body".to_string(),
-                    required: true,
-                    schema: Some(SwaggerTypeOrReference::Reference(SwaggerReference {
-                        reference: "#/definitions/".to_string()
-                            + swagger_request_body.definition_name.as_str(),
-                    })),
-                    type_: None,
-                });
+                // A definition with only path and query parameters has no body.
+                let has_body = !swagger_request_body.definition_value.properties.is_empty();
+
+                if has_body {
+                    operation.parameters.push(SwaggerParameter {
+                        name: swagger_request_body
+                            .definition_name
+                            .split("::")
+                            .last()
+                            .unwrap_or("Request Body")
+                            .to_string(),
+                        in_: SwaggerParameterCategory::Body,
+                        description: "Request Body".to_string(),
+                        required: true,
+                        schema: Some(SwaggerTypeOrReference::Reference(SwaggerReference {
+                            reference: "#/definitions/".to_string()
+                                + swagger_request_body.definition_name.as_str(),
+                        })),
+                        type_: None,
+                    });
+                }
 
                 swagger.definitions.insert(
                     swagger_request_body.definition_name.clone(),
@@ -107,46 +300,28 @@ fn generate_swagger(swagger: &mut SwaggerSchema, root_module: Box<dyn crate::IMo
                 }
             }
 
-            let response_info = route.swagger_response_info();
+            if let Some(swagger_response_body) = route.swagger_response_info() {
+                let response = make_response(swagger, "200", Some(swagger_response_body));
 
-            if let Some(swagger_response_body) = response_info {
-                swagger.definitions.insert(
-                    swagger_response_body.definition_name.clone(),
-                    swagger_response_body.definition_value,
-                );
+                operation.responses.insert("200".to_string(), response);
+            }
 
-                operation.responses.insert(
-                    "200".to_string(),
-                    SwaggerResponse {
-                        description: "OK".to_string(),
-                        schema: Some(SwaggerReference {
-                            reference: "#/definitions/".to_string()
-                                + swagger_response_body.definition_name.as_str(),
-                        }),
-                    },
-                );
+            for response_info in route.swagger_responses() {
+                let response = make_response(swagger, &response_info.status, response_info.body);
 
-                for dependency in swagger_response_body.dependencies {
-                    swagger.definitions.insert(
-                        dependency.definition_name.clone(),
-                        dependency.definition_value,
-                    );
-                }
+                operation.responses.insert(response_info.status, response);
             }
 
-            // TODO: 추후에는 swagger ignore 속성을 추가해서 그걸로 처리
-            match normalized_path.as_str() {
-                "/docs/swagger.json"
-                | "/docs"
-                | "/docs/favicon-16x16.png"
-                | "/docs/favicon-32x32.png"
-                | "/docs/swagger-initializer.js"
-                | "/docs/swagger-ui.css"
-                | "/docs/swagger-ui-standalone-preset.js"
-                | "/docs/swagger-ui-bundle.js" => continue,
-                _ => {}
+            // The responses declared in the route take precedence over the error handlers.
+            for (status, response) in error_responses.iter() {
+                operation
+                    .responses
+                    .entry(status.clone())
+                    .or_insert_with(|| response.clone());
             }
 
+            document_response_headers(&mut operation);
+
             let method = to_string(route.method());
 
             if let Some(path) = swagger.paths.get_mut(&normalized_path) {
@@ -166,49 +341,141 @@ fn generate_swagger(swagger: &mut SwaggerSchema, root_module: Box<dyn crate::IMo
     }
 
     for child_module in root_module.child_modules() {
-        generate_swagger(swagger, child_module);
+        generate_swagger_from_module(
+            swagger,
+            child_module,
+            &error_responses,
+            application_properties,
+        );
     }
 }
 
-// /:id/do-something -> /{id}/do-something
-fn swaggerize_url(url: &str) -> String {
-    let mut result = String::new();
+// Registers the definitions of the body, and returns the response that refers to it.
+fn make_response(
+    swagger: &mut SwaggerSchema,
+    status: &str,
+    body: Option<SwaggerRequestBody>,
+) -> SwaggerResponse {
+    let description = status_description(status);
+
+    let Some(body) = body else {
+        return SwaggerResponse {
+            description,
+            schema: None,
+            headers: Default::default(),
+        };
+    };
 
-    let mut is_segment_start = true;
-    let mut in_path_param = false;
+    let reference = "#/definitions/".to_string() + body.definition_name.as_str();
 
-    for c in url.chars() {
-        match c {
-            '/' => {
-                if in_path_param {
-                    result.push('}');
-                    in_path_param = false;
-                }
+    swagger
+        .definitions
+        .insert(body.definition_name, body.definition_value);
 
-                is_segment_start = true;
+    for dependency in body.dependencies {
+        swagger
+            .definitions
+            .insert(dependency.definition_name, dependency.definition_value);
+    }
 
-                result.push(c);
+    SwaggerResponse {
+        description,
+        schema: Some(SwaggerReference { reference }),
+        headers: Default::default(),
+    }
+}
 
-                continue;
-            }
-            ':' if is_segment_start => {
-                is_segment_start = false;
-                in_path_param = true;
-                result.push('{');
-                continue;
-            }
-            _ => {
-                is_segment_start = false;
-                result.push(c);
-            }
+// "404" => "Not Found"
+fn status_description(status: &str) -> String {
+    let reason = status
+        .parse::<u16>()
+        .ok()
+        .and_then(|status| hyper::StatusCode::from_u16(status).ok())
+        .and_then(|status| status.canonical_reason());
+
+    match (reason, status) {
+        (Some(reason), _) => reason.to_string(),
+        (None, "default") => "Error".to_string(),
+        (None, _) => status.to_string(),
+    }
+}
+
+// The response headers are documented on the success (2xx) responses.
+fn document_response_headers(operation: &mut SwaggerOperation) {
+    if operation.response_headers.is_empty() {
+        return;
+    }
+
+    if !operation.responses.keys().any(|e| e.starts_with('2')) {
+        operation.responses.insert(
+            "200".to_string(),
+            SwaggerResponse {
+                description: status_description("200"),
+                schema: None,
+                headers: Default::default(),
+            },
+        );
+    }
+
+    for (status, response) in operation.responses.iter_mut() {
+        if !status.starts_with('2') {
+            continue;
+        }
+
+        for header in operation.response_headers.iter() {
+            response.headers.insert(
+                header.clone(),
+                SwaggerHeader {
+                    type_: "string".to_string(),
+                    description: "".to_string(),
+                },
+            );
         }
     }
+}
 
-    if in_path_param {
-        result.push('}');
+// The default error responses of the framework are documented as the `default` response of every operation.
+fn document_problem_detail(swagger: &mut SwaggerSchema) {
+    let Some(problem_detail) =
+        crate::swagger::macros::generate_swagger_request_info::<ProblemDetail>()
+    else {
+        return;
+    };
+
+    for path in swagger.paths.values_mut() {
+        for operation in path.values_mut() {
+            operation
+                .responses
+                .entry("default".to_string())
+                .or_insert_with(|| SwaggerResponse {
+                    description: "Error (application/problem+json)".to_string(),
+                    schema: Some(SwaggerReference {
+                        reference: "#/definitions/".to_string()
+                            + problem_detail.definition_name.as_str(),
+                    }),
+                    headers: Default::default(),
+                });
+        }
     }
 
-    result
+    swagger.definitions.insert(
+        problem_detail.definition_name,
+        problem_detail.definition_value,
+    );
+}
+
+// /:id/do-something -> /{id}/do-something
+// /:id(\d+), /{id:int}, /*rest -> /{id}, /{id}, /{rest}
+fn swaggerize_url(url: &str) -> String {
+    url.split("/")
+        .map(|segment| match parse_path_segment(segment) {
+            PathSegment::Static(segment) => segment.to_string(),
+            PathSegment::Param { name, .. } | PathSegment::CatchAll { name } => {
+                format!("{{{name}}}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
 }
 
 #[cfg(test)]
@@ -231,5 +498,42 @@ mod tests {
             swaggerize_url("/users/:id/do-something/:id2/"),
             "/users/{id}/do-something/{id2}/"
         );
+        assert_eq!(swaggerize_url(r"/users/:id(\d+)"), "/users/{id}");
+        assert_eq!(
+            swaggerize_url("/users/{id:int}/:page?"),
+            "/users/{id}/{page}"
+        );
+        assert_eq!(swaggerize_url("/static/*file"), "/static/{file}");
+        assert_eq!(swaggerize_url("/static/{*file}"), "/static/{file}");
+    }
+
+    #[test]
+    fn test_document_problem_detail() {
+        let mut swagger = SwaggerSchema::default();
+
+        let mut path = SwaggerPath::default();
+        path.insert("get".to_string(), Default::default());
+        swagger.paths.insert("/users/{id}".to_string(), path);
+
+        document_problem_detail(&mut swagger);
+
+        let definition_name = "rupring::response::ProblemDetail";
+
+        let response = swagger.paths["/users/{id}"]["get"]
+            .responses
+            .get("default")
+            .expect("default response should be documented");
+        assert_eq!(
+            response.schema.as_ref().unwrap().reference,
+            format!("#/definitions/{definition_name}")
+        );
+
+        let definition = swagger
+            .definitions
+            .get(definition_name)
+            .expect("ProblemDetail should be documented");
+        assert_eq!(definition.required, vec!["type", "title", "status"]);
+        assert!(definition.properties.contains_key("detail"));
+        assert!(definition.properties.contains_key("instance"));
     }
 }
@@ -4,11 +4,14 @@ use crate as rupring;
 #[derive(Debug, Clone)]
 #[rupring_macro::Controller(
     prefix = /docs, 
+    resolve_prefix = routes::swagger_path,
     routes=[
         routes::get_docs, 
+        routes::get_oauth2_redirect,
         routes::get_favicon32,
         routes::get_favicon16,
         routes::get_json,
+        routes::get_openapi_json,
         routes::get_swagger_ui_bundle,
         routes::get_swagger_ui_css,
     ]
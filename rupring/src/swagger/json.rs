@@ -31,7 +31,7 @@ pub struct SwaggerSchema {
     #[serde(rename = "securityDefinitions")]
     pub security_definitions: SwaggerSecurityDefinitions,
 
-    #[serde(rename = "externalDocs")]
+    #[serde(rename = "externalDocs", skip_serializing_if = "Option::is_none")]
     pub external_docs: Option<SwaggerExternalDoc>,
 }
 
@@ -52,7 +52,7 @@ impl Default for SwaggerSchema {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SwaggerLicense {
     #[serde(rename = "name")]
     pub name: String,
@@ -70,7 +70,7 @@ impl Default for SwaggerLicense {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SwaggerInfo {
     #[serde(rename = "title")]
     pub title: String,
@@ -104,8 +104,14 @@ impl Default for SwaggerInfo {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SwaggerContact {
+    #[serde(rename = "name", default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
+
+    #[serde(rename = "url", default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+
     #[serde(rename = "email")]
     pub email: String,
 }
@@ -113,6 +119,8 @@ pub struct SwaggerContact {
 impl Default for SwaggerContact {
     fn default() -> Self {
         SwaggerContact {
+            name: "".to_string(),
+            url: "".to_string(),
             email: "".to_string(),
         }
     }
@@ -126,7 +134,7 @@ pub struct SwaggerTag {
     #[serde(rename = "description")]
     pub description: String,
 
-    #[serde(rename = "externalDocs")]
+    #[serde(rename = "externalDocs", skip_serializing_if = "Option::is_none")]
     pub external_docs: Option<SwaggerExternalDoc>,
 }
 
@@ -210,6 +218,18 @@ pub struct SwaggerOperation {
 
     #[serde(rename = "security")]
     pub security: Vec<SwaggerSecurity>,
+
+    // Headers of the success (2xx) responses. (#[response_headers = [...]])
+    #[serde(skip)]
+    pub response_headers: Vec<String>,
+
+    // If true, the route is not documented. (hidden = true)
+    #[serde(skip)]
+    pub hidden: bool,
+
+    // If true, the default security of the controller is not applied. (#[auth = none])
+    #[serde(skip)]
+    pub auth_disabled: bool,
 }
 
 impl Default for SwaggerOperation {
@@ -224,6 +244,9 @@ impl Default for SwaggerOperation {
             parameters: Default::default(),
             responses: Default::default(),
             security: Default::default(),
+            response_headers: Default::default(),
+            hidden: false,
+            auth_disabled: false,
         }
     }
 }
@@ -249,7 +272,7 @@ pub struct SwaggerParameter {
     pub schema: Option<SwaggerTypeOrReference>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum SwaggerParameterCategory {
     #[serde(rename = "path")]
     Path,
@@ -265,6 +288,9 @@ pub enum SwaggerParameterCategory {
 
     #[serde(rename = "formData")]
     FormData,
+
+    #[serde(rename = "cookie")]
+    Cookie,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -282,6 +308,20 @@ pub struct SwaggerResponse {
 
     #[serde(rename = "schema")]
     pub schema: Option<SwaggerReference>,
+
+    #[serde(rename = "headers", skip_serializing_if = "HashMap::is_empty", default)]
+    pub headers: SwaggerHeaders,
+}
+
+pub type SwaggerHeaders = HashMap<String, SwaggerHeader>;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SwaggerHeader {
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    #[serde(rename = "description")]
+    pub description: String,
 }
 
 pub type SwaggerSecurity = HashMap<String, Vec<String>>;
@@ -297,6 +337,65 @@ impl SwaggerSecurityDefinition {
         })
     }
 
+    /// API key in the header. (ex. X-API-Key)
+    pub fn new_api_key_header(name: impl ToString) -> Self {
+        Self::new_api_key(name, "header")
+    }
+
+    /// API key in the query string. (ex. api_key)
+    pub fn new_api_key_query(name: impl ToString) -> Self {
+        Self::new_api_key(name, "query")
+    }
+
+    /// API key in the cookie. (OpenAPI 3.1 only, it is not written to the Swagger 2.0 document)
+    pub fn new_api_key_cookie(name: impl ToString) -> Self {
+        Self::new_api_key(name, "cookie")
+    }
+
+    fn new_api_key(name: impl ToString, in_: &str) -> Self {
+        SwaggerSecurityDefinition::APIKey(SwaggerAPIKey {
+            type_: "apiKey".to_string(),
+            name: name.to_string(),
+            in_: in_.to_string(),
+        })
+    }
+
+    /// OAuth2 authorization code flow. (scopes: [(name, description)])
+    pub fn new_oauth2_authorization_code(
+        authorization_url: impl ToString,
+        token_url: impl ToString,
+        scopes: &[(&str, &str)],
+    ) -> Self {
+        SwaggerSecurityDefinition::Oauth2(SwaggerOauth2 {
+            type_: "oauth2".to_string(),
+            flow: "accessCode".to_string(),
+            authorization_url: authorization_url.to_string(),
+            token_url: token_url.to_string(),
+            scopes: to_scopes(scopes),
+        })
+    }
+
+    /// OAuth2 client credentials flow. (scopes: [(name, description)])
+    pub fn new_oauth2_client_credentials(
+        token_url: impl ToString,
+        scopes: &[(&str, &str)],
+    ) -> Self {
+        SwaggerSecurityDefinition::Oauth2(SwaggerOauth2 {
+            type_: "oauth2".to_string(),
+            flow: "application".to_string(),
+            authorization_url: "".to_string(),
+            token_url: token_url.to_string(),
+            scopes: to_scopes(scopes),
+        })
+    }
+
+    /// HTTP basic authentication.
+    pub fn new_basic_auth() -> Self {
+        SwaggerSecurityDefinition::Basic(SwaggerBasicAuth {
+            type_: "basic".to_string(),
+        })
+    }
+
     pub fn new_default_definitions() -> SwaggerSecurityDefinitions {
         let mut security_definitions = SwaggerSecurityDefinitions::new();
 
@@ -306,14 +405,22 @@ impl SwaggerSecurityDefinition {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+fn to_scopes(scopes: &[(&str, &str)]) -> SwaggerOauth2Scopes {
+    scopes
+        .iter()
+        .map(|(name, description)| (name.to_string(), description.to_string()))
+        .collect()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(untagged)]
 pub enum SwaggerSecurityDefinition {
     APIKey(SwaggerAPIKey),
     Oauth2(SwaggerOauth2),
+    Basic(SwaggerBasicAuth),
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SwaggerAPIKey {
     #[serde(rename = "type")]
     pub type_: String,
@@ -325,28 +432,42 @@ pub struct SwaggerAPIKey {
     pub in_: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct SwaggerOauth2 {
     #[serde(rename = "type")]
     pub type_: String,
 
+    // implicit, password, application, accessCode
     #[serde(rename = "flow")]
     pub flow: String,
 
-    #[serde(rename = "authorizationUrl")]
+    #[serde(
+        rename = "authorizationUrl",
+        default,
+        skip_serializing_if = "String::is_empty"
+    )]
     pub authorization_url: String,
 
+    #[serde(rename = "tokenUrl", default, skip_serializing_if = "String::is_empty")]
+    pub token_url: String,
+
     #[serde(rename = "scopes")]
     pub scopes: SwaggerOauth2Scopes,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SwaggerBasicAuth {
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
 pub type SwaggerOauth2Scopes = HashMap<String, String>;
 
 pub type SwaggerDefinitions = HashMap<String, SwaggerDefinition>;
 
 pub type SwaggerDefinition = SwaggerDefinitionObject;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct SwaggerDefinitionObject {
     #[serde(rename = "type")]
     pub type_: String,
@@ -362,6 +483,15 @@ pub struct SwaggerDefinitionObject {
 
     #[serde(skip_serializing)]
     pub query_parameters: Vec<SwaggerParameter>,
+
+    // Properties of Option<T> type, which are nullable in OpenAPI 3.1.
+    #[serde(skip_serializing, default)]
+    pub nullable: Vec<String>,
+
+    // Variants of an enum with data, which are oneOf in OpenAPI 3.1.
+    // Swagger 2.0 has no oneOf, so the definition has the properties of all variants instead.
+    #[serde(skip_serializing, default)]
+    pub one_of: Vec<SwaggerProperty>,
 }
 
 pub type SwaggerProperties = HashMap<String, SwaggerProperty>;
@@ -372,6 +502,21 @@ pub enum SwaggerProperty {
     Array(SwaggerArrayProperty),
     Single(SwaggerSingleProperty),
     Reference(SwaggerReferenceProperty),
+    Map(SwaggerMapProperty),
+    Object(SwaggerDefinitionObject),
+}
+
+// HashMap<String, T> => { "type": "object", "additionalProperties": T }
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SwaggerMapProperty {
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    #[serde(rename = "additionalProperties")]
+    pub additional_properties: Box<SwaggerProperty>,
+
+    #[serde(rename = "description")]
+    pub description: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -384,6 +529,9 @@ pub struct SwaggerArrayProperty {
 
     #[serde(rename = "description")]
     pub description: String,
+
+    #[serde(flatten)]
+    pub validation: SwaggerValidation,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -434,6 +582,59 @@ pub struct SwaggerSingleProperty {
 
     #[serde(rename = "example")]
     pub example: Option<SwaggerPrimitiveType>, // TODO: Union으로 치환
+
+    #[serde(flatten)]
+    pub validation: SwaggerValidation,
+}
+
+// Constraints from the validation attributes of RupringDto
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SwaggerValidation {
+    #[serde(rename = "minimum", skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f64>,
+
+    #[serde(rename = "maximum", skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<f64>,
+
+    #[serde(rename = "minLength", skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+
+    #[serde(rename = "maxLength", skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    #[serde(rename = "minItems", skip_serializing_if = "Option::is_none")]
+    pub min_items: Option<usize>,
+
+    #[serde(rename = "maxItems", skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<usize>,
+
+    #[serde(rename = "pattern", skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    #[serde(rename = "format", skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    // Values of a unit enum
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub enum_: Option<Vec<String>>,
+}
+
+impl SwaggerValidation {
+    // Fills the constraints that are not set with the constraints of `fallback`.
+    // (ex. the field attributes over the format of the type)
+    pub fn or(self, fallback: SwaggerValidation) -> SwaggerValidation {
+        SwaggerValidation {
+            minimum: self.minimum.or(fallback.minimum),
+            maximum: self.maximum.or(fallback.maximum),
+            min_length: self.min_length.or(fallback.min_length),
+            max_length: self.max_length.or(fallback.max_length),
+            min_items: self.min_items.or(fallback.min_items),
+            max_items: self.max_items.or(fallback.max_items),
+            pattern: self.pattern.or(fallback.pattern),
+            format: self.format.or(fallback.format),
+            enum_: self.enum_.or(fallback.enum_),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -445,10 +646,13 @@ pub struct SwaggerReferenceProperty {
     pub description: String,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct SwaggerType {
     #[serde(rename = "type")]
     pub type_: String,
+
+    #[serde(flatten)]
+    pub validation: SwaggerValidation,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -460,8 +664,6 @@ pub enum SwaggerTypeOrReference {
 
 impl Default for SwaggerTypeOrReference {
     fn default() -> Self {
-        SwaggerTypeOrReference::Type(SwaggerType {
-            type_: "".to_string(),
-        })
+        SwaggerTypeOrReference::Type(SwaggerType::default())
     }
 }
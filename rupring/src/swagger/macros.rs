@@ -1,8 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use super::{
-    SwaggerArrayProperty, SwaggerDefinition, SwaggerDefinitionObject, SwaggerParameter,
-    SwaggerReference, SwaggerSingleProperty, SwaggerType, SwaggerTypeOrReference,
+    SwaggerArrayProperty, SwaggerDefinition, SwaggerDefinitionObject, SwaggerMapProperty,
+    SwaggerParameter, SwaggerPrimitiveType, SwaggerProperty, SwaggerReference,
+    SwaggerReferenceProperty, SwaggerSingleProperty, SwaggerType, SwaggerTypeOrReference,
+    SwaggerValidation,
 };
 
 pub struct SwaggerRequestInfo {
@@ -30,6 +32,7 @@ pub enum SwaggerDefinitionNode {
     Object(SwaggerDefinition),
     Array(SwaggerArrayProperty),
     Single(SwaggerSingleProperty),
+    Map(SwaggerMapProperty),
 }
 
 impl ToSwaggerDefinitionNode for i8 {
@@ -77,6 +80,15 @@ impl ToSwaggerDefinitionNode for i128 {
     }
 }
 
+impl ToSwaggerDefinitionNode for isize {
+    fn to_swagger_definition(_: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
+        SwaggerDefinitionNode::Single(SwaggerSingleProperty {
+            type_: "number".to_string(),
+            ..Default::default()
+        })
+    }
+}
+
 impl ToSwaggerDefinitionNode for u8 {
     fn to_swagger_definition(_: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
         SwaggerDefinitionNode::Single(SwaggerSingleProperty {
@@ -122,6 +134,15 @@ impl ToSwaggerDefinitionNode for u128 {
     }
 }
 
+impl ToSwaggerDefinitionNode for usize {
+    fn to_swagger_definition(_: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
+        SwaggerDefinitionNode::Single(SwaggerSingleProperty {
+            type_: "number".to_string(),
+            ..Default::default()
+        })
+    }
+}
+
 impl ToSwaggerDefinitionNode for bool {
     fn to_swagger_definition(_: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
         SwaggerDefinitionNode::Single(SwaggerSingleProperty {
@@ -158,35 +179,424 @@ impl ToSwaggerDefinitionNode for String {
     }
 }
 
+// string with a format (https://swagger.io/docs/specification/data-models/data-types/#format)
+fn formatted_string(format: &str) -> SwaggerDefinitionNode {
+    SwaggerDefinitionNode::Single(SwaggerSingleProperty {
+        type_: "string".to_string(),
+        validation: SwaggerValidation {
+            format: Some(format.to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+impl<Tz: chrono::TimeZone> ToSwaggerDefinitionNode for chrono::DateTime<Tz> {
+    fn to_swagger_definition(_: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
+        formatted_string("date-time")
+    }
+}
+
+impl ToSwaggerDefinitionNode for chrono::NaiveDateTime {
+    fn to_swagger_definition(_: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
+        formatted_string("date-time")
+    }
+}
+
+impl ToSwaggerDefinitionNode for chrono::NaiveDate {
+    fn to_swagger_definition(_: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
+        formatted_string("date")
+    }
+}
+
+impl ToSwaggerDefinitionNode for chrono::NaiveTime {
+    fn to_swagger_definition(_: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
+        formatted_string("time")
+    }
+}
+
+impl ToSwaggerDefinitionNode for uuid::Uuid {
+    fn to_swagger_definition(_: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
+        formatted_string("uuid")
+    }
+}
+
 impl<T: ToSwaggerDefinitionNode> ToSwaggerDefinitionNode for Vec<T> {
     fn to_swagger_definition(context: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
-        let item = T::to_swagger_definition(context);
-
-        let item = match item {
-            SwaggerDefinitionNode::Single(item) => {
-                SwaggerTypeOrReference::Type(SwaggerType { type_: item.type_ })
-            }
-            SwaggerDefinitionNode::Array(item) => item.items,
-            SwaggerDefinitionNode::Object(_) => {
-                SwaggerTypeOrReference::Reference(SwaggerReference {
-                    reference: "#/definitions/".to_string() + T::get_definition_name().as_str(),
-                })
-            }
-        };
+        let item = property_of::<T>(context);
 
         SwaggerDefinitionNode::Array(SwaggerArrayProperty {
             type_: "array".to_string(),
-            items: item,
+            items: type_or_reference(item),
             ..Default::default()
         })
     }
 }
 
+// JSON object keys are always strings, so the key type is not documented.
+impl<K, V: ToSwaggerDefinitionNode, S> ToSwaggerDefinitionNode for HashMap<K, V, S> {
+    fn to_swagger_definition(context: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
+        map_of::<V>(context)
+    }
+}
+
+impl<K, V: ToSwaggerDefinitionNode> ToSwaggerDefinitionNode for BTreeMap<K, V> {
+    fn to_swagger_definition(context: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
+        map_of::<V>(context)
+    }
+}
+
+fn map_of<V: ToSwaggerDefinitionNode>(
+    context: &mut SwaggerDefinitionContext,
+) -> SwaggerDefinitionNode {
+    SwaggerDefinitionNode::Map(SwaggerMapProperty {
+        type_: "object".to_string(),
+        additional_properties: Box::new(property_of::<V>(context)),
+        description: "".to_string(),
+    })
+}
+
 impl<T: ToSwaggerDefinitionNode> ToSwaggerDefinitionNode for Option<T> {
     fn to_swagger_definition(context: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
         T::to_swagger_definition(context)
     }
+
+    fn get_definition_name() -> String {
+        T::get_definition_name()
+    }
+}
+
+// RFC 7807 error schema (server.error.format=problem-json)
+impl ToSwaggerDefinitionNode for crate::response::ProblemDetail {
+    fn to_swagger_definition(_: &mut SwaggerDefinitionContext) -> SwaggerDefinitionNode {
+        let property = |type_: &str, description: &str| {
+            SwaggerProperty::Single(SwaggerSingleProperty {
+                type_: type_.to_string(),
+                description: description.to_string(),
+                ..Default::default()
+            })
+        };
+
+        SwaggerDefinitionNode::Object(SwaggerDefinitionObject {
+            type_: "object".to_string(),
+            properties: HashMap::from([
+                (
+                    "type".to_string(),
+                    property("string", "URI reference that identifies the problem type"),
+                ),
+                (
+                    "title".to_string(),
+                    property("string", "Short summary of the problem type"),
+                ),
+                ("status".to_string(), property("number", "HTTP status code")),
+                (
+                    "detail".to_string(),
+                    property("string", "Explanation specific to this occurrence"),
+                ),
+                (
+                    "instance".to_string(),
+                    property("string", "URI reference of this occurrence"),
+                ),
+            ]),
+            required: vec![
+                "type".to_string(),
+                "title".to_string(),
+                "status".to_string(),
+            ],
+            ..Default::default()
+        })
+    }
+
+    fn get_definition_name() -> String {
+        "rupring::response::ProblemDetail".to_string()
+    }
+}
+
+// The validation of a path parameter, from the constraint of its route segment. ex) /users/{id:int}
+pub fn path_parameter_validation(route_path: &str, name: &str) -> SwaggerValidation {
+    let (pattern, format) = crate::core::route::path_parameter_constraint(route_path, name);
+
+    SwaggerValidation {
+        pattern,
+        format: format.map(|e| e.to_string()),
+        ..Default::default()
+    }
 }
+
+// The property of a field of type T. An object is added to the definitions and referenced.
+pub fn property_of<T: ToSwaggerDefinitionNode>(
+    context: &mut SwaggerDefinitionContext,
+) -> SwaggerProperty {
+    match T::to_swagger_definition(context) {
+        SwaggerDefinitionNode::Single(property) => SwaggerProperty::Single(property),
+        SwaggerDefinitionNode::Array(property) => SwaggerProperty::Array(property),
+        SwaggerDefinitionNode::Map(property) => SwaggerProperty::Map(property),
+        SwaggerDefinitionNode::Object(object) => {
+            let definition_name = T::get_definition_name();
+
+            if definition_name.is_empty() {
+                return SwaggerProperty::Object(object);
+            }
+
+            context.definitions.insert(definition_name.clone(), object);
+
+            SwaggerProperty::Reference(SwaggerReferenceProperty {
+                reference: "#/definitions/".to_string() + definition_name.as_str(),
+                description: "".to_string(),
+            })
+        }
+    }
+}
+
+// The property of a RupringDto field, with the description, example and validation of the field attributes.
+pub fn field_property<T: ToSwaggerDefinitionNode>(
+    context: &mut SwaggerDefinitionContext,
+    description: &str,
+    example: SwaggerPrimitiveType,
+    validation: SwaggerValidation,
+    array_validation: SwaggerValidation,
+) -> SwaggerProperty {
+    let description = description.to_string();
+
+    match property_of::<T>(context) {
+        SwaggerProperty::Single(leaf) => SwaggerProperty::Single(SwaggerSingleProperty {
+            type_: leaf.type_,
+            description,
+            example: Some(example),
+            validation: validation.or(leaf.validation),
+        }),
+        SwaggerProperty::Array(array) => SwaggerProperty::Array(SwaggerArrayProperty {
+            description,
+            validation: array_validation.or(array.validation),
+            ..array
+        }),
+        SwaggerProperty::Reference(reference) => {
+            SwaggerProperty::Reference(SwaggerReferenceProperty {
+                description,
+                ..reference
+            })
+        }
+        SwaggerProperty::Map(map) => {
+            SwaggerProperty::Map(SwaggerMapProperty { description, ..map })
+        }
+        SwaggerProperty::Object(object) => SwaggerProperty::Object(object),
+    }
+}
+
+// #[serde(flatten)]: the properties of T become the properties of the definition.
+pub fn flatten_into<T: ToSwaggerDefinitionNode>(
+    context: &mut SwaggerDefinitionContext,
+    definition: &mut SwaggerDefinitionObject,
+    required: bool,
+) {
+    let SwaggerDefinitionNode::Object(object) = T::to_swagger_definition(context) else {
+        return;
+    };
+
+    definition.properties.extend(object.properties);
+    definition.nullable.extend(object.nullable);
+
+    if required {
+        definition.required.extend(object.required);
+    }
+}
+
+// The type name without the module paths, used for the definition names of generic types.
+// (ex. alloc::vec::Vec<my_app::User> => Vec<User>)
+pub fn type_name_of<T: ?Sized>() -> String {
+    let mut name = String::new();
+    let mut path = String::new();
+
+    for c in std::any::type_name::<T>().chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+            continue;
+        }
+
+        name += path.rsplit("::").next().unwrap_or_default();
+        path.clear();
+
+        if c != ' ' {
+            name.push(c);
+        }
+    }
+
+    name += path.rsplit("::").next().unwrap_or_default();
+
+    name
+}
+
+fn type_or_reference(property: SwaggerProperty) -> SwaggerTypeOrReference {
+    match property {
+        SwaggerProperty::Single(property) => SwaggerTypeOrReference::Type(SwaggerType {
+            type_: property.type_,
+            validation: property.validation,
+        }),
+        SwaggerProperty::Array(property) => property.items,
+        SwaggerProperty::Reference(property) => {
+            SwaggerTypeOrReference::Reference(SwaggerReference {
+                reference: property.reference,
+            })
+        }
+        SwaggerProperty::Map(_) | SwaggerProperty::Object(_) => {
+            SwaggerTypeOrReference::Type(SwaggerType {
+                type_: "object".to_string(),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+// A tuple is an array of a fixed length. Swagger 2.0 has no tuple, so the items are documented with the first type.
+pub fn tuple_property(items: Vec<SwaggerProperty>) -> SwaggerProperty {
+    let length = items.len();
+
+    SwaggerProperty::Array(SwaggerArrayProperty {
+        type_: "array".to_string(),
+        items: items
+            .into_iter()
+            .next()
+            .map(type_or_reference)
+            .unwrap_or_default(),
+        validation: SwaggerValidation {
+            min_items: Some(length),
+            max_items: Some(length),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+// How the variants of an enum are serialized. (https://serde.rs/enum-representations.html)
+pub enum SwaggerEnumTagging {
+    // {"Variant": content}
+    External,
+    // #[serde(tag = "type")] => {"type": "Variant", ...content}
+    Internal { tag: String },
+    // #[serde(tag = "t", content = "c")] => {"t": "Variant", "c": content}
+    Adjacent { tag: String, content: String },
+    // #[serde(untagged)] => content
+    Untagged,
+}
+
+fn string_enum(values: Vec<String>) -> SwaggerProperty {
+    SwaggerProperty::Single(SwaggerSingleProperty {
+        type_: "string".to_string(),
+        validation: SwaggerValidation {
+            enum_: Some(values),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
+
+fn object_of(properties: Vec<(&str, SwaggerProperty)>) -> SwaggerDefinitionObject {
+    SwaggerDefinitionObject {
+        type_: "object".to_string(),
+        required: properties
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect(),
+        properties: properties
+            .into_iter()
+            .map(|(name, property)| (name.to_string(), property))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+// The schema of a variant of an enum. `content` is None for a unit variant.
+pub fn enum_variant(
+    context: &SwaggerDefinitionContext,
+    tagging: &SwaggerEnumTagging,
+    name: &str,
+    content: Option<SwaggerProperty>,
+) -> SwaggerProperty {
+    let variant = match (tagging, content) {
+        (SwaggerEnumTagging::External, None) => return string_enum(vec![name.to_string()]),
+        (SwaggerEnumTagging::External, Some(content)) => object_of(vec![(name, content)]),
+        (SwaggerEnumTagging::Internal { tag }, None)
+        | (SwaggerEnumTagging::Adjacent { tag, .. }, None) => {
+            object_of(vec![(tag, string_enum(vec![name.to_string()]))])
+        }
+        (SwaggerEnumTagging::Internal { tag }, Some(content)) => {
+            // The tag is a property of the content, which is a struct.
+            let mut variant = match content {
+                SwaggerProperty::Object(object) => object,
+                SwaggerProperty::Reference(reference) => context
+                    .definitions
+                    .get(reference.reference.trim_start_matches("#/definitions/"))
+                    .cloned()
+                    .unwrap_or_default(),
+                _ => Default::default(),
+            };
+
+            variant.type_ = "object".to_string();
+            variant
+                .properties
+                .insert(tag.clone(), string_enum(vec![name.to_string()]));
+            variant.required.insert(0, tag.clone());
+
+            variant
+        }
+        (SwaggerEnumTagging::Adjacent { tag, content: key }, Some(content)) => object_of(vec![
+            (tag, string_enum(vec![name.to_string()])),
+            (key, content),
+        ]),
+        (SwaggerEnumTagging::Untagged, None) => {
+            return SwaggerProperty::Single(SwaggerSingleProperty {
+                type_: "null".to_string(),
+                ..Default::default()
+            })
+        }
+        (SwaggerEnumTagging::Untagged, Some(content)) => return content,
+    };
+
+    SwaggerProperty::Object(variant)
+}
+
+// An enum of unit variants is a string with the enum values.
+// Otherwise it is an object with oneOf the variants.
+pub fn enum_definition(variants: Vec<SwaggerProperty>) -> SwaggerDefinitionNode {
+    let values = variants
+        .iter()
+        .map(|variant| match variant {
+            SwaggerProperty::Single(variant) if variant.type_ == "string" => {
+                variant.validation.enum_.clone()
+            }
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>();
+
+    if let Some(values) = values {
+        let SwaggerProperty::Single(property) = string_enum(values.concat()) else {
+            unreachable!()
+        };
+
+        return SwaggerDefinitionNode::Single(property);
+    }
+
+    let mut definition = SwaggerDefinitionObject {
+        type_: "object".to_string(),
+        ..Default::default()
+    };
+
+    for variant in variants.iter() {
+        if let SwaggerProperty::Object(variant) = variant {
+            for (name, property) in variant.properties.iter() {
+                definition
+                    .properties
+                    .entry(name.clone())
+                    .or_insert_with(|| property.clone());
+            }
+        }
+    }
+
+    definition.one_of = variants;
+
+    SwaggerDefinitionNode::Object(definition)
+}
+
 pub struct SwaggerRequestBody {
     pub definition_name: String,
     pub definition_value: SwaggerDefinitionObject,
@@ -197,6 +607,22 @@ pub struct SwaggerRequestBody {
     pub query_parameters: Vec<SwaggerParameter>,
 }
 
+// A documented response of a route or an error handler. (#[responses(201: UserDto, 404)])
+pub struct SwaggerResponseInfo {
+    // "201", "404", "default"
+    pub status: String,
+    pub body: Option<SwaggerRequestBody>,
+}
+
+impl SwaggerResponseInfo {
+    pub fn new(status: impl ToString, body: Option<SwaggerRequestBody>) -> Self {
+        SwaggerResponseInfo {
+            status: status.to_string(),
+            body,
+        }
+    }
+}
+
 pub fn generate_swagger_request_info<T: ToSwaggerDefinitionNode>() -> Option<SwaggerRequestBody> {
     let mut context = SwaggerDefinitionContext {
         definitions: Default::default(),
@@ -230,3 +656,87 @@ pub fn generate_swagger_request_info<T: ToSwaggerDefinitionNode>() -> Option<Swa
 
     Some(swagger_request_body)
 }
+
+// Extractor parameters (ex. #[RequestBody], #[Query]) accept any deserializable type,
+// so they are documented only when the type implements ToSwaggerDefinitionNode.
+// The route macro calls `(&&SwaggerSchemaOf::<T>::new()).request_info()`,
+// which resolves to SwaggerSchemaDocumented if T implements it, and to SwaggerSchemaUndocumented otherwise.
+pub struct SwaggerSchemaOf<T>(std::marker::PhantomData<T>);
+
+impl<T> SwaggerSchemaOf<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        SwaggerSchemaOf(std::marker::PhantomData)
+    }
+}
+
+pub trait SwaggerSchemaDocumented {
+    fn request_info(&self) -> Option<SwaggerRequestBody>;
+    fn query_parameters(&self) -> Vec<SwaggerParameter>;
+}
+
+impl<T: ToSwaggerDefinitionNode> SwaggerSchemaDocumented for &SwaggerSchemaOf<T> {
+    fn request_info(&self) -> Option<SwaggerRequestBody> {
+        generate_swagger_request_info::<T>()
+    }
+
+    // Every property of the struct is a query parameter.
+    fn query_parameters(&self) -> Vec<SwaggerParameter> {
+        let Some(request_info) = generate_swagger_request_info::<T>() else {
+            return vec![];
+        };
+
+        let definition = request_info.definition_value;
+        let mut parameters = request_info.query_parameters;
+
+        for (name, property) in definition.properties {
+            let (type_, description, validation) = match property {
+                SwaggerProperty::Single(property) => {
+                    (property.type_, property.description, property.validation)
+                }
+                SwaggerProperty::Array(property) => {
+                    (property.type_, property.description, property.validation)
+                }
+                SwaggerProperty::Reference(property) => (
+                    "object".to_string(),
+                    property.description,
+                    Default::default(),
+                ),
+                SwaggerProperty::Map(property) => {
+                    (property.type_, property.description, Default::default())
+                }
+                SwaggerProperty::Object(property) => {
+                    (property.type_, "".to_string(), Default::default())
+                }
+            };
+
+            parameters.push(SwaggerParameter {
+                required: definition.required.contains(&name),
+                name,
+                in_: super::SwaggerParameterCategory::Query,
+                description,
+                schema: Some(SwaggerTypeOrReference::Type(SwaggerType {
+                    type_,
+                    validation,
+                })),
+                type_: None,
+            });
+        }
+
+        parameters.sort_by(|a, b| a.name.cmp(&b.name));
+
+        parameters
+    }
+}
+
+pub trait SwaggerSchemaUndocumented {
+    fn request_info(&self) -> Option<SwaggerRequestBody> {
+        None
+    }
+
+    fn query_parameters(&self) -> Vec<SwaggerParameter> {
+        vec![]
+    }
+}
+
+impl<T> SwaggerSchemaUndocumented for SwaggerSchemaOf<T> {}
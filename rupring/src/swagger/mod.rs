@@ -1,6 +1,6 @@
 /*!
 # Swagger Support
-- When rupring starts the server, it automatically serves swagger documents to the `/docs` path.
+- When rupring starts the server, it automatically serves swagger documents to the `/docs` path. (See [Settings](#settings))
 
 Additional annotations such as `summary`, `description`, and `tags` are provided for swagger documentation.
 ```rust
@@ -43,6 +43,59 @@ pub struct CreateUserRequest {
 5. `#[path_param = "id"]` or `#[param = "id"]`: If the field is a path parameter, you can add this annotation.
 6. `#[query = "query"]`: If the field is a query parameter, you can add this annotation.
 7. `#[ignore]`: If you want to ignore the field, you can add this annotation.
+8. `#[min = 0]`, `#[max = 100]`, `#[range(min = 0, max = 100)]`, `#[length(min = 1, max = 20)]`, `#[pattern = "^[a-z]+$"]`, `#[email]`, `#[validate]`: Validation of the field. It is checked when binding the request, and written to the document as minimum, maximum, minLength, maxLength, pattern and format. (See [crate::validation])
+
+### Enums, maps, generics and serde attributes
+RupringDto can also be derived for enums and generic structs.
+1. An enum of unit variants is a string with `enum` values. An enum with data is `oneOf` its variants in the serde representation (external, `tag`, `tag` + `content`, `untagged`). Swagger 2.0 has no `oneOf`, so it has the properties of all variants instead.
+2. `HashMap<String, T>` and `BTreeMap<String, T>` are objects with `additionalProperties`.
+3. A generic struct like `Page<T>` is documented for each type argument. (ex. `#[response = Page::<UserDto>]`)
+4. `chrono::DateTime`, `NaiveDateTime`, `NaiveDate`, `NaiveTime` and `uuid::Uuid` are strings with the `date-time`, `date`, `time` and `uuid` format.
+5. `#[serde(rename_all, rename, skip, flatten, default)]` are applied to the document and to the binding of the request.
+```rust
+use rupring::RupringDto;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Serialize, Deserialize, RupringDto)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatus {
+    Pending,
+    InProgress,
+}
+
+#[derive(Debug, Serialize, Deserialize, RupringDto)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Payment {
+    Card { card_number: String },
+    Cash,
+}
+
+#[derive(Debug, Serialize, Deserialize, RupringDto)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrderRequest {
+    pub order_id: uuid::Uuid,
+    pub ordered_at: chrono::DateTime<chrono::Utc>,
+    pub status: OrderStatus,
+    pub payment: Payment,
+    pub quantities: HashMap<String, i32>,
+
+    #[serde(default)]
+    pub note: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, RupringDto)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+}
+
+#[rupring::Get(path = /orders)]
+#[response = Page::<CreateOrderRequest>]
+pub fn list_orders(request: rupring::Request) -> rupring::Response {
+    rupring::Response::new()
+}
+```
 
 Then, you can specify request information in the API through the params attribute as follows.
 ```rust
@@ -115,6 +168,171 @@ pub fn get_user(request: rupring::Request, _: rupring::Response) -> rupring::Res
     return rupring::Response::new().text("OK".to_string());
 }
 ```
+
+### Security schemes
+- Other security schemes are declared with [crate::application_properties::Swagger::security_scheme], and referred to by name in `auth`.
+- API key (header, query, cookie), OAuth2 (authorization code, client credentials) and basic are supported. An API key in the cookie is only written to the OpenAPI 3.1 document.
+- `#[auth = [A, B]]` accepts either A or B, and `#[scopes = [...]]` sets the scopes of the OAuth2 schemes.
+- `auth` of the controller is the default of its routes. `#[auth = none]` removes it from a route.
+```rust
+use rupring::swagger::SwaggerSecurityDefinition;
+
+#[derive(Debug, Clone)]
+#[rupring::Controller(prefix=/orders, routes=[list_orders, create_order, health], auth=ApiKeyAuth)]
+pub struct OrderController {}
+
+#[rupring::Get(path = /)]
+pub fn list_orders(request: rupring::Request) -> rupring::Response {
+    rupring::Response::new()
+}
+
+#[rupring::Post(path = /)]
+#[auth = OAuth2]
+#[scopes = ["orders:write"]]
+pub fn create_order(request: rupring::Request) -> rupring::Response {
+    rupring::Response::new()
+}
+
+#[rupring::Get(path = /health)]
+#[auth = none]
+pub fn health(request: rupring::Request) -> rupring::Response {
+    rupring::Response::new()
+}
+
+let swagger = rupring::application_properties::Swagger::default()
+    .security_scheme("ApiKeyAuth", SwaggerSecurityDefinition::new_api_key_header("X-API-Key"))
+    .security_scheme(
+        "OAuth2",
+        SwaggerSecurityDefinition::new_oauth2_authorization_code(
+            "https://auth.example.com/authorize",
+            "https://auth.example.com/token",
+            &[("orders:write", "write orders")],
+        ),
+    )
+    .security_scheme("BasicAuth", SwaggerSecurityDefinition::new_basic_auth());
+```
+With [crate::RupringFactory::swagger], it is `.swagger(|swagger| swagger.security_scheme(...))`.
+
+## Responses
+- `#[response = T]` documents the `200` response. If the route returns other status codes, you can document them with the `responses` attribute.
+- A status code without a type is documented without a body.
+- `#[response_headers = [...]]` documents the headers of the success (2xx) responses.
+```rust
+use rupring::response::ProblemDetail;
+use rupring::RupringDto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, RupringDto)]
+pub struct UserDto {
+    pub id: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, RupringDto)]
+pub struct ConflictDto {
+    pub message: String,
+}
+
+#[rupring::Post(path = /users)]
+#[responses(201: UserDto, 404: ProblemDetail, 409: ConflictDto, 401)]
+#[response_headers = [Location]]
+pub fn create_user(request: rupring::Request) -> rupring::Response {
+    rupring::Response::new().status(201).header("location", "/users/1")
+}
+```
+
+- `#[produces = "..."]` and `#[consumes = "..."]` set the content types of the route. (default: `application/json`)
+- `#[sse]` documents the route as Server-Sent Events. (`text/event-stream`)
+- `stream_multipart = true` routes consume `multipart/form-data`, and `stream_body = true` routes consume `application/octet-stream` unless `consumes` is set.
+```rust
+#[rupring::Get(path = /events)]
+#[sse]
+pub fn subscribe(request: rupring::Request) -> rupring::Response {
+    rupring::Response::new().sse_stream(async move |stream_handler| {
+        // ...
+    })
+}
+```
+
+- The error handlers registered in a module are documented on the routes of the module and its child modules. (The responses declared in the route take precedence)
+- An error handler with `status` is documented as that status, with the body of `#[response = T]`. Other error handlers can declare their responses with `#[responses(...)]`.
+```rust
+use rupring::RupringDto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, RupringDto)]
+pub struct ErrorDto {
+    pub message: String,
+}
+
+#[derive(Debug)]
+pub struct UserNotFound {}
+
+impl std::fmt::Display for UserNotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "user not found")
+    }
+}
+
+impl std::error::Error for UserNotFound {}
+
+#[rupring::ErrorHandler(status = 401)]
+#[response = ErrorDto]
+pub fn handle_unauthorized(error: &rupring::error::HttpError) -> rupring::Response {
+    rupring::Response::new().status(401)
+}
+
+#[rupring::ErrorHandler]
+#[responses(404: ErrorDto)]
+pub fn handle_user_not_found(error: &UserNotFound) -> rupring::Response {
+    rupring::Response::new().status(404)
+}
+```
+
+## OpenAPI 3.1
+- The same document is also served as OpenAPI 3.1 to the `/docs/openapi.json` path. (Swagger 2.0 is served to `/docs/swagger.json`)
+- In OpenAPI 3.1, the definitions are written to `components/schemas` with `.` instead of `::` in the name, the body is written as `requestBody`, and `Option<T>` fields are written as nullable. (`["string", "null"]` or `oneOf`)
+- You can choose the document shown by the Swagger UI, and add the `servers` of the document with application.properties.
+```properties
+swagger.openapi-version=3.1
+swagger.servers=https://api.example.com,http://localhost:3000
+```
+
+## Settings
+- The path of the Swagger UI and the documents is set with `swagger.path`. (default: `/docs`)
+- `swagger.ui.enabled=false` disables the Swagger UI, and only the documents (`swagger.json`, `openapi.json`) are served.
+- The title, version, description, contact and license of the document are set with `swagger.*`. (See [crate::application_properties])
+- The first server of `swagger.servers` is also the host, basePath and schemes of the Swagger 2.0 document.
+```properties
+swagger.path=/api-docs
+swagger.ui.enabled=false
+swagger.title=Shop API
+swagger.version=1.0.0
+swagger.contact.email=api@example.com
+swagger.license.name=MIT
+```
+
+They can also be set in code with [crate::RupringFactory::swagger]. It is applied over application.properties.
+```rust,ignore
+rupring::RupringFactory::create(RootModule {})
+    .swagger(|swagger| {
+        swagger
+            .title("Shop API")
+            .version("1.0.0")
+            .contact("API Team", "https://example.com", "api@example.com")
+            .license("MIT", "https://opensource.org/licenses/MIT")
+            .server("https://api.example.com")
+            .path("/api-docs")
+    })
+    .listen()
+```
+
+A route with `hidden = true` is served, but not documented.
+```rust
+#[rupring::Get(path = /internal/metrics, hidden = true)]
+pub fn metrics(request: rupring::Request) -> rupring::Response {
+    rupring::Response::new().text("OK".to_string())
+}
+```
 */
 
 pub mod context;
@@ -122,7 +340,9 @@ pub mod controller;
 pub mod html;
 pub mod json;
 pub mod module;
+pub mod openapi;
 pub mod routes;
+#[allow(clippy::invisible_characters)]
 pub mod swagger_ui_bundle;
 pub mod swagger_ui_css;
 
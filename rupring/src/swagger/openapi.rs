@@ -0,0 +1,884 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::http::meme;
+
+use super::json::{
+    SwaggerAPIKey, SwaggerDefinitionObject, SwaggerExternalDoc, SwaggerInfo, SwaggerOauth2Scopes,
+    SwaggerOperation, SwaggerParameter, SwaggerParameterCategory, SwaggerPrimitiveType,
+    SwaggerProperty, SwaggerResponse, SwaggerSchema, SwaggerSecurity, SwaggerSecurityDefinition,
+    SwaggerTag, SwaggerTypeOrReference, SwaggerValidation,
+};
+
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+// OpenAPI 3.1 document. It is converted from the Swagger 2.0 document generated from the routes.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OpenApiSchema {
+    #[serde(rename = "openapi")]
+    pub version: String,
+
+    #[serde(rename = "info")]
+    pub info: SwaggerInfo,
+
+    #[serde(rename = "servers", skip_serializing_if = "Vec::is_empty", default)]
+    pub servers: Vec<OpenApiServer>,
+
+    #[serde(rename = "tags")]
+    pub tags: Vec<SwaggerTag>,
+
+    #[serde(rename = "paths")]
+    pub paths: OpenApiPaths,
+
+    #[serde(rename = "components")]
+    pub components: OpenApiComponents,
+
+    #[serde(rename = "externalDocs", skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<SwaggerExternalDoc>,
+}
+
+impl Default for OpenApiSchema {
+    fn default() -> Self {
+        OpenApiSchema {
+            version: OPENAPI_VERSION.to_string(),
+            info: Default::default(),
+            servers: vec![],
+            tags: vec![],
+            paths: Default::default(),
+            components: Default::default(),
+            external_docs: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OpenApiServer {
+    #[serde(rename = "url")]
+    pub url: String,
+
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl OpenApiServer {
+    pub fn new(url: impl ToString) -> Self {
+        OpenApiServer {
+            url: url.to_string(),
+            description: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OpenApiComponents {
+    #[serde(rename = "schemas")]
+    pub schemas: HashMap<String, OpenApiSchemaObject>,
+
+    #[serde(rename = "securitySchemes")]
+    pub security_schemes: HashMap<String, OpenApiSecurityScheme>,
+}
+
+pub type OpenApiPaths = HashMap<String, OpenApiPath>;
+
+pub type OpenApiPath = HashMap<String, OpenApiOperation>;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OpenApiOperation {
+    #[serde(rename = "tags")]
+    pub tags: Vec<String>,
+
+    #[serde(rename = "summary")]
+    pub summary: String,
+
+    #[serde(rename = "description")]
+    pub description: String,
+
+    // operationId must be unique, so an empty one is omitted.
+    #[serde(
+        rename = "operationId",
+        skip_serializing_if = "String::is_empty",
+        default
+    )]
+    pub operation_id: String,
+
+    #[serde(rename = "parameters")]
+    pub parameters: Vec<OpenApiParameter>,
+
+    #[serde(rename = "requestBody", skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<OpenApiRequestBody>,
+
+    #[serde(rename = "responses")]
+    pub responses: OpenApiResponses,
+
+    #[serde(rename = "security")]
+    pub security: Vec<SwaggerSecurity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OpenApiParameter {
+    #[serde(rename = "name")]
+    pub name: String,
+
+    // path, query, header, cookie
+    #[serde(rename = "in")]
+    pub in_: SwaggerParameterCategory,
+
+    #[serde(rename = "description")]
+    pub description: String,
+
+    #[serde(rename = "required")]
+    pub required: bool,
+
+    #[serde(rename = "schema")]
+    pub schema: OpenApiSchemaObject,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OpenApiRequestBody {
+    #[serde(rename = "description")]
+    pub description: String,
+
+    #[serde(rename = "required")]
+    pub required: bool,
+
+    #[serde(rename = "content")]
+    pub content: OpenApiContent,
+}
+
+pub type OpenApiResponses = HashMap<String, OpenApiResponse>;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OpenApiResponse {
+    #[serde(rename = "description")]
+    pub description: String,
+
+    #[serde(rename = "headers", skip_serializing_if = "HashMap::is_empty", default)]
+    pub headers: HashMap<String, OpenApiHeader>,
+
+    #[serde(rename = "content", skip_serializing_if = "HashMap::is_empty", default)]
+    pub content: OpenApiContent,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OpenApiHeader {
+    #[serde(rename = "description")]
+    pub description: String,
+
+    #[serde(rename = "schema")]
+    pub schema: OpenApiSchemaObject,
+}
+
+// content type => media type
+pub type OpenApiContent = HashMap<String, OpenApiMediaType>;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OpenApiMediaType {
+    #[serde(rename = "schema")]
+    pub schema: OpenApiSchemaObject,
+}
+
+// JSON Schema (2020-12) object
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OpenApiSchemaObject {
+    #[serde(rename = "$ref", skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<OpenApiType>,
+
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(rename = "examples", skip_serializing_if = "Vec::is_empty", default)]
+    pub examples: Vec<SwaggerPrimitiveType>,
+
+    #[serde(
+        rename = "properties",
+        skip_serializing_if = "HashMap::is_empty",
+        default
+    )]
+    pub properties: HashMap<String, OpenApiSchemaObject>,
+
+    #[serde(rename = "required", skip_serializing_if = "Vec::is_empty", default)]
+    pub required: Vec<String>,
+
+    #[serde(rename = "items", skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<OpenApiSchemaObject>>,
+
+    #[serde(rename = "oneOf", skip_serializing_if = "Vec::is_empty", default)]
+    pub one_of: Vec<OpenApiSchemaObject>,
+
+    #[serde(
+        rename = "additionalProperties",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub additional_properties: Option<Box<OpenApiSchemaObject>>,
+
+    #[serde(flatten)]
+    pub validation: SwaggerValidation,
+}
+
+// "string" or ["string", "null"]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum OpenApiType {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl OpenApiSchemaObject {
+    pub fn of_type(type_: impl ToString) -> Self {
+        OpenApiSchemaObject {
+            type_: Some(OpenApiType::Single(type_.to_string())),
+            ..Default::default()
+        }
+    }
+
+    pub fn of_reference(reference: impl ToString) -> Self {
+        OpenApiSchemaObject {
+            reference: Some(reference.to_string()),
+            ..Default::default()
+        }
+    }
+
+    // T => T | null
+    // A single type becomes a type array, and a reference becomes oneOf.
+    pub fn nullable(mut self) -> Self {
+        if let Some(OpenApiType::Single(type_)) = &self.type_ {
+            if self.reference.is_none() {
+                self.type_ = Some(OpenApiType::Multiple(vec![
+                    type_.clone(),
+                    "null".to_string(),
+                ]));
+
+                return self;
+            }
+        }
+
+        if let Some(OpenApiType::Multiple(types)) = &mut self.type_ {
+            if !types.iter().any(|e| e == "null") {
+                types.push("null".to_string());
+            }
+
+            return self;
+        }
+
+        let description = self.description.take();
+
+        OpenApiSchemaObject {
+            description,
+            one_of: vec![self, OpenApiSchemaObject::of_type("null")],
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum OpenApiSecurityScheme {
+    APIKey(SwaggerAPIKey),
+    Oauth2(Box<OpenApiOauth2>),
+    Http(OpenApiHttpAuth),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OpenApiHttpAuth {
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    // basic, bearer, ...
+    #[serde(rename = "scheme")]
+    pub scheme: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OpenApiOauth2 {
+    #[serde(rename = "type")]
+    pub type_: String,
+
+    #[serde(rename = "flows")]
+    pub flows: OpenApiOauth2Flows,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OpenApiOauth2Flows {
+    #[serde(rename = "implicit", skip_serializing_if = "Option::is_none")]
+    pub implicit: Option<OpenApiOauth2Flow>,
+
+    #[serde(rename = "password", skip_serializing_if = "Option::is_none")]
+    pub password: Option<OpenApiOauth2Flow>,
+
+    #[serde(rename = "clientCredentials", skip_serializing_if = "Option::is_none")]
+    pub client_credentials: Option<OpenApiOauth2Flow>,
+
+    #[serde(rename = "authorizationCode", skip_serializing_if = "Option::is_none")]
+    pub authorization_code: Option<OpenApiOauth2Flow>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OpenApiOauth2Flow {
+    #[serde(rename = "authorizationUrl", skip_serializing_if = "Option::is_none")]
+    pub authorization_url: Option<String>,
+
+    #[serde(rename = "tokenUrl", skip_serializing_if = "Option::is_none")]
+    pub token_url: Option<String>,
+
+    #[serde(rename = "scopes")]
+    pub scopes: SwaggerOauth2Scopes,
+}
+
+impl From<SwaggerSchema> for OpenApiSchema {
+    fn from(swagger: SwaggerSchema) -> Self {
+        let mut servers = vec![];
+
+        if let Some(host) = &swagger.host {
+            if swagger.schemes.is_empty() {
+                servers.push(OpenApiServer::new(format!(
+                    "http://{host}{}",
+                    swagger.base_path
+                )));
+            }
+
+            for scheme in swagger.schemes.iter() {
+                servers.push(OpenApiServer::new(format!(
+                    "{scheme}://{host}{}",
+                    swagger.base_path
+                )));
+            }
+        } else if !swagger.base_path.is_empty() {
+            servers.push(OpenApiServer::new(&swagger.base_path));
+        }
+
+        let paths = swagger
+            .paths
+            .into_iter()
+            .map(|(url, path)| {
+                let path = path
+                    .into_iter()
+                    .map(|(method, operation)| (method, convert_operation(operation)))
+                    .collect();
+
+                (url, path)
+            })
+            .collect();
+
+        let schemas = swagger
+            .definitions
+            .iter()
+            .map(|(name, definition)| (component_name(name), convert_definition(definition)))
+            .collect();
+
+        let security_schemes = swagger
+            .security_definitions
+            .into_iter()
+            .map(|(name, definition)| (name, convert_security_definition(definition)))
+            .collect();
+
+        OpenApiSchema {
+            version: OPENAPI_VERSION.to_string(),
+            info: swagger.info,
+            servers,
+            tags: swagger.tags,
+            paths,
+            components: OpenApiComponents {
+                schemas,
+                security_schemes,
+            },
+            external_docs: swagger.external_docs,
+        }
+    }
+}
+
+// Component names should match ^[a-zA-Z0-9.\-_]+$, so "a::b::C" becomes "a.b.C",
+// and "a::Page<User,i32>" becomes "a.Page_User_i32".
+pub fn component_name(definition_name: &str) -> String {
+    definition_name
+        .replace("::", ".")
+        .replace(['<', ','], "_")
+        .replace(['>', ' '], "")
+}
+
+// #/definitions/a::b::C => #/components/schemas/a.b.C
+fn component_reference(reference: &str) -> String {
+    let definition_name = reference
+        .strip_prefix("#/definitions/")
+        .unwrap_or(reference);
+
+    format!("#/components/schemas/{}", component_name(definition_name))
+}
+
+// The types of path and query parameters can be Rust types. (ex. i32, Option::<String>)
+// Types other than the known primitives are documented as strings.
+fn json_type(type_: &str) -> String {
+    let type_ = type_.replace(' ', "");
+    let type_ = ["std::option::Option<", "Option::<", "Option<"]
+        .iter()
+        .find_map(|prefix| type_.strip_prefix(prefix)?.strip_suffix('>'))
+        .unwrap_or(&type_);
+
+    match type_ {
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128"
+        | "usize" | "integer" => "integer".to_string(),
+        "f32" | "f64" | "number" => "number".to_string(),
+        "bool" | "boolean" => "boolean".to_string(),
+        "array" | "object" => type_.to_string(),
+        _ => "string".to_string(),
+    }
+}
+
+fn with_description(mut schema: OpenApiSchemaObject, description: String) -> OpenApiSchemaObject {
+    if !description.is_empty() {
+        schema.description = Some(description);
+    }
+
+    schema
+}
+
+fn convert_type_or_reference(type_or_reference: &SwaggerTypeOrReference) -> OpenApiSchemaObject {
+    match type_or_reference {
+        SwaggerTypeOrReference::Type(type_) => OpenApiSchemaObject {
+            validation: type_.validation.clone(),
+            ..OpenApiSchemaObject::of_type(json_type(&type_.type_))
+        },
+        SwaggerTypeOrReference::Reference(reference) => {
+            OpenApiSchemaObject::of_reference(component_reference(&reference.reference))
+        }
+    }
+}
+
+fn convert_property(property: &SwaggerProperty) -> OpenApiSchemaObject {
+    match property {
+        SwaggerProperty::Single(property) => {
+            let mut schema = OpenApiSchemaObject {
+                validation: property.validation.clone(),
+                ..OpenApiSchemaObject::of_type(&property.type_)
+            };
+
+            // An example that is not written is an empty string.
+            match &property.example {
+                Some(SwaggerPrimitiveType::String(example)) if example.is_empty() => {}
+                Some(example) => schema.examples.push(example.clone()),
+                None => {}
+            }
+
+            with_description(schema, property.description.clone())
+        }
+        SwaggerProperty::Array(property) => {
+            let schema = OpenApiSchemaObject {
+                items: Some(Box::new(convert_type_or_reference(&property.items))),
+                validation: property.validation.clone(),
+                ..OpenApiSchemaObject::of_type(&property.type_)
+            };
+
+            with_description(schema, property.description.clone())
+        }
+        SwaggerProperty::Reference(property) => {
+            let schema =
+                OpenApiSchemaObject::of_reference(component_reference(&property.reference));
+
+            with_description(schema, property.description.clone())
+        }
+        SwaggerProperty::Map(property) => {
+            let schema = OpenApiSchemaObject {
+                additional_properties: Some(Box::new(convert_property(
+                    &property.additional_properties,
+                ))),
+                ..OpenApiSchemaObject::of_type(&property.type_)
+            };
+
+            with_description(schema, property.description.clone())
+        }
+        SwaggerProperty::Object(definition) => convert_definition(definition),
+    }
+}
+
+fn convert_definition(definition: &SwaggerDefinitionObject) -> OpenApiSchemaObject {
+    // An enum with data
+    if !definition.one_of.is_empty() {
+        return OpenApiSchemaObject {
+            one_of: definition.one_of.iter().map(convert_property).collect(),
+            ..Default::default()
+        };
+    }
+
+    let properties = definition
+        .properties
+        .iter()
+        .map(|(name, property)| {
+            let schema = convert_property(property);
+
+            if definition.nullable.contains(name) {
+                (name.clone(), schema.nullable())
+            } else {
+                (name.clone(), schema)
+            }
+        })
+        .collect();
+
+    // Path and query parameters of the definition are not properties of the body.
+    let required = definition
+        .required
+        .iter()
+        .filter(|name| definition.properties.contains_key(*name))
+        .cloned()
+        .collect();
+
+    OpenApiSchemaObject {
+        properties,
+        required,
+        ..OpenApiSchemaObject::of_type(&definition.type_)
+    }
+}
+
+fn convert_parameter_schema(parameter: &SwaggerParameter) -> OpenApiSchemaObject {
+    match (&parameter.schema, &parameter.type_) {
+        (Some(schema), _) => convert_type_or_reference(schema),
+        (None, Some(type_)) => OpenApiSchemaObject::of_type(json_type(type_)),
+        (None, None) => OpenApiSchemaObject::of_type("string"),
+    }
+}
+
+fn convert_operation(operation: SwaggerOperation) -> OpenApiOperation {
+    let mut parameters = vec![];
+    let mut request_body = None;
+    let mut form_schema = OpenApiSchemaObject::of_type("object");
+
+    for parameter in operation.parameters {
+        match parameter.in_ {
+            // in: body => requestBody
+            SwaggerParameterCategory::Body => {
+                let schema = convert_parameter_schema(&parameter);
+
+                let content = operation
+                    .consumes
+                    .iter()
+                    .map(|content_type| {
+                        (
+                            content_type.clone(),
+                            OpenApiMediaType {
+                                schema: schema.clone(),
+                            },
+                        )
+                    })
+                    .collect();
+
+                request_body = Some(OpenApiRequestBody {
+                    description: parameter.description,
+                    required: parameter.required,
+                    content,
+                });
+            }
+            // in: formData => properties of the form requestBody
+            SwaggerParameterCategory::FormData => {
+                let schema = convert_parameter_schema(&parameter);
+
+                if parameter.required {
+                    form_schema.required.push(parameter.name.clone());
+                }
+
+                form_schema.properties.insert(
+                    parameter.name,
+                    with_description(schema, parameter.description),
+                );
+            }
+            _ => {
+                parameters.push(OpenApiParameter {
+                    schema: convert_parameter_schema(&parameter),
+                    name: parameter.name,
+                    in_: parameter.in_,
+                    description: parameter.description,
+                    required: parameter.required,
+                });
+            }
+        }
+    }
+
+    if request_body.is_none() && !form_schema.properties.is_empty() {
+        let required = !form_schema.required.is_empty();
+
+        request_body = Some(OpenApiRequestBody {
+            description: "".to_string(),
+            required,
+            content: HashMap::from([(
+                meme::FORM.to_string(),
+                OpenApiMediaType {
+                    schema: form_schema,
+                },
+            )]),
+        });
+    }
+
+    let mut swagger_responses = operation.responses;
+
+    // Responses should have the response of a successful call.
+    if !swagger_responses.keys().any(|e| e.starts_with('2')) {
+        swagger_responses.insert(
+            "200".to_string(),
+            SwaggerResponse {
+                description: "OK".to_string(),
+                schema: None,
+                headers: Default::default(),
+            },
+        );
+    }
+
+    let responses = swagger_responses
+        .into_iter()
+        .map(|(status, response)| {
+            let response = convert_response(&status, response, &operation.produces);
+
+            (status, response)
+        })
+        .collect();
+
+    OpenApiOperation {
+        tags: operation.tags,
+        summary: operation.summary,
+        description: operation.description,
+        operation_id: operation.operation_id,
+        parameters,
+        request_body,
+        responses,
+        security: operation.security,
+    }
+}
+
+fn convert_response(
+    status: &str,
+    response: SwaggerResponse,
+    produces: &[String],
+) -> OpenApiResponse {
+    let headers = response
+        .headers
+        .into_iter()
+        .map(|(name, header)| {
+            let header = OpenApiHeader {
+                description: header.description,
+                schema: OpenApiSchemaObject::of_type(header.type_),
+            };
+
+            (name, header)
+        })
+        .collect();
+
+    let content = match response.schema {
+        Some(reference) => {
+            let schema =
+                OpenApiSchemaObject::of_reference(component_reference(&reference.reference));
+
+            // The default error responses of the framework are RFC 7807 documents.
+            let problem_detail = <crate::response::ProblemDetail as super::macros::ToSwaggerDefinitionNode>::get_definition_name();
+
+            if reference.reference.ends_with(problem_detail.as_str()) {
+                HashMap::from([(meme::PROBLEM_JSON.to_string(), OpenApiMediaType { schema })])
+            } else {
+                produces
+                    .iter()
+                    .map(|content_type| {
+                        (
+                            content_type.clone(),
+                            OpenApiMediaType {
+                                schema: schema.clone(),
+                            },
+                        )
+                    })
+                    .collect()
+            }
+        }
+        // A success response without a schema has content only if it is not JSON. (ex. text/event-stream)
+        None if status.starts_with('2') => produces
+            .iter()
+            .filter(|content_type| content_type.as_str() != meme::JSON)
+            .map(|content_type| {
+                (
+                    content_type.clone(),
+                    OpenApiMediaType {
+                        schema: OpenApiSchemaObject::of_type("string"),
+                    },
+                )
+            })
+            .collect(),
+        None => Default::default(),
+    };
+
+    OpenApiResponse {
+        description: response.description,
+        headers,
+        content,
+    }
+}
+
+fn convert_security_definition(definition: SwaggerSecurityDefinition) -> OpenApiSecurityScheme {
+    match definition {
+        SwaggerSecurityDefinition::APIKey(api_key) => OpenApiSecurityScheme::APIKey(api_key),
+        SwaggerSecurityDefinition::Oauth2(oauth2) => {
+            let flow = OpenApiOauth2Flow {
+                authorization_url: Some(oauth2.authorization_url).filter(|url| !url.is_empty()),
+                token_url: Some(oauth2.token_url).filter(|url| !url.is_empty()),
+                scopes: oauth2.scopes,
+            };
+
+            let mut flows = OpenApiOauth2Flows::default();
+
+            // Swagger 2.0 flow names => OpenAPI 3 flow names
+            match oauth2.flow.as_str() {
+                "password" => flows.password = Some(flow),
+                "application" => flows.client_credentials = Some(flow),
+                "accessCode" => flows.authorization_code = Some(flow),
+                _ => flows.implicit = Some(flow),
+            }
+
+            OpenApiSecurityScheme::Oauth2(Box::new(OpenApiOauth2 {
+                type_: "oauth2".to_string(),
+                flows,
+            }))
+        }
+        SwaggerSecurityDefinition::Basic(_) => OpenApiSecurityScheme::Http(OpenApiHttpAuth {
+            type_: "http".to_string(),
+            scheme: "basic".to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nullable() {
+        struct TestCase {
+            name: String,
+            input: OpenApiSchemaObject,
+            expected: serde_json::Value,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "단일 타입".to_string(),
+                input: OpenApiSchemaObject::of_type("string"),
+                expected: serde_json::json!({ "type": ["string", "null"] }),
+            },
+            TestCase {
+                name: "이미 nullable한 타입".to_string(),
+                input: OpenApiSchemaObject::of_type("string").nullable(),
+                expected: serde_json::json!({ "type": ["string", "null"] }),
+            },
+            TestCase {
+                name: "참조".to_string(),
+                input: OpenApiSchemaObject {
+                    description: Some("address".to_string()),
+                    ..OpenApiSchemaObject::of_reference("#/components/schemas/Address")
+                },
+                expected: serde_json::json!({
+                    "description": "address",
+                    "oneOf": [
+                        { "$ref": "#/components/schemas/Address" },
+                        { "type": "null" },
+                    ],
+                }),
+            },
+        ];
+
+        for tc in test_cases {
+            let got = serde_json::to_value(tc.input.nullable()).unwrap();
+
+            assert_eq!(got, tc.expected, "{} failed", tc.name);
+        }
+    }
+
+    #[test]
+    fn test_component_reference() {
+        assert_eq!(
+            component_reference("#/definitions/rupring::response::ProblemDetail"),
+            "#/components/schemas/rupring.response.ProblemDetail"
+        );
+        assert_eq!(json_type("Option::<i32>"), "integer");
+        assert_eq!(json_type("Option < bool >"), "boolean");
+        assert_eq!(json_type("String"), "string");
+        assert_eq!(json_type("number"), "number");
+        assert_eq!(json_type("Option<Vec<i32>>"), "string");
+        assert_eq!(json_type("uuid::Uuid"), "string");
+    }
+
+    #[test]
+    fn test_convert_security_definition() {
+        struct TestCase {
+            name: String,
+            input: SwaggerSecurityDefinition,
+            expected: serde_json::Value,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "authorization code".to_string(),
+                input: SwaggerSecurityDefinition::Oauth2(super::super::json::SwaggerOauth2 {
+                    type_: "oauth2".to_string(),
+                    flow: "accessCode".to_string(),
+                    authorization_url: "https://example.com/authorize".to_string(),
+                    token_url: "".to_string(),
+                    scopes: HashMap::from([("read".to_string(), "read access".to_string())]),
+                }),
+                expected: serde_json::json!({
+                    "type": "oauth2",
+                    "flows": {
+                        "authorizationCode": {
+                            "authorizationUrl": "https://example.com/authorize",
+                            "scopes": { "read": "read access" },
+                        },
+                    },
+                }),
+            },
+            TestCase {
+                name: "authorization code와 token url".to_string(),
+                input: SwaggerSecurityDefinition::new_oauth2_authorization_code(
+                    "https://example.com/authorize",
+                    "https://example.com/token",
+                    &[("read", "read access")],
+                ),
+                expected: serde_json::json!({
+                    "type": "oauth2",
+                    "flows": {
+                        "authorizationCode": {
+                            "authorizationUrl": "https://example.com/authorize",
+                            "tokenUrl": "https://example.com/token",
+                            "scopes": { "read": "read access" },
+                        },
+                    },
+                }),
+            },
+            TestCase {
+                name: "client credentials".to_string(),
+                input: SwaggerSecurityDefinition::new_oauth2_client_credentials(
+                    "https://example.com/token",
+                    &[],
+                ),
+                expected: serde_json::json!({
+                    "type": "oauth2",
+                    "flows": {
+                        "clientCredentials": {
+                            "tokenUrl": "https://example.com/token",
+                            "scopes": {},
+                        },
+                    },
+                }),
+            },
+            TestCase {
+                name: "basic".to_string(),
+                input: SwaggerSecurityDefinition::new_basic_auth(),
+                expected: serde_json::json!({ "type": "http", "scheme": "basic" }),
+            },
+            TestCase {
+                name: "cookie api key".to_string(),
+                input: SwaggerSecurityDefinition::new_api_key_cookie("SESSION"),
+                expected: serde_json::json!({ "type": "apiKey", "name": "SESSION", "in": "cookie" }),
+            },
+        ];
+
+        for tc in test_cases {
+            let got = serde_json::to_value(convert_security_definition(tc.input)).unwrap();
+
+            assert_eq!(got, tc.expected, "{} failed", tc.name);
+        }
+    }
+}
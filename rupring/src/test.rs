@@ -0,0 +1,415 @@
+/*!
+# About Test Client
+- [TestClient] sends requests through the same request pipeline as the server, without binding a port.
+- Routing, middlewares, error handlers, extractors and response post-processing (ex. compression) work in the same way.
+- The connection level checks (request timeout, URI length) are not applied.
+
+```rust
+use rupring::test::TestClient;
+
+#[derive(Debug, Clone, Copy)]
+#[rupring::Module(controllers=[HomeController{}], modules=[], providers=[], middlewares=[])]
+pub struct RootModule {}
+
+#[derive(Debug, Clone)]
+#[rupring::Controller(prefix=/, routes=[echo])]
+pub struct HomeController {}
+
+#[rupring::Get(path = /echo/:id)]
+pub fn echo(request: rupring::Request) -> rupring::Response {
+    rupring::Response::new().text(request.path_parameters["id"].clone())
+}
+
+# rupring::tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+let client = TestClient::new(RootModule {});
+
+let response = client.get("/echo/1").header("x-request-id", "abc").send().await;
+
+assert_eq!(response.status, 200);
+assert_eq!(response.text(), "1");
+# });
+```
+
+## Provider Override
+- The values given to [TestClientBuilder::provider] replace the providers of the modules for the same type, which are not provided at all.
+- Other providers that depend on them receive the given values. (ex. a mock repository for the real service)
+
+```rust,ignore
+let mut repository = MockIUserRepository::new();
+repository.expect_get_user().returning(|_| Ok(None));
+
+let repository: Arc<dyn IUserRepository> = Arc::new(repository);
+
+let client = TestClient::builder(RootModule {})
+    .provider(repository)
+    .build();
+
+let response = client.get("/users/1").await;
+assert_eq!(response.status, 404);
+```
+
+- With [TestClientBuilder::slice], only the providers of the given modules are provided, and the others should be given as overrides. (ex. `@WebMvcTest` of Spring)
+- The application properties are loaded in the same way as the server, and can be replaced with [TestClientBuilder::application_properties].
+*/
+
+use std::{
+    collections::HashMap,
+    future::{Future, IntoFuture},
+    pin::Pin,
+    sync::Arc,
+};
+
+use http_body_util::BodyExt;
+
+use crate::{
+    application_properties::{load_application_properties_from_all, ApplicationProperties},
+    core::{self, adapter::InMemoryRequest, route::Router},
+    di::DIContext,
+    header,
+    http::{
+        cookie::{self, Cookie},
+        meme,
+    },
+    swagger::context::SwaggerContext,
+    IModule, IProvider,
+};
+
+/// Client that sends requests to the application without a socket.
+#[derive(Clone)]
+pub struct TestClient {
+    application_properties: Arc<ApplicationProperties>,
+    di_context: Arc<DIContext>,
+    router: Arc<Router>,
+}
+
+impl TestClient {
+    /// Create a test client of the root module.
+    pub fn new(root_module: impl IModule + Clone + Send + Sync + 'static) -> Self {
+        Self::builder(root_module).build()
+    }
+
+    /// Create a builder of the test client, to override providers or application properties.
+    pub fn builder<T: IModule + Clone + Send + Sync + 'static>(
+        root_module: T,
+    ) -> TestClientBuilder<T> {
+        TestClientBuilder {
+            root_module,
+            application_properties: None,
+            di_context: DIContext::new(),
+            slice: None,
+        }
+    }
+
+    /// DI context of the application. (to check the providers)
+    pub fn di_context(&self) -> &DIContext {
+        &self.di_context
+    }
+
+    /// Create a request with the given method and path. (the path can include the query string)
+    pub fn request(&self, method: hyper::Method, path: impl ToString) -> TestRequest<'_> {
+        TestRequest {
+            client: self,
+            method,
+            path: path.to_string(),
+            headers: hyper::HeaderMap::new(),
+            cookies: vec![],
+            body: vec![],
+        }
+    }
+
+    /// Create a GET request.
+    pub fn get(&self, path: impl ToString) -> TestRequest<'_> {
+        self.request(hyper::Method::GET, path)
+    }
+
+    /// Create a POST request.
+    pub fn post(&self, path: impl ToString) -> TestRequest<'_> {
+        self.request(hyper::Method::POST, path)
+    }
+
+    /// Create a PUT request.
+    pub fn put(&self, path: impl ToString) -> TestRequest<'_> {
+        self.request(hyper::Method::PUT, path)
+    }
+
+    /// Create a PATCH request.
+    pub fn patch(&self, path: impl ToString) -> TestRequest<'_> {
+        self.request(hyper::Method::PATCH, path)
+    }
+
+    /// Create a DELETE request.
+    pub fn delete(&self, path: impl ToString) -> TestRequest<'_> {
+        self.request(hyper::Method::DELETE, path)
+    }
+
+    /// Create a HEAD request.
+    pub fn head(&self, path: impl ToString) -> TestRequest<'_> {
+        self.request(hyper::Method::HEAD, path)
+    }
+
+    /// Create an OPTIONS request.
+    pub fn options(&self, path: impl ToString) -> TestRequest<'_> {
+        self.request(hyper::Method::OPTIONS, path)
+    }
+}
+
+/// Builder of [TestClient]
+pub struct TestClientBuilder<T: IModule> {
+    root_module: T,
+    application_properties: Option<ApplicationProperties>,
+    di_context: DIContext,
+    slice: Option<Vec<Box<dyn IModule>>>,
+}
+
+impl<T: IModule + Clone + Send + Sync + 'static> TestClientBuilder<T> {
+    /// Replace the providers of the modules for the type with the value. (ex. mock)
+    /// - The value is registered with its own type, so a trait object should be passed as the type that handlers get. (ex. `Arc<dyn IUserRepository>`)
+    pub fn provider<P: 'static>(mut self, value: P) -> Self {
+        self.di_context.override_value(value);
+        self
+    }
+
+    /// Replace the providers of the modules for the type with the qualifier. (see [crate::di])
+    pub fn qualified_provider<P: 'static>(mut self, qualifier: impl ToString, value: P) -> Self {
+        self.di_context.override_qualified_value(qualifier, value);
+        self
+    }
+
+    /// Replace the providers of the modules with the provider, which is provided with its dependencies.
+    pub fn override_provider(mut self, provider: impl IProvider + 'static) -> Self {
+        self.di_context.override_provider(provider);
+        self
+    }
+
+    /// Provide only the providers declared in the given modules. (not in their child modules)
+    /// - The routes are still the ones of the root module.
+    pub fn slice(mut self, modules: Vec<Box<dyn IModule>>) -> Self {
+        self.slice = Some(modules);
+        self
+    }
+
+    /// Replace the application properties.
+    pub fn application_properties(mut self, application_properties: ApplicationProperties) -> Self {
+        self.application_properties = Some(application_properties);
+        self
+    }
+
+    /// Initialize the DI context and the router, in the same way as the server.
+    pub fn build(self) -> TestClient {
+        let application_properties = self
+            .application_properties
+            .unwrap_or_else(load_application_properties_from_all);
+
+        let mut di_context = self.di_context;
+        match self.slice {
+            Some(modules) => di_context.initialize_slice(modules),
+            None => di_context.initialize(Box::new(self.root_module.clone())),
+        }
+
+        let router =
+            Router::from_module(Box::new(self.root_module.clone()), &application_properties);
+
+        if let Some(swagger_context) = di_context.get::<SwaggerContext>() {
+            swagger_context
+                .initialize_from_module_with_properties(self.root_module, &application_properties);
+        }
+
+        TestClient {
+            application_properties: Arc::new(application_properties),
+            di_context: Arc::new(di_context),
+            router: Arc::new(router),
+        }
+    }
+}
+
+/// Request of [TestClient]. It is sent with [TestRequest::send] or `.await`.
+pub struct TestRequest<'a> {
+    client: &'a TestClient,
+    method: hyper::Method,
+    path: String,
+    headers: hyper::HeaderMap,
+    cookies: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl TestRequest<'_> {
+    /// Add a header.
+    /// - Panics if the name or value is not a valid header.
+    pub fn header(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        let name = hyper::header::HeaderName::from_bytes(name.as_ref().as_bytes())
+            .expect("invalid header name");
+        let value =
+            hyper::header::HeaderValue::from_str(value.as_ref()).expect("invalid header value");
+
+        self.headers.append(name, value);
+        self
+    }
+
+    /// Add a cookie. (Cookie header)
+    pub fn cookie(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.cookies.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Add a query parameter. It is encoded and appended to the path.
+    pub fn query(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair(name.as_ref(), value.as_ref())
+            .finish();
+
+        let separator = if self.path.contains('?') { '&' } else { '?' };
+        self.path = format!("{}{}{}", self.path, separator, query);
+        self
+    }
+
+    /// Set the raw body.
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Set the body as text. (Content-Type: text/plain)
+    pub fn text(self, text: impl ToString) -> Self {
+        self.content_type(meme::TEXT).body(text.to_string())
+    }
+
+    /// Set the body as JSON. (Content-Type: application/json)
+    /// - Panics if the value cannot be serialized.
+    pub fn json(self, value: impl serde::Serialize) -> Self {
+        let body = serde_json::to_vec(&value).expect("failed to serialize the json body");
+
+        self.content_type(meme::JSON).body(body)
+    }
+
+    /// Set the body as a form. (Content-Type: application/x-www-form-urlencoded)
+    pub fn form<'b>(self, fields: impl IntoIterator<Item = (&'b str, &'b str)>) -> Self {
+        let body = form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(fields)
+            .finish();
+
+        self.content_type(meme::FORM).body(body)
+    }
+
+    fn content_type(mut self, content_type: &str) -> Self {
+        self.headers.insert(
+            hyper::header::CONTENT_TYPE,
+            hyper::header::HeaderValue::from_str(content_type).expect("invalid content type"),
+        );
+        self
+    }
+
+    /// Send the request, and read the whole response.
+    pub async fn send(self) -> TestResponse {
+        let mut headers = self.headers;
+
+        if !self.cookies.is_empty() {
+            let cookie_header = self
+                .cookies
+                .iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect::<Vec<_>>()
+                .join("; ");
+
+            headers.insert(
+                hyper::header::COOKIE,
+                hyper::header::HeaderValue::from_str(&cookie_header).expect("invalid cookie"),
+            );
+        }
+
+        if !self.body.is_empty() {
+            headers.insert(hyper::header::CONTENT_LENGTH, self.body.len().into());
+        }
+
+        let request = InMemoryRequest {
+            uri: self.path.parse().expect("invalid request path"),
+            method: self.method,
+            headers,
+            body: self.body,
+        };
+
+        let client = self.client;
+        let response = core::handle_in_memory_request(
+            Arc::clone(&client.application_properties),
+            Arc::clone(&client.di_context),
+            Arc::clone(&client.router),
+            request,
+        )
+        .await;
+
+        let (parts, body) = response.into_parts();
+
+        let body = match body.collect().await {
+            Ok(body) => body.to_bytes().to_vec(),
+            Err(infallible) => match infallible {},
+        };
+
+        let mut headers: HashMap<String, Vec<String>> = HashMap::new();
+        for (name, value) in parts.headers.iter() {
+            headers
+                .entry(name.to_string())
+                .or_default()
+                .push(value.to_str().unwrap_or_default().to_string());
+        }
+
+        TestResponse {
+            status: parts.status.as_u16(),
+            headers,
+            body,
+        }
+    }
+}
+
+impl<'a> IntoFuture for TestRequest<'a> {
+    type Output = TestResponse;
+    type IntoFuture = Pin<Box<dyn Future<Output = TestResponse> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+/// Response of [TestClient]
+#[derive(Debug, Clone)]
+pub struct TestResponse {
+    pub status: u16,
+    /// Header values by lowercase name.
+    pub headers: HashMap<String, Vec<String>>,
+    pub body: Vec<u8>,
+}
+
+impl TestResponse {
+    /// Returns the first value of the header.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .and_then(|values| values.first())
+            .map(|value| value.as_str())
+    }
+
+    /// Returns the body as text.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).to_string()
+    }
+
+    /// Deserialize the body as JSON.
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_slice(&self.body)
+    }
+
+    /// Returns the cookies set by the response. (Set-Cookie header)
+    pub fn cookies(&self) -> Vec<Cookie> {
+        self.headers
+            .get(header::SET_COOKIE)
+            .into_iter()
+            .flatten()
+            .filter_map(|value| cookie::parse_set_cookie_header(value))
+            .collect()
+    }
+
+    /// Returns the cookie set by the response with the name.
+    pub fn cookie(&self, name: &str) -> Option<Cookie> {
+        self.cookies()
+            .into_iter()
+            .find(|cookie| cookie.name == name)
+    }
+}
@@ -0,0 +1,65 @@
+mod test_async_handler {
+    use crate::{self as rupring, IRoute};
+
+    #[rupring_macro::Get(path = /sync)]
+    pub fn sync_echo(request: rupring::Request) -> rupring::Response {
+        rupring::Response::new().text(request.path)
+    }
+
+    #[rupring_macro::Get(path = /async)]
+    pub async fn async_echo(request: rupring::Request) -> rupring::Response {
+        tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+
+        rupring::Response::new().text(request.path)
+    }
+
+    #[test]
+    fn test_async_handler() {
+        struct TestCase {
+            name: String,
+            route: Box<dyn IRoute + Send + Sync>,
+            path: String,
+            expected: String,
+        }
+
+        let test_cases = [
+            TestCase {
+                name: "동기 핸들러도 IAsyncHandler로 호출 가능".to_string(),
+                route: Box::new(Route_sync_echo {}),
+                path: "/sync".to_string(),
+                expected: "/sync".to_string(),
+            },
+            TestCase {
+                name: "async 핸들러 호출".to_string(),
+                route: Box::new(Route_async_echo {}),
+                path: "/async".to_string(),
+                expected: "/async".to_string(),
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            let handler = tc.route.handler();
+            let response = runtime.block_on(handler.handle(
+                rupring::Request::for_test().with_path(&tc.path),
+                rupring::Response::new(),
+            ));
+
+            let body = match response.data {
+                rupring::response::ResponseData::Immediate(body) => body,
+                _ => panic!("{} failed: expected immediate body", tc.name),
+            };
+
+            assert_eq!(
+                String::from_utf8(body).unwrap(),
+                tc.expected,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+}
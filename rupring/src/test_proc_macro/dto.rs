@@ -0,0 +1,451 @@
+mod test_dto {
+    use crate::{
+        self as rupring, request::BindFromRequest, swagger::module::SwaggerModule, test::TestClient,
+    };
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum OrderStatus {
+        Pending,
+        InProgress,
+        #[serde(rename = "done")]
+        Completed,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    #[serde(tag = "type", rename_all = "snake_case")]
+    pub enum Payment {
+        Card {
+            #[desc = "card number"]
+            card_number: String,
+        },
+        BankTransfer(BankAccount),
+        Cash,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct BankAccount {
+        pub bank: String,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct Audit {
+        pub created_by: String,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    #[serde(rename_all = "camelCase")]
+    pub struct CreateOrderRequest {
+        pub user_name: String,
+
+        #[serde(rename = "ID")]
+        pub order_id: uuid::Uuid,
+
+        pub ordered_at: chrono::DateTime<chrono::Utc>,
+
+        pub due_date: Option<chrono::NaiveDate>,
+
+        pub status: OrderStatus,
+
+        pub payment: Payment,
+
+        pub quantities: HashMap<String, i32>,
+
+        #[serde(default)]
+        pub note: String,
+
+        #[serde(skip)]
+        pub internal: i32,
+
+        #[serde(flatten)]
+        pub audit: Audit,
+
+        #[path_param = "shop"]
+        pub shop_id: i64,
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct OrderResponse {
+        pub id: i32,
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct Page<T> {
+        pub items: Vec<T>,
+        pub total: i64,
+    }
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    #[serde(default)]
+    pub struct SearchRequest {
+        pub keyword: String,
+        pub limit: i32,
+    }
+
+    impl Default for SearchRequest {
+        fn default() -> Self {
+            SearchRequest {
+                keyword: "".to_string(),
+                limit: 20,
+            }
+        }
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    #[serde(tag = "kind", content = "value")]
+    pub enum Adjacent {
+        Text(String),
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    #[serde(untagged)]
+    pub enum Untagged {
+        Text(String),
+        Number(i64),
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub enum External {
+        Text(String),
+        Point(i32, i32),
+        Empty,
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct Representations {
+        pub adjacent: Adjacent,
+        pub untagged: Untagged,
+        pub external: External,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[OrderController{}],
+        modules=[SwaggerModule{}],
+        providers=[],
+        middlewares=[]
+    )]
+    pub struct RootModule {}
+
+    #[derive(Debug, Clone)]
+    #[rupring_macro::Controller(prefix=/, routes=[create_order, list_orders, representations])]
+    pub struct OrderController {}
+
+    #[rupring_macro::Post(path = /shops/:shop/orders)]
+    #[params = CreateOrderRequest]
+    #[response = OrderResponse]
+    pub fn create_order(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new()
+    }
+
+    #[rupring_macro::Get(path = /orders)]
+    #[response = Page::<OrderResponse>]
+    pub fn list_orders(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new()
+    }
+
+    #[rupring_macro::Post(path = /representations)]
+    #[params = Representations]
+    pub fn representations(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new()
+    }
+
+    fn get_document(path: &str) -> Value {
+        let client = TestClient::new(RootModule {});
+
+        let response = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(client.get(path).send());
+
+        response.json().unwrap()
+    }
+
+    #[test]
+    fn test_bind_with_serde_attributes() {
+        let body = json!({
+            "userName": "foo",
+            "ID": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "orderedAt": "2024-01-02T03:04:05Z",
+            "status": "IN_PROGRESS",
+            "payment": { "type": "card", "card_number": "1234" },
+            "quantities": { "apple": 3 },
+            "internal": 100,
+            "created_by": "admin",
+        });
+
+        let request = rupring::Request::for_test()
+            .with_method(rupring::Method::POST)
+            .with_body(body)
+            .with_path_parameter("shop", "7");
+
+        let bound: CreateOrderRequest = BindFromRequest::bind(request).unwrap();
+
+        assert_eq!(bound.user_name, "foo");
+        assert_eq!(
+            bound.order_id.to_string(),
+            "67e55044-10b1-426f-9247-bb680e5fe0c8"
+        );
+        assert_eq!(bound.ordered_at.to_rfc3339(), "2024-01-02T03:04:05+00:00");
+        assert_eq!(bound.due_date, None);
+        assert_eq!(bound.status, OrderStatus::InProgress);
+        assert_eq!(
+            bound.payment,
+            Payment::Card {
+                card_number: "1234".to_string()
+            }
+        );
+        assert_eq!(bound.quantities, HashMap::from([("apple".to_string(), 3)]));
+        assert_eq!(bound.note, "");
+        assert_eq!(bound.internal, 0);
+        assert_eq!(bound.audit.created_by, "admin");
+        assert_eq!(bound.shop_id, 7);
+    }
+
+    #[test]
+    fn test_bind_with_container_default() {
+        struct TestCase {
+            name: String,
+            body: &'static str,
+            expected: SearchRequest,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "빈 객체".to_string(),
+                body: "{}",
+                expected: SearchRequest {
+                    keyword: "".to_string(),
+                    limit: 20,
+                },
+            },
+            TestCase {
+                name: "일부 필드".to_string(),
+                body: r#"{"keyword": "rust"}"#,
+                expected: SearchRequest {
+                    keyword: "rust".to_string(),
+                    limit: 20,
+                },
+            },
+        ];
+
+        for tc in test_cases {
+            let bound = rupring::Request::for_test()
+                .with_method(rupring::Method::POST)
+                .with_body(tc.body)
+                .bind::<SearchRequest>();
+
+            assert_eq!(bound.unwrap(), tc.expected, "{} failed", tc.name);
+        }
+    }
+
+    #[test]
+    fn test_swagger_definitions() {
+        let document = get_document("/docs/swagger.json");
+
+        let request_name = "rupring::test_proc_macro::dto::test_dto::CreateOrderRequest";
+        let payment_name = "rupring::test_proc_macro::dto::test_dto::Payment";
+
+        let request = &document["definitions"][request_name];
+        assert_eq!(
+            request["required"],
+            json!([
+                "userName",
+                "ID",
+                "orderedAt",
+                "status",
+                "payment",
+                "quantities",
+                "created_by",
+                "shop_id",
+            ])
+        );
+
+        let properties = &request["properties"];
+        assert_eq!(
+            properties
+                .as_object()
+                .unwrap()
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![
+                "ID",
+                "created_by",
+                "dueDate",
+                "note",
+                "orderedAt",
+                "payment",
+                "quantities",
+                "status",
+                "userName",
+            ]
+        );
+        assert_eq!(properties["ID"]["format"], "uuid");
+        assert_eq!(properties["orderedAt"]["format"], "date-time");
+        assert_eq!(properties["dueDate"]["format"], "date");
+        assert_eq!(
+            properties["status"]["enum"],
+            json!(["PENDING", "IN_PROGRESS", "done"])
+        );
+        assert_eq!(
+            properties["quantities"],
+            json!({
+                "type": "object",
+                "additionalProperties": { "type": "number", "description": "", "example": null },
+                "description": "",
+            })
+        );
+        assert_eq!(
+            properties["payment"]["$ref"],
+            format!("#/definitions/{payment_name}")
+        );
+
+        // Swagger 2.0 has no oneOf, so an enum has the properties of all variants.
+        let payment = &document["definitions"][payment_name];
+        assert_eq!(payment["type"], "object");
+        assert!(payment["properties"]["type"].is_object());
+        assert!(payment["properties"]["card_number"].is_object());
+        assert!(payment["properties"]["bank"].is_object());
+
+        assert!(document["definitions"]
+            ["rupring::test_proc_macro::dto::test_dto::Page<OrderResponse>"]
+            .is_object());
+    }
+
+    #[test]
+    fn test_openapi_schemas() {
+        let document = get_document("/docs/openapi.json");
+        let schemas = &document["components"]["schemas"];
+
+        let prefix = "rupring.test_proc_macro.dto.test_dto";
+
+        assert_eq!(
+            schemas[format!("{prefix}.Payment")],
+            json!({
+                "oneOf": [
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["card"] },
+                            "card_number": { "type": "string", "description": "card number" },
+                        },
+                        "required": ["type", "card_number"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["bank_transfer"] },
+                            "bank": { "type": "string" },
+                        },
+                        "required": ["type", "bank"],
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "type": { "type": "string", "enum": ["cash"] },
+                        },
+                        "required": ["type"],
+                    },
+                ]
+            })
+        );
+
+        let request = &schemas[format!("{prefix}.CreateOrderRequest")];
+        assert_eq!(
+            request["properties"]["dueDate"],
+            json!({ "type": ["string", "null"], "format": "date" })
+        );
+        assert_eq!(
+            request["properties"]["quantities"],
+            json!({ "type": "object", "additionalProperties": { "type": "number" } })
+        );
+
+        assert_eq!(
+            schemas[format!("{prefix}.Page_OrderResponse")]["properties"]["items"],
+            json!({
+                "type": "array",
+                "items": { "$ref": format!("#/components/schemas/{prefix}.OrderResponse") },
+            })
+        );
+        assert_eq!(
+            document["paths"]["/orders"]["get"]["responses"]["200"]["content"]["application/json"]
+                ["schema"],
+            json!({ "$ref": format!("#/components/schemas/{prefix}.Page_OrderResponse") })
+        );
+    }
+
+    #[test]
+    fn test_enum_representations() {
+        struct TestCase {
+            name: String,
+            schema: String,
+            expected: Value,
+        }
+
+        let prefix = "rupring.test_proc_macro.dto.test_dto";
+
+        let test_cases = vec![
+            TestCase {
+                name: "adjacently tagged".to_string(),
+                schema: format!("{prefix}.Adjacent"),
+                expected: json!({
+                    "oneOf": [{
+                        "type": "object",
+                        "properties": {
+                            "kind": { "type": "string", "enum": ["Text"] },
+                            "value": { "type": "string" },
+                        },
+                        "required": ["kind", "value"],
+                    }]
+                }),
+            },
+            TestCase {
+                name: "untagged".to_string(),
+                schema: format!("{prefix}.Untagged"),
+                expected: json!({
+                    "oneOf": [{ "type": "string" }, { "type": "number" }]
+                }),
+            },
+            TestCase {
+                name: "externally tagged".to_string(),
+                schema: format!("{prefix}.External"),
+                expected: json!({
+                    "oneOf": [
+                        {
+                            "type": "object",
+                            "properties": { "Text": { "type": "string" } },
+                            "required": ["Text"],
+                        },
+                        {
+                            "type": "object",
+                            "properties": {
+                                "Point": {
+                                    "type": "array",
+                                    "items": { "type": "number" },
+                                    "minItems": 2,
+                                    "maxItems": 2,
+                                },
+                            },
+                            "required": ["Point"],
+                        },
+                        { "type": "string", "enum": ["Empty"] },
+                    ]
+                }),
+            },
+        ];
+
+        let document = get_document("/docs/openapi.json");
+
+        for tc in test_cases {
+            assert_eq!(
+                document["components"]["schemas"][&tc.schema], tc.expected,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+}
@@ -0,0 +1,147 @@
+mod test_error_handler {
+    use crate::{self as rupring, IErrorHandler, IRoute};
+
+    #[derive(Debug)]
+    pub struct UserNotFound {
+        pub id: i32,
+    }
+
+    impl std::fmt::Display for UserNotFound {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "user {} not found", self.id)
+        }
+    }
+
+    impl std::error::Error for UserNotFound {}
+
+    #[rupring_macro::ErrorHandler]
+    pub fn handle_user_not_found(error: &UserNotFound) -> rupring::Response {
+        rupring::Response::new().status(404).text(error.to_string())
+    }
+
+    #[derive(Debug)]
+    pub struct UpstreamHttpError {
+        pub status: u16,
+    }
+
+    impl std::fmt::Display for UpstreamHttpError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "upstream responded with {}", self.status)
+        }
+    }
+
+    impl std::error::Error for UpstreamHttpError {}
+
+    // Only rupring::error::HttpError itself receives every error.
+    #[rupring_macro::ErrorHandler]
+    pub fn handle_upstream_error(error: &UpstreamHttpError) -> rupring::Response {
+        rupring::Response::new().status(502).text(error.to_string())
+    }
+
+    #[rupring_macro::ExceptionHandler(status = 404)]
+    pub fn handle_not_found(error: &rupring::error::HttpError) -> rupring::Response {
+        rupring::Response::new()
+            .status(404)
+            .text(format!("{} not found", error.path))
+    }
+
+    #[rupring_macro::Get(path = /users/:id)]
+    pub fn get_user(#[PathVariable = "id"] id: i32) -> Result<rupring::Response, UserNotFound> {
+        if id == 1 {
+            return Ok(rupring::Response::new().text("user 1"));
+        }
+
+        Err(UserNotFound { id })
+    }
+
+    #[test]
+    fn test_error_handler() {
+        struct TestCase {
+            name: String,
+            error: rupring::error::HttpError,
+            expected: [Option<String>; 3],
+        }
+
+        let error_handlers: [Box<dyn IErrorHandler>; 3] = [
+            Box::new(ErrorHandler_handle_user_not_found {}),
+            Box::new(ErrorHandler_handle_upstream_error {}),
+            Box::new(ErrorHandler_handle_not_found {}),
+        ];
+
+        let test_cases = [
+            TestCase {
+                name: "에러 타입이 일치하는 경우".to_string(),
+                error: rupring::error::HttpError::new(
+                    500,
+                    rupring::Method::GET,
+                    "/users/2",
+                    UserNotFound { id: 2 }.into(),
+                ),
+                expected: [Some("user 2 not found".to_string()), None, None],
+            },
+            TestCase {
+                name: "상태 코드가 일치하는 경우".to_string(),
+                error: rupring::error::HttpError::new(
+                    404,
+                    rupring::Method::GET,
+                    "/asdf",
+                    rupring::error::Errors::NotFound.into(),
+                ),
+                expected: [None, None, Some("/asdf not found".to_string())],
+            },
+            TestCase {
+                name: "이름이 HttpError로 끝나는 에러 타입".to_string(),
+                error: rupring::error::HttpError::new(
+                    500,
+                    rupring::Method::GET,
+                    "/orders",
+                    UpstreamHttpError { status: 503 }.into(),
+                ),
+                expected: [None, Some("upstream responded with 503".to_string()), None],
+            },
+        ];
+
+        for tc in test_cases {
+            for (error_handler, expected) in error_handlers.iter().zip(tc.expected) {
+                let result = error_handler
+                    .handle(&tc.error)
+                    .map(|response| String::from_utf8(response.data.into_bytes()).unwrap());
+
+                assert_eq!(result, expected, "{} failed", tc.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_result_handler() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let handler = Route_get_user {}.handler();
+
+        let response = runtime.block_on(handler.handle(
+            rupring::Request::for_test().with_path_parameter("id", "1"),
+            rupring::Response::new(),
+        ));
+        assert_eq!(response.status, 200);
+        assert!(response.error.is_none());
+
+        let response = runtime.block_on(handler.handle(
+            rupring::Request::for_test().with_path_parameter("id", "2"),
+            rupring::Response::new(),
+        ));
+        assert_eq!(response.status, 500);
+        assert!(response
+            .error
+            .unwrap()
+            .downcast_ref::<UserNotFound>()
+            .is_some());
+
+        let response = runtime.block_on(handler.handle(
+            rupring::Request::for_test().with_path_parameter("id", "a"),
+            rupring::Response::new(),
+        ));
+        assert_eq!(response.status, 400);
+    }
+}
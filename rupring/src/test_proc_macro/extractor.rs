@@ -0,0 +1,246 @@
+mod test_extractor {
+    use crate::{self as rupring, swagger::SwaggerParameterCategory, IRoute};
+    use std::collections::HashMap;
+
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct CreateUserRequest {
+        pub username: String,
+    }
+
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct ListUsersQuery {
+        pub page: Option<i32>,
+        pub size: Option<i32>,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct UserService {
+        prefix: String,
+    }
+
+    #[rupring_macro::Post(path = /users)]
+    pub fn create_user(
+        #[RequestBody] body: CreateUserRequest,
+        #[Query] query: ListUsersQuery,
+        #[Query = "dry_run"] dry_run: Option<bool>,
+        #[Header = "X-Request-Id"] request_id: String,
+        #[Cookie = "sid"] session_id: Option<String>,
+        #[Inject] user_service: &UserService,
+    ) -> rupring::Response {
+        rupring::Response::new().text(format!(
+            "{}{} {:?} {:?} {:?} {} {:?}",
+            user_service.prefix,
+            body.username,
+            query.page,
+            query.size,
+            dry_run,
+            request_id,
+            session_id
+        ))
+    }
+
+    // Body types without RupringDto are bound but not documented.
+    #[rupring_macro::Post(path = /raw)]
+    pub fn create_raw(#[RequestBody] body: Option<HashMap<String, String>>) -> rupring::Response {
+        rupring::Response::new().text(format!("{:?}", body))
+    }
+
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct CreateMemberRequest {
+        #[length(min = 2)]
+        pub name: String,
+
+        #[email]
+        pub email: String,
+    }
+
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct ListMembersQuery {
+        #[range(min = 1, max = 100)]
+        pub size: Option<i32>,
+    }
+
+    // RupringDto extractors are validated, the same as bind().
+    #[rupring_macro::Post(path = /members)]
+    pub fn create_member(
+        #[RequestBody] body: CreateMemberRequest,
+        #[Query] query: ListMembersQuery,
+        #[Query = "limit"] limit: std::option::Option<i32>,
+    ) -> rupring::Response {
+        rupring::Response::new().text(format!(
+            "{} {} {:?} {:?}",
+            body.name, body.email, query.size, limit
+        ))
+    }
+
+    // A POST request with the UserService registered.
+    fn post_request(body: &str) -> rupring::Request {
+        let mut di_context = rupring::DIContext::new();
+        di_context.register(Box::new(UserService {
+            prefix: "user:".to_string(),
+        }));
+
+        rupring::Request::for_test()
+            .with_method(rupring::Method::POST)
+            .with_body(body)
+            .with_di_context(di_context)
+    }
+
+    #[test]
+    fn test_extractor() {
+        struct TestCase {
+            name: String,
+            route: Box<dyn IRoute + Send + Sync>,
+            request: rupring::Request,
+            expected_status: u16,
+            expected_body: String,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "모든 추출기 바인딩".to_string(),
+                route: Box::new(Route_create_user {}),
+                request: post_request(r#"{"username":"foo"}"#)
+                    .with_query("page", "2")
+                    .with_query("dry_run", "true")
+                    .with_header("x-request-id", "abc")
+                    .with_cookie("sid", "s1"),
+                expected_status: 200,
+                expected_body: r#"user:foo Some(2) None Some(true) abc Some("s1")"#.to_string(),
+            },
+            TestCase {
+                name: "Option 값은 없으면 None".to_string(),
+                route: Box::new(Route_create_user {}),
+                request: post_request(r#"{"username":"foo"}"#).with_header("x-request-id", "abc"),
+                expected_status: 200,
+                expected_body: "user:foo None None None abc None".to_string(),
+            },
+            TestCase {
+                name: "필수 헤더 누락".to_string(),
+                route: Box::new(Route_create_user {}),
+                request: post_request(r#"{"username":"foo"}"#),
+                expected_status: 400,
+                expected_body: "Bad Request: missing header: x-request-id".to_string(),
+            },
+            TestCase {
+                name: "잘못된 body".to_string(),
+                route: Box::new(Route_create_user {}),
+                request: post_request("{").with_header("x-request-id", "abc"),
+                expected_status: 400,
+                expected_body: "Bad Request: invalid request body: EOF while parsing an object at line 1 column 1".to_string(),
+            },
+            TestCase {
+                name: "잘못된 query".to_string(),
+                route: Box::new(Route_create_user {}),
+                request: post_request(r#"{"username":"foo"}"#)
+                    .with_query("page", "a")
+                    .with_header("x-request-id", "abc"),
+                expected_status: 400,
+                expected_body: "Bad Request: invalid query: invalid digit found in string".to_string(),
+            },
+            TestCase {
+                name: "검증 통과".to_string(),
+                route: Box::new(Route_create_member {}),
+                request: post_request(r#"{"name":"foo","email":"foo@example.com"}"#)
+                    .with_query("size", "10")
+                    .with_query("limit", "5"),
+                expected_status: 200,
+                expected_body: "foo foo@example.com Some(10) Some(5)".to_string(),
+            },
+            TestCase {
+                name: "form body".to_string(),
+                route: Box::new(Route_create_member {}),
+                request: post_request("name=foo&email=foo%40example.com")
+                    .with_header("content-type", "application/x-www-form-urlencoded"),
+                expected_status: 200,
+                expected_body: "foo foo@example.com None None".to_string(),
+            },
+            TestCase {
+                name: "잘못된 body 검증 실패".to_string(),
+                route: Box::new(Route_create_member {}),
+                request: post_request(r#"{"name":"f","email":"foo"}"#),
+                expected_status: 400,
+                expected_body: "Validation Failed: name: length must be at least 2, email: must be a valid email address".to_string(),
+            },
+            TestCase {
+                name: "잘못된 query 검증 실패".to_string(),
+                route: Box::new(Route_create_member {}),
+                request: post_request(r#"{"name":"foo","email":"foo@example.com"}"#)
+                    .with_query("size", "1000"),
+                expected_status: 400,
+                expected_body: "Validation Failed: size: must be between 1 and 100".to_string(),
+            },
+            TestCase {
+                name: "Option body는 비어 있으면 None".to_string(),
+                route: Box::new(Route_create_raw {}),
+                request: post_request(""),
+                expected_status: 200,
+                expected_body: "None".to_string(),
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            let handler = tc.route.handler();
+            let response = runtime.block_on(handler.handle(tc.request, rupring::Response::new()));
+
+            assert_eq!(response.status, tc.expected_status, "{} failed", tc.name);
+
+            // Errors are rendered later by the error handlers.
+            let body = match (response.error, response.data) {
+                (Some(error), _) => error.to_string().into_bytes(),
+                (None, rupring::response::ResponseData::Immediate(body)) => body,
+                _ => panic!("{} failed: expected immediate body", tc.name),
+            };
+
+            assert_eq!(
+                String::from_utf8(body).unwrap(),
+                tc.expected_body,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_extractor_swagger() {
+        let route = Route_create_user {};
+
+        let parameters = route
+            .swagger()
+            .parameters
+            .into_iter()
+            .map(|parameter| (parameter.name, parameter.in_, parameter.required))
+            .collect::<Vec<_>>();
+
+        // The cookie parameter is left out of the Swagger 2.0 document. (OpenAPI 3.1 only)
+        assert_eq!(
+            parameters,
+            vec![
+                ("page".to_string(), SwaggerParameterCategory::Query, false),
+                ("size".to_string(), SwaggerParameterCategory::Query, false),
+                (
+                    "dry_run".to_string(),
+                    SwaggerParameterCategory::Query,
+                    false
+                ),
+                (
+                    "x-request-id".to_string(),
+                    SwaggerParameterCategory::Header,
+                    true
+                ),
+                ("sid".to_string(), SwaggerParameterCategory::Cookie, false),
+            ]
+        );
+
+        let request_info = route
+            .swagger_request_info()
+            .expect("request body should be documented");
+        assert!(request_info.definition_name.ends_with("CreateUserRequest"));
+
+        assert!(Route_create_raw {}.swagger_request_info().is_none());
+    }
+}
@@ -202,3 +202,239 @@ mod test_1대1_inject_module_내_테스트 {
         );
     }
 }
+
+mod test_primary_qualifier_override {
+    use crate::{self as rupring, DIContext};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Greeting(pub String);
+
+    #[derive(Debug, Clone)]
+    pub struct Greeter {
+        pub greeting: Greeting,
+        pub qualified: Greeting,
+    }
+
+    // The number of times the non-primary provider is provided.
+    static HELLO_PROVIDED: AtomicUsize = AtomicUsize::new(0);
+
+    #[rupring_macro::Injectable]
+    fn inject_hello() -> Greeting {
+        HELLO_PROVIDED.fetch_add(1, Ordering::SeqCst);
+        Greeting("hello".to_string())
+    }
+
+    #[rupring_macro::Injectable(primary = true)]
+    fn inject_hi() -> Greeting {
+        Greeting("hi".to_string())
+    }
+
+    #[rupring_macro::Injectable(qualifier = "hey")]
+    fn inject_hey() -> Greeting {
+        Greeting("hey".to_string())
+    }
+
+    #[rupring_macro::Injectable(primary = true, qualifier = "main")]
+    fn inject_main() -> Greeting {
+        Greeting("main".to_string())
+    }
+
+    #[rupring_macro::Injectable]
+    fn inject_greeter(greeting: Greeting, #[qualifier = "hey"] qualified: Greeting) -> Greeter {
+        Greeter {
+            greeting,
+            qualified,
+        }
+    }
+
+    #[rupring_macro::Injectable]
+    fn inject_mock_greeting(#[qualifier = "hey"] hey: Greeting) -> Greeting {
+        Greeting(format!("mock {}", hey.0))
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[],
+        modules=[],
+        providers=[inject_hello{}, inject_hey{}, inject_greeter{}],
+        middlewares=[]
+    )]
+    pub struct PlainModule {}
+
+    // The greeter is declared before the primary provider, so it should wait for it.
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[],
+        modules=[],
+        providers=[inject_greeter{}, inject_hello{}, inject_hey{}, inject_hi{}],
+        middlewares=[]
+    )]
+    pub struct PrimaryModule {}
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[],
+        modules=[],
+        providers=[inject_hello{}, inject_main{}],
+        middlewares=[]
+    )]
+    pub struct QualifiedPrimaryModule {}
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[],
+        modules=[PlainModule{}],
+        providers=[inject_hey{}],
+        middlewares=[]
+    )]
+    pub struct RootModule {}
+
+    #[test]
+    fn test_primary_qualifier_override() {
+        struct TestCase {
+            name: String,
+            setup: fn(&mut DIContext),
+            expected_greeting: Option<&'static str>,
+            expected_greeter: Option<(&'static str, &'static str)>,
+            expected_qualified: Vec<(&'static str, Option<&'static str>)>,
+            expected_hello_provided: usize,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "먼저 등록된 프로바이더가 기본값".to_string(),
+                setup: |di_context| di_context.initialize(Box::new(PlainModule {})),
+                expected_greeting: Some("hello"),
+                expected_greeter: Some(("hello", "hey")),
+                expected_qualified: vec![("hey", Some("hey")), ("unknown", None)],
+                expected_hello_provided: 1,
+            },
+            TestCase {
+                name: "primary가 우선하고 나머지는 생성하지 않음".to_string(),
+                setup: |di_context| di_context.initialize(Box::new(PrimaryModule {})),
+                expected_greeting: Some("hi"),
+                expected_greeter: Some(("hi", "hey")),
+                expected_qualified: vec![("hey", Some("hey"))],
+                expected_hello_provided: 0,
+            },
+            TestCase {
+                name: "qualifier가 있는 primary".to_string(),
+                setup: |di_context| di_context.initialize(Box::new(QualifiedPrimaryModule {})),
+                expected_greeting: Some("main"),
+                expected_greeter: None,
+                expected_qualified: vec![("main", Some("main"))],
+                expected_hello_provided: 0,
+            },
+            TestCase {
+                name: "override_value는 primary보다 우선".to_string(),
+                setup: |di_context| {
+                    di_context.override_value(Greeting("mock".to_string()));
+                    di_context.initialize(Box::new(PrimaryModule {}));
+                },
+                expected_greeting: Some("mock"),
+                expected_greeter: Some(("mock", "hey")),
+                expected_qualified: vec![("hey", Some("hey"))],
+                expected_hello_provided: 0,
+            },
+            TestCase {
+                name: "override_qualified_value".to_string(),
+                setup: |di_context| {
+                    di_context.override_qualified_value("hey", Greeting("mock hey".to_string()));
+                    di_context.initialize(Box::new(PlainModule {}));
+                },
+                expected_greeting: Some("hello"),
+                expected_greeter: Some(("hello", "mock hey")),
+                expected_qualified: vec![("hey", Some("mock hey"))],
+                expected_hello_provided: 1,
+            },
+            TestCase {
+                name: "override_provider는 의존성을 주입받음".to_string(),
+                setup: |di_context| {
+                    di_context.override_provider(inject_mock_greeting {});
+                    di_context.initialize(Box::new(PlainModule {}));
+                },
+                expected_greeting: Some("mock hey"),
+                expected_greeter: Some(("mock hey", "hey")),
+                expected_qualified: vec![("hey", Some("hey"))],
+                expected_hello_provided: 0,
+            },
+            TestCase {
+                name: "slice는 하위 모듈의 프로바이더를 포함하지 않음".to_string(),
+                setup: |di_context| di_context.initialize_slice(vec![Box::new(RootModule {})]),
+                expected_greeting: None,
+                expected_greeter: None,
+                expected_qualified: vec![("hey", Some("hey"))],
+                expected_hello_provided: 0,
+            },
+            TestCase {
+                name: "slice 밖의 의존성은 override로 주입".to_string(),
+                setup: |di_context| {
+                    di_context.override_value(Greeting("mock".to_string()));
+                    di_context.initialize_slice(vec![Box::new(PlainModule {})]);
+                },
+                expected_greeting: Some("mock"),
+                expected_greeter: Some(("mock", "hey")),
+                expected_qualified: vec![("hey", Some("hey"))],
+                expected_hello_provided: 0,
+            },
+        ];
+
+        for tc in test_cases {
+            HELLO_PROVIDED.store(0, Ordering::SeqCst);
+
+            let mut di_context = rupring::DIContext::new();
+            (tc.setup)(&mut di_context);
+
+            assert_eq!(
+                di_context.get::<Greeting>().map(|e| e.0.as_str()),
+                tc.expected_greeting,
+                "{} failed",
+                tc.name
+            );
+
+            assert_eq!(
+                di_context
+                    .get::<Greeter>()
+                    .map(|e| (e.greeting.0.as_str(), e.qualified.0.as_str())),
+                tc.expected_greeter,
+                "{} failed",
+                tc.name
+            );
+
+            for (qualifier, expected) in tc.expected_qualified {
+                assert_eq!(
+                    di_context
+                        .get_qualified::<Greeting>(qualifier)
+                        .map(|e| e.0.as_str()),
+                    expected,
+                    "{} failed: {}",
+                    tc.name,
+                    qualifier
+                );
+            }
+
+            assert_eq!(
+                HELLO_PROVIDED.load(Ordering::SeqCst),
+                tc.expected_hello_provided,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiple primary providers")]
+    fn test_multiple_primary_providers() {
+        #[derive(Debug, Clone, Copy)]
+        #[rupring_macro::Module(
+            controllers=[],
+            modules=[],
+            providers=[inject_hi{}, inject_hi{}],
+            middlewares=[]
+        )]
+        pub struct DuplicatedModule {}
+
+        rupring::DIContext::new().initialize(Box::new(DuplicatedModule {}));
+    }
+}
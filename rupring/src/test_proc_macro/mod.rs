@@ -1 +1,12 @@
+mod async_handler;
+mod dto;
+mod error_handler;
+mod extractor;
 mod injectable;
+mod multipart;
+mod openapi;
+mod path_pattern;
+mod stream_body;
+mod test_client;
+mod validation;
+mod websocket;
@@ -0,0 +1,129 @@
+mod test_stream_multipart {
+    use crate::{
+        self as rupring,
+        http::{body::BodyStream, multipart::Multipart},
+        IRoute,
+    };
+
+    #[rupring_macro::Post(path = /buffered)]
+    pub fn buffered_upload(request: rupring::Request) -> rupring::Response {
+        rupring::Response::new().text(request.files.len().to_string())
+    }
+
+    #[rupring_macro::Post(path = /upload, stream_multipart = true)]
+    pub async fn streaming_upload(request: rupring::Request) -> rupring::Response {
+        let multipart = match request.multipart() {
+            Ok(multipart) => multipart,
+            Err(error) => return rupring::Response::new().status(400).text(error.to_string()),
+        };
+
+        let form = match multipart.collect().await {
+            Ok(form) => form,
+            Err(error) => return rupring::Response::new().status(400).text(error.to_string()),
+        };
+
+        let file = form.file("file").unwrap();
+        let bytes = file.bytes().await.unwrap();
+
+        rupring::Response::new().text(format!(
+            "{}={}, {}={}",
+            form.fields["title"][0],
+            file.filename,
+            file.name,
+            String::from_utf8(bytes).unwrap()
+        ))
+    }
+
+    #[test]
+    fn test_stream_multipart() {
+        let body = concat!(
+            "--XBOUNDARY\r\n",
+            "Content-Disposition: form-data; name=\"title\"\r\n",
+            "\r\n",
+            "hello\r\n",
+            "--XBOUNDARY\r\n",
+            "Content-Disposition: form-data; name=\"file\"; filename=\"a.txt\"\r\n",
+            "\r\n",
+            "content\r\n",
+            "--XBOUNDARY--\r\n",
+        );
+
+        struct TestCase {
+            name: String,
+            route: Box<dyn IRoute + Send + Sync>,
+            multipart: Option<Multipart>,
+            expected_stream_multipart: bool,
+            expected_status: u16,
+            expected_body: String,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "기본 라우트는 스트리밍하지 않음".to_string(),
+                route: Box::new(Route_buffered_upload {}),
+                multipart: None,
+                expected_stream_multipart: false,
+                expected_status: 200,
+                expected_body: "0".to_string(),
+            },
+            TestCase {
+                name: "스트리밍 멀티파트 수신".to_string(),
+                route: Box::new(Route_streaming_upload {}),
+                multipart: Some(Multipart::new(
+                    BodyStream::from_bytes(body, None),
+                    "XBOUNDARY",
+                    Default::default(),
+                )),
+                expected_stream_multipart: true,
+                expected_status: 200,
+                expected_body: "hello=a.txt, file=content".to_string(),
+            },
+            TestCase {
+                name: "멀티파트 요청이 아님".to_string(),
+                route: Box::new(Route_streaming_upload {}),
+                multipart: None,
+                expected_stream_multipart: true,
+                expected_status: 400,
+                expected_body: "Invalid multipart body: not a streaming multipart request, or the body is already taken".to_string(),
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            assert_eq!(
+                tc.route.stream_multipart(),
+                tc.expected_stream_multipart,
+                "{} failed",
+                tc.name
+            );
+
+            let mut request = rupring::Request::for_test()
+                .with_method(rupring::Method::POST)
+                .with_path("/upload");
+            if let Some(multipart) = tc.multipart {
+                request = request.with_multipart(multipart);
+            }
+
+            let handler = tc.route.handler();
+            let response = runtime.block_on(handler.handle(request, rupring::Response::new()));
+
+            assert_eq!(response.status, tc.expected_status, "{} failed", tc.name);
+
+            let body = match response.data {
+                rupring::response::ResponseData::Immediate(body) => body,
+                _ => panic!("{} failed: expected immediate body", tc.name),
+            };
+
+            assert_eq!(
+                String::from_utf8(body).unwrap(),
+                tc.expected_body,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+}
@@ -0,0 +1,840 @@
+mod test_openapi {
+    use crate::{
+        self as rupring,
+        application_properties::{ApplicationProperties, OpenApiVersion, Swagger},
+        swagger::module::SwaggerModule,
+        test::TestClient,
+    };
+    use serde_json::{json, Value};
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct Address {
+        pub city: String,
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct CreateUserRequest {
+        #[desc = "user name"]
+        #[example = "foobar"]
+        #[length(min = 2)]
+        pub username: String,
+
+        pub nickname: Option<String>,
+
+        pub address: Option<Address>,
+
+        pub tags: Vec<String>,
+
+        #[path_param = "group"]
+        pub group_id: i64,
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct CreateUserResponse {
+        pub id: i32,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[UserController{}],
+        modules=[SwaggerModule{}],
+        providers=[],
+        middlewares=[]
+    )]
+    pub struct RootModule {}
+
+    #[derive(Debug, Clone)]
+    #[rupring_macro::Controller(prefix=/, routes=[create_user])]
+    pub struct UserController {}
+
+    #[rupring_macro::Post(path = /groups/:group/users)]
+    #[tags = [user]]
+    #[summary = "user create"]
+    #[params = CreateUserRequest]
+    #[response = CreateUserResponse]
+    pub fn create_user(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new().text("OK")
+    }
+
+    fn client(openapi_version: OpenApiVersion) -> TestClient {
+        TestClient::builder(RootModule {})
+            .application_properties(ApplicationProperties {
+                swagger: Swagger {
+                    openapi_version,
+                    servers: vec!["https://api.example.com".to_string()],
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .build()
+    }
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn test_openapi_document() {
+        let client = client(OpenApiVersion::V2_0);
+
+        let response = block_on(client.get("/docs/openapi.json").send());
+        assert_eq!(response.status, 200);
+        assert_eq!(response.header("content-type"), Some("application/json"));
+
+        let document: Value = response.json().unwrap();
+
+        assert_eq!(document["openapi"], "3.1.0");
+        assert_eq!(
+            document["servers"],
+            json!([{ "url": "https://api.example.com" }])
+        );
+
+        // The document routes are not documented.
+        let paths = document["paths"].as_object().unwrap();
+        assert_eq!(
+            paths.keys().collect::<Vec<_>>(),
+            vec!["/groups/{group}/users"]
+        );
+
+        let request_name = "rupring.test_proc_macro.openapi.test_openapi.CreateUserRequest";
+        let response_name = "rupring.test_proc_macro.openapi.test_openapi.CreateUserResponse";
+        let address_name = "rupring.test_proc_macro.openapi.test_openapi.Address";
+
+        let operation = &document["paths"]["/groups/{group}/users"]["post"];
+        assert_eq!(operation["summary"], "user create");
+        assert_eq!(operation["tags"], json!(["user"]));
+        assert_eq!(
+            operation["parameters"],
+            json!([{
+                "name": "group_id",
+                "in": "path",
+                "description": "",
+                "required": true,
+                "schema": { "type": "integer" },
+            }])
+        );
+        assert_eq!(
+            operation["requestBody"]["content"]["application/json"]["schema"],
+            json!({ "$ref": format!("#/components/schemas/{request_name}") })
+        );
+        assert_eq!(operation["requestBody"]["required"], true);
+        assert_eq!(
+            operation["responses"]["200"]["content"]["application/json"]["schema"],
+            json!({ "$ref": format!("#/components/schemas/{response_name}") })
+        );
+
+        let schemas = &document["components"]["schemas"];
+        let request_schema = &schemas[request_name];
+        assert_eq!(request_schema["type"], "object");
+        assert_eq!(request_schema["required"], json!(["username", "tags"]));
+        assert_eq!(
+            request_schema["properties"]["username"],
+            json!({
+                "type": "string",
+                "description": "user name",
+                "examples": ["foobar"],
+                "minLength": 2,
+            })
+        );
+        assert_eq!(
+            request_schema["properties"]["nickname"],
+            json!({ "type": ["string", "null"] })
+        );
+        assert_eq!(
+            request_schema["properties"]["address"],
+            json!({
+                "oneOf": [
+                    { "$ref": format!("#/components/schemas/{address_name}") },
+                    { "type": "null" },
+                ]
+            })
+        );
+        assert_eq!(
+            request_schema["properties"]["tags"],
+            json!({ "type": "array", "items": { "type": "string" } })
+        );
+        assert!(schemas[address_name].is_object());
+        assert!(schemas[response_name].is_object());
+
+        assert_eq!(
+            document["components"]["securitySchemes"]["BearerAuth"],
+            json!({ "type": "apiKey", "name": "Authorization", "in": "header" })
+        );
+
+        // Swagger 2.0 is still served.
+        let response = block_on(client.get("/docs/swagger.json").send());
+        let document: Value = response.json().unwrap();
+        assert_eq!(document["swagger"], "2.0");
+    }
+
+    #[test]
+    fn test_swagger_ui_version() {
+        struct TestCase {
+            name: String,
+            openapi_version: OpenApiVersion,
+            expected_url: &'static str,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "2.0 문서".to_string(),
+                openapi_version: OpenApiVersion::V2_0,
+                expected_url: "url: '/docs/swagger.json'",
+            },
+            TestCase {
+                name: "3.1 문서".to_string(),
+                openapi_version: OpenApiVersion::V3_1,
+                expected_url: "url: '/docs/openapi.json'",
+            },
+        ];
+
+        for tc in test_cases {
+            let client = client(tc.openapi_version);
+
+            let response = block_on(client.get("/docs").send());
+
+            assert_eq!(response.status, 200, "{} failed", tc.name);
+            assert!(
+                response.text().contains(tc.expected_url),
+                "{} failed: {}",
+                tc.name,
+                response.text()
+            );
+        }
+    }
+}
+
+mod test_responses {
+    use crate::{
+        self as rupring, response::ProblemDetail, swagger::module::SwaggerModule, test::TestClient,
+    };
+    use serde_json::{json, Value};
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct UserResponse {
+        pub id: i32,
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct ConflictResponse {
+        pub message: String,
+    }
+
+    #[derive(Debug, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct UnauthorizedResponse {
+        pub reason: String,
+    }
+
+    #[derive(Debug)]
+    pub struct UserNotFound {}
+
+    impl std::fmt::Display for UserNotFound {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "user not found")
+        }
+    }
+
+    impl std::error::Error for UserNotFound {}
+
+    #[rupring_macro::ErrorHandler(status = 401)]
+    #[response = UnauthorizedResponse]
+    pub fn handle_unauthorized(_: &rupring::error::HttpError) -> rupring::Response {
+        rupring::Response::new().status(401)
+    }
+
+    #[rupring_macro::ErrorHandler]
+    #[responses(404: ProblemDetail)]
+    pub fn handle_user_not_found(_: &UserNotFound) -> rupring::Response {
+        rupring::Response::new().status(404)
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[EventController{}],
+        modules=[SwaggerModule{}, UserModule{}],
+        providers=[],
+        middlewares=[],
+        error_handlers=[handle_unauthorized]
+    )]
+    pub struct RootModule {}
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[UserController{}],
+        modules=[],
+        providers=[],
+        middlewares=[],
+        error_handlers=[handle_user_not_found]
+    )]
+    pub struct UserModule {}
+
+    #[derive(Debug, Clone)]
+    #[rupring_macro::Controller(prefix=/, routes=[subscribe, ingest])]
+    pub struct EventController {}
+
+    #[derive(Debug, Clone)]
+    #[rupring_macro::Controller(prefix=/, routes=[create_user])]
+    pub struct UserController {}
+
+    #[rupring_macro::Post(path = /users)]
+    #[responses(201: UserResponse, 409: ConflictResponse, 401)]
+    #[response_headers = [Location, X-Request-Id]]
+    pub fn create_user(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new().status(201)
+    }
+
+    #[rupring_macro::Get(path = /events)]
+    #[sse]
+    pub fn subscribe(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new()
+    }
+
+    #[rupring_macro::Post(path = /events, stream_body = true)]
+    pub fn ingest(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new()
+    }
+
+    fn get_document(path: &str) -> Value {
+        let client = TestClient::new(RootModule {});
+
+        let response = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(client.get(path).send());
+
+        response.json().unwrap()
+    }
+
+    #[test]
+    fn test_swagger_responses() {
+        let document = get_document("/docs/swagger.json");
+
+        let user_response = "rupring::test_proc_macro::openapi::test_responses::UserResponse";
+        let conflict_response =
+            "rupring::test_proc_macro::openapi::test_responses::ConflictResponse";
+        let problem_detail = "rupring::response::ProblemDetail";
+
+        let responses = &document["paths"]["/users"]["post"]["responses"];
+        assert_eq!(
+            responses["201"],
+            json!({
+                "description": "Created",
+                "schema": { "$ref": format!("#/definitions/{user_response}") },
+                "headers": {
+                    "Location": { "type": "string", "description": "" },
+                    "X-Request-Id": { "type": "string", "description": "" },
+                },
+            })
+        );
+        assert_eq!(
+            responses["409"],
+            json!({
+                "description": "Conflict",
+                "schema": { "$ref": format!("#/definitions/{conflict_response}") },
+            })
+        );
+        // The response declared in the route takes precedence over the error handler.
+        assert_eq!(
+            responses["401"],
+            json!({ "description": "Unauthorized", "schema": null })
+        );
+        // The error handler of the module.
+        assert_eq!(
+            responses["404"],
+            json!({
+                "description": "Not Found",
+                "schema": { "$ref": format!("#/definitions/{problem_detail}") },
+            })
+        );
+        assert!(document["definitions"][problem_detail].is_object());
+
+        // The error handlers of the child module are not applied to the parent module.
+        let responses = &document["paths"]["/events"]["get"]["responses"];
+        assert_eq!(
+            responses.as_object().unwrap().keys().collect::<Vec<_>>(),
+            vec!["401"]
+        );
+        assert!(document["definitions"]
+            ["rupring::test_proc_macro::openapi::test_responses::UnauthorizedResponse"]
+            .is_object());
+
+        assert_eq!(
+            document["paths"]["/events"]["get"]["produces"],
+            json!(["text/event-stream"])
+        );
+        assert_eq!(
+            document["paths"]["/events"]["post"]["consumes"],
+            json!(["application/octet-stream"])
+        );
+    }
+
+    #[test]
+    fn test_openapi_responses() {
+        let document = get_document("/docs/openapi.json");
+
+        let responses = &document["paths"]["/users"]["post"]["responses"];
+        assert_eq!(
+            responses["201"]["headers"]["Location"],
+            json!({ "description": "", "schema": { "type": "string" } })
+        );
+        assert_eq!(
+            responses["404"]["content"],
+            json!({
+                "application/problem+json": {
+                    "schema": { "$ref": "#/components/schemas/rupring.response.ProblemDetail" },
+                },
+            })
+        );
+        assert_eq!(responses["401"], json!({ "description": "Unauthorized" }));
+
+        let responses = &document["paths"]["/events"]["get"]["responses"];
+        assert_eq!(
+            responses["200"],
+            json!({
+                "description": "OK",
+                "content": { "text/event-stream": { "schema": { "type": "string" } } },
+            })
+        );
+
+        let request_body = &document["paths"]["/events"]["post"];
+        assert!(request_body["requestBody"].is_null());
+    }
+}
+
+mod test_swagger_settings {
+    use crate::{
+        self as rupring,
+        application_properties::{ApplicationProperties, Swagger},
+        swagger::module::SwaggerModule,
+        test::TestClient,
+    };
+    use serde_json::{json, Value};
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[HealthController{}],
+        modules=[SwaggerModule{}],
+        providers=[],
+        middlewares=[]
+    )]
+    pub struct RootModule {}
+
+    #[derive(Debug, Clone)]
+    #[rupring_macro::Controller(prefix=/, routes=[health, internal_metrics])]
+    pub struct HealthController {}
+
+    #[rupring_macro::Get(path = /health)]
+    pub fn health(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new().text("OK")
+    }
+
+    #[rupring_macro::Get(path = /internal/metrics, hidden = true)]
+    pub fn internal_metrics(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new().text("metrics")
+    }
+
+    fn client(swagger: Swagger) -> TestClient {
+        TestClient::builder(RootModule {})
+            .application_properties(ApplicationProperties {
+                swagger,
+                ..Default::default()
+            })
+            .build()
+    }
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn test_swagger_path_and_ui() {
+        struct TestCase {
+            name: String,
+            swagger: Swagger,
+            path: &'static str,
+            expected_status: u16,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "기본 경로의 UI".to_string(),
+                swagger: Swagger::default(),
+                path: "/docs",
+                expected_status: 200,
+            },
+            TestCase {
+                name: "변경된 경로의 UI".to_string(),
+                swagger: Swagger::default().path("/api-docs/"),
+                path: "/api-docs",
+                expected_status: 200,
+            },
+            TestCase {
+                name: "변경된 경로의 문서".to_string(),
+                swagger: Swagger::default().path("api-docs"),
+                path: "/api-docs/swagger.json",
+                expected_status: 200,
+            },
+            TestCase {
+                name: "변경 전 경로는 제공하지 않음".to_string(),
+                swagger: Swagger::default().path("/api-docs"),
+                path: "/docs",
+                expected_status: 404,
+            },
+            TestCase {
+                name: "UI 비활성화".to_string(),
+                swagger: Swagger::default().ui_enabled(false),
+                path: "/docs",
+                expected_status: 404,
+            },
+            TestCase {
+                name: "UI 비활성화 - 번들".to_string(),
+                swagger: Swagger::default().ui_enabled(false),
+                path: "/docs/swagger-ui-bundle.js",
+                expected_status: 404,
+            },
+            TestCase {
+                name: "UI 비활성화 - 2.0 문서는 제공".to_string(),
+                swagger: Swagger::default().ui_enabled(false),
+                path: "/docs/swagger.json",
+                expected_status: 200,
+            },
+            TestCase {
+                name: "UI 비활성화 - 3.1 문서는 제공".to_string(),
+                swagger: Swagger::default().ui_enabled(false),
+                path: "/docs/openapi.json",
+                expected_status: 200,
+            },
+        ];
+
+        for tc in test_cases {
+            let client = client(tc.swagger);
+
+            let response = block_on(client.get(tc.path).send());
+
+            assert_eq!(
+                response.status, tc.expected_status,
+                "{} failed: {}",
+                tc.name, tc.path
+            );
+        }
+    }
+
+    #[test]
+    fn test_swagger_ui_assets_follow_path() {
+        let client = client(Swagger::default().path("/api-docs"));
+
+        let response = block_on(client.get("/api-docs").send());
+        let html = response.text();
+
+        assert!(html.contains("href=\"/api-docs/swagger-ui.css\""), "{html}");
+        assert!(
+            html.contains("src=\"/api-docs/swagger-ui-bundle.js\""),
+            "{html}"
+        );
+        assert!(html.contains("url: '/api-docs/swagger.json'"), "{html}");
+        assert!(!html.contains("/docs/"), "{html}");
+    }
+
+    #[test]
+    fn test_swagger_info_and_servers() {
+        let client = client(
+            Swagger::default()
+                .title("Shop API")
+                .version("1.2.0")
+                .description("shop")
+                .contact("API Team", "https://example.com", "api@example.com")
+                .license("MIT", "https://opensource.org/licenses/MIT")
+                .server("https://api.example.com/v1")
+                .server("http://localhost:3000")
+                .path("/api-docs"),
+        );
+
+        let response = block_on(client.get("/api-docs/swagger.json").send());
+        let document: Value = response.json().unwrap();
+
+        assert_eq!(document["info"]["title"], "Shop API");
+        assert_eq!(document["info"]["version"], "1.2.0");
+        assert_eq!(document["info"]["description"], "shop");
+        assert_eq!(
+            document["info"]["contact"],
+            json!({ "name": "API Team", "url": "https://example.com", "email": "api@example.com" })
+        );
+        assert_eq!(
+            document["info"]["license"],
+            json!({ "name": "MIT", "url": "https://opensource.org/licenses/MIT" })
+        );
+        // The first server is the host of the 2.0 document.
+        assert_eq!(document["host"], "api.example.com");
+        assert_eq!(document["basePath"], "/v1");
+        assert_eq!(document["schemes"], json!(["https"]));
+
+        // The hidden routes and the document routes are not documented.
+        let paths = document["paths"].as_object().unwrap();
+        assert_eq!(paths.keys().collect::<Vec<_>>(), vec!["/health"]);
+
+        let response = block_on(client.get("/api-docs/openapi.json").send());
+        let document: Value = response.json().unwrap();
+
+        assert_eq!(document["info"]["title"], "Shop API");
+        assert_eq!(
+            document["servers"],
+            json!([
+                { "url": "https://api.example.com/v1" },
+                { "url": "http://localhost:3000" },
+            ])
+        );
+        let paths = document["paths"].as_object().unwrap();
+        assert_eq!(paths.keys().collect::<Vec<_>>(), vec!["/health"]);
+
+        // The hidden route is still served.
+        let response = block_on(client.get("/internal/metrics").send());
+        assert_eq!(response.status, 200);
+        assert_eq!(response.text(), "metrics");
+    }
+}
+
+mod test_security {
+    use crate::{
+        self as rupring,
+        application_properties::{ApplicationProperties, Swagger},
+        swagger::{module::SwaggerModule, SwaggerSecurityDefinition},
+        test::TestClient,
+    };
+    use serde_json::{json, Value};
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[OrderController{}, LegacyController{}],
+        modules=[SwaggerModule{}],
+        providers=[],
+        middlewares=[]
+    )]
+    pub struct RootModule {}
+
+    #[derive(Debug, Clone)]
+    #[rupring_macro::Controller(
+        prefix=/orders,
+        routes=[list_orders, create_order, order_health, cancel_order],
+        auth=ApiKeyAuth
+    )]
+    pub struct OrderController {}
+
+    #[derive(Debug, Clone)]
+    #[rupring_macro::Controller(prefix=/, routes=[legacy])]
+    pub struct LegacyController {}
+
+    #[rupring_macro::Get(path = /)]
+    pub fn list_orders(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new()
+    }
+
+    #[rupring_macro::Post(path = /)]
+    #[auth = OAuth2]
+    #[scopes = ["orders:write"]]
+    pub fn create_order(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new()
+    }
+
+    #[rupring_macro::Get(path = /health)]
+    #[auth = none]
+    pub fn order_health(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new()
+    }
+
+    #[rupring_macro::Delete(path = /:id)]
+    #[auth = [BasicAuth, SessionAuth]]
+    pub fn cancel_order(
+        #[PathVariable = "id"] _id: String,
+        #[Cookie = "SESSION"] _session: Option<String>,
+    ) -> rupring::Response {
+        rupring::Response::new()
+    }
+
+    #[rupring_macro::Get(path = /legacy)]
+    #[auth]
+    pub fn legacy(_: rupring::Request) -> rupring::Response {
+        rupring::Response::new()
+    }
+
+    fn get_document(path: &str) -> Value {
+        let swagger = Swagger::default()
+            .security_scheme(
+                "ApiKeyAuth",
+                SwaggerSecurityDefinition::new_api_key_header("X-API-Key"),
+            )
+            .security_scheme(
+                "OAuth2",
+                SwaggerSecurityDefinition::new_oauth2_authorization_code(
+                    "https://auth.example.com/authorize",
+                    "https://auth.example.com/token",
+                    &[("orders:write", "write orders")],
+                ),
+            )
+            .security_scheme("BasicAuth", SwaggerSecurityDefinition::new_basic_auth())
+            .security_scheme(
+                "SessionAuth",
+                SwaggerSecurityDefinition::new_api_key_cookie("SESSION"),
+            );
+
+        let client = TestClient::builder(RootModule {})
+            .application_properties(ApplicationProperties {
+                swagger,
+                ..Default::default()
+            })
+            .build();
+
+        let response = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(client.get(path).send());
+
+        response.json().unwrap()
+    }
+
+    #[test]
+    fn test_swagger_security() {
+        let document = get_document("/docs/swagger.json");
+
+        assert_eq!(
+            document["securityDefinitions"],
+            json!({
+                "BearerAuth": { "type": "apiKey", "name": "Authorization", "in": "header" },
+                "ApiKeyAuth": { "type": "apiKey", "name": "X-API-Key", "in": "header" },
+                "OAuth2": {
+                    "type": "oauth2",
+                    "flow": "accessCode",
+                    "authorizationUrl": "https://auth.example.com/authorize",
+                    "tokenUrl": "https://auth.example.com/token",
+                    "scopes": { "orders:write": "write orders" },
+                },
+                "BasicAuth": { "type": "basic" },
+            })
+        );
+
+        struct TestCase {
+            name: String,
+            path: &'static str,
+            method: &'static str,
+            expected: Value,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "컨트롤러 기본값".to_string(),
+                path: "/orders",
+                method: "get",
+                expected: json!([{ "ApiKeyAuth": [] }]),
+            },
+            TestCase {
+                name: "라우트의 auth와 scopes".to_string(),
+                path: "/orders",
+                method: "post",
+                expected: json!([{ "OAuth2": ["orders:write"] }]),
+            },
+            TestCase {
+                name: "auth = none".to_string(),
+                path: "/orders/health",
+                method: "get",
+                expected: json!([]),
+            },
+            TestCase {
+                name: "cookie API key는 2.0에서 제외".to_string(),
+                path: "/orders/{id}",
+                method: "delete",
+                expected: json!([{ "BasicAuth": [] }]),
+            },
+            TestCase {
+                name: "기본 BearerAuth".to_string(),
+                path: "/legacy",
+                method: "get",
+                expected: json!([{ "BearerAuth": [] }]),
+            },
+        ];
+
+        for tc in test_cases {
+            assert_eq!(
+                document["paths"][tc.path][tc.method]["security"], tc.expected,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_swagger_cookie_parameter() {
+        struct TestCase {
+            name: String,
+            document_path: &'static str,
+            expected: Vec<Value>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "2.0에는 cookie 위치가 없음".to_string(),
+                document_path: "/docs/swagger.json",
+                expected: vec![json!("path")],
+            },
+            TestCase {
+                name: "3.1에는 cookie 파라미터 유지".to_string(),
+                document_path: "/docs/openapi.json",
+                expected: vec![json!("path"), json!("cookie")],
+            },
+        ];
+
+        for tc in test_cases {
+            let document = get_document(tc.document_path);
+
+            let locations = document["paths"]["/orders/{id}"]["delete"]["parameters"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|parameter| parameter["in"].clone())
+                .collect::<Vec<_>>();
+
+            assert_eq!(locations, tc.expected, "{} failed", tc.name);
+        }
+    }
+
+    #[test]
+    fn test_openapi_security() {
+        let document = get_document("/docs/openapi.json");
+
+        let schemes = &document["components"]["securitySchemes"];
+        assert_eq!(
+            schemes["OAuth2"],
+            json!({
+                "type": "oauth2",
+                "flows": {
+                    "authorizationCode": {
+                        "authorizationUrl": "https://auth.example.com/authorize",
+                        "tokenUrl": "https://auth.example.com/token",
+                        "scopes": { "orders:write": "write orders" },
+                    },
+                },
+            })
+        );
+        assert_eq!(
+            schemes["BasicAuth"],
+            json!({ "type": "http", "scheme": "basic" })
+        );
+        assert_eq!(
+            schemes["SessionAuth"],
+            json!({ "type": "apiKey", "name": "SESSION", "in": "cookie" })
+        );
+
+        assert_eq!(
+            document["paths"]["/orders/{id}"]["delete"]["security"],
+            json!([{ "BasicAuth": [] }, { "SessionAuth": [] }])
+        );
+    }
+}
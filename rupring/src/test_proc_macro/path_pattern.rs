@@ -0,0 +1,180 @@
+mod test_path_pattern {
+    use crate::{
+        self as rupring,
+        swagger::{SwaggerParameterCategory, SwaggerTypeOrReference},
+        IRoute,
+    };
+
+    #[rupring_macro::Get(path = "/users/:id(\\d+)")]
+    pub fn get_user(#[PathVariable = "id"] id: i32) -> rupring::Response {
+        rupring::Response::new().text(id.to_string())
+    }
+
+    #[rupring_macro::Get(path = /posts/{page?:int})]
+    pub fn list_posts(#[PathVariable = "page"] page: Option<i32>) -> rupring::Response {
+        rupring::Response::new().text(format!("{:?}", page))
+    }
+
+    #[rupring_macro::Get(path = /static/{*file})]
+    pub fn get_static_file(#[PathVariable = "file"] file: String) -> rupring::Response {
+        rupring::Response::new().text(file)
+    }
+
+    #[test]
+    fn test_path_pattern() {
+        struct TestCase {
+            name: String,
+            route: Box<dyn IRoute + Send + Sync>,
+            path_parameters: Vec<(&'static str, &'static str)>,
+            expected_path: String,
+            expected_status: u16,
+            expected_body: String,
+        }
+
+        let test_cases = [
+            TestCase {
+                name: "문자열 리터럴 경로의 정규식 제약".to_string(),
+                route: Box::new(Route_get_user {}),
+                path_parameters: vec![("id", "42")],
+                expected_path: r"/users/:id(\d+)".to_string(),
+                expected_status: 200,
+                expected_body: "42".to_string(),
+            },
+            TestCase {
+                name: "생략 가능한 세그먼트가 있는 경우".to_string(),
+                route: Box::new(Route_list_posts {}),
+                path_parameters: vec![("page", "2")],
+                expected_path: "/posts/{page?:int}".to_string(),
+                expected_status: 200,
+                expected_body: "Some(2)".to_string(),
+            },
+            TestCase {
+                name: "생략 가능한 세그먼트가 없는 경우".to_string(),
+                route: Box::new(Route_list_posts {}),
+                path_parameters: vec![],
+                expected_path: "/posts/{page?:int}".to_string(),
+                expected_status: 200,
+                expected_body: "None".to_string(),
+            },
+            TestCase {
+                name: "catch-all 세그먼트".to_string(),
+                route: Box::new(Route_get_static_file {}),
+                path_parameters: vec![("file", "js/app.js")],
+                expected_path: "/static/{*file}".to_string(),
+                expected_status: 200,
+                expected_body: "js/app.js".to_string(),
+            },
+            TestCase {
+                name: "필수 파라미터가 없는 경우".to_string(),
+                route: Box::new(Route_get_user {}),
+                path_parameters: vec![],
+                expected_path: r"/users/:id(\d+)".to_string(),
+                expected_status: 400,
+                expected_body: "Invalid parameter: id".to_string(),
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            assert_eq!(tc.route.path(), tc.expected_path, "{} failed", tc.name);
+
+            let request = tc
+                .path_parameters
+                .iter()
+                .fold(rupring::Request::for_test(), |request, (name, value)| {
+                    request.with_path_parameter(name, value)
+                });
+
+            let handler = tc.route.handler();
+            let response = runtime.block_on(handler.handle(request, rupring::Response::new()));
+
+            assert_eq!(response.status, tc.expected_status, "{} failed", tc.name);
+
+            let body = match response.data {
+                rupring::response::ResponseData::Immediate(body) => body,
+                _ => panic!("{} failed: expected immediate body", tc.name),
+            };
+
+            assert_eq!(
+                String::from_utf8(body).unwrap(),
+                tc.expected_body,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_path_pattern_swagger() {
+        struct TestCase {
+            name: String,
+            route: Box<dyn IRoute + Send + Sync>,
+            expected_type: String,
+            expected_required: bool,
+            expected_pattern: Option<String>,
+            expected_format: Option<String>,
+        }
+
+        let test_cases = [
+            TestCase {
+                name: "정규식 제약은 pattern".to_string(),
+                route: Box::new(Route_get_user {}),
+                expected_type: "integer".to_string(),
+                expected_required: true,
+                expected_pattern: Some(r"^(?:\d+)$".to_string()),
+                expected_format: None,
+            },
+            TestCase {
+                name: "타입 제약은 pattern과 format".to_string(),
+                route: Box::new(Route_list_posts {}),
+                expected_type: "integer".to_string(),
+                expected_required: false,
+                expected_pattern: Some("^(?:-?[0-9]+)$".to_string()),
+                expected_format: Some("int64".to_string()),
+            },
+            TestCase {
+                name: "제약이 없는 경우".to_string(),
+                route: Box::new(Route_get_static_file {}),
+                expected_type: "string".to_string(),
+                expected_required: true,
+                expected_pattern: None,
+                expected_format: None,
+            },
+        ];
+
+        for tc in test_cases {
+            let parameter = tc.route.swagger().parameters.remove(0);
+
+            assert_eq!(
+                parameter.in_,
+                SwaggerParameterCategory::Path,
+                "{} failed",
+                tc.name
+            );
+            assert_eq!(
+                parameter.required, tc.expected_required,
+                "{} failed",
+                tc.name
+            );
+
+            let Some(SwaggerTypeOrReference::Type(schema)) = parameter.schema else {
+                panic!("{} failed: expected schema type", tc.name);
+            };
+
+            assert_eq!(schema.type_, tc.expected_type, "{} failed", tc.name);
+            assert_eq!(
+                schema.validation.pattern, tc.expected_pattern,
+                "{} failed",
+                tc.name
+            );
+            assert_eq!(
+                schema.validation.format, tc.expected_format,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+}
@@ -0,0 +1,195 @@
+mod test_stream_body {
+    use crate::{self as rupring, http::body::BodyStream, test::TestClient, IRoute};
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[StreamBodyController{}],
+        modules=[],
+        providers=[],
+        middlewares=[]
+    )]
+    pub struct RootModule {}
+
+    #[derive(Debug, Clone)]
+    #[rupring_macro::Controller(prefix=/, routes=[ingest])]
+    pub struct StreamBodyController {}
+
+    #[rupring_macro::Post(path = /buffered)]
+    pub fn buffered(request: rupring::Request) -> rupring::Response {
+        rupring::Response::new().text(request.raw_body.len().to_string())
+    }
+
+    #[rupring_macro::Post(path = /events, stream_body = true)]
+    pub async fn ingest(request: rupring::Request) -> rupring::Response {
+        let mut body = match request.body_stream() {
+            Ok(body) => body,
+            Err(error) => return rupring::Response::new().status(400).text(error.to_string()),
+        };
+
+        let mut lines = vec![];
+        loop {
+            match body.next_line().await {
+                Ok(Some(line)) => lines.push(String::from_utf8(line.to_vec()).unwrap()),
+                Ok(None) => break,
+                Err(error) => return rupring::Response::new().status(413).text(error.to_string()),
+            }
+        }
+
+        rupring::Response::new().text(lines.join(","))
+    }
+
+    #[test]
+    fn test_stream_body() {
+        struct TestCase {
+            name: String,
+            route: Box<dyn IRoute + Send + Sync>,
+            body: Option<BodyStream>,
+            expected_stream_body: bool,
+            expected_status: u16,
+            expected_body: String,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "기본 라우트는 스트리밍하지 않음".to_string(),
+                route: Box::new(Route_buffered {}),
+                body: None,
+                expected_stream_body: false,
+                expected_status: 200,
+                expected_body: "0".to_string(),
+            },
+            TestCase {
+                name: "스트리밍 본문 수신".to_string(),
+                route: Box::new(Route_ingest {}),
+                body: Some(BodyStream::from_bytes("{\"a\":1}\n{\"a\":2}\n", None)),
+                expected_stream_body: true,
+                expected_status: 200,
+                expected_body: "{\"a\":1},{\"a\":2}".to_string(),
+            },
+            TestCase {
+                name: "본문 크기 제한".to_string(),
+                route: Box::new(Route_ingest {}),
+                body: Some(BodyStream::from_bytes("{\"a\":1}\n{\"a\":2}\n", Some(8))),
+                expected_stream_body: true,
+                expected_status: 413,
+                expected_body: "Payload Too Large".to_string(),
+            },
+            TestCase {
+                name: "스트리밍 본문이 없음".to_string(),
+                route: Box::new(Route_ingest {}),
+                body: None,
+                expected_stream_body: true,
+                expected_status: 400,
+                expected_body: "Failed to read request body: not a streaming body request, or the body is already taken".to_string(),
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            assert_eq!(
+                tc.route.stream_body(),
+                tc.expected_stream_body,
+                "{} failed",
+                tc.name
+            );
+
+            let mut request = rupring::Request::for_test()
+                .with_method(rupring::Method::POST)
+                .with_path("/events");
+            if let Some(body) = tc.body {
+                request = request.with_body_stream(body);
+            }
+
+            let handler = tc.route.handler();
+            let response = runtime.block_on(handler.handle(request, rupring::Response::new()));
+
+            assert_eq!(response.status, tc.expected_status, "{} failed", tc.name);
+
+            let body = match response.data {
+                rupring::response::ResponseData::Immediate(body) => body,
+                _ => panic!("{} failed: expected immediate body", tc.name),
+            };
+
+            assert_eq!(
+                String::from_utf8(body).unwrap(),
+                tc.expected_body,
+                "{} failed",
+                tc.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream_body_content_encoding() {
+        struct TestCase {
+            name: String,
+            decompression_enabled: bool,
+            content_encoding: Option<&'static str>,
+            expected_status: u16,
+            expected_body: String,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "인코딩 없음".to_string(),
+                decompression_enabled: true,
+                content_encoding: None,
+                expected_status: 200,
+                expected_body: "a,b".to_string(),
+            },
+            TestCase {
+                name: "identity".to_string(),
+                decompression_enabled: true,
+                content_encoding: Some("identity"),
+                expected_status: 200,
+                expected_body: "a,b".to_string(),
+            },
+            TestCase {
+                name: "스트리밍 본문은 압축 해제하지 않으므로 거부".to_string(),
+                decompression_enabled: true,
+                content_encoding: Some("gzip"),
+                expected_status: 415,
+                expected_body: "Unsupported Media Type".to_string(),
+            },
+            TestCase {
+                name: "압축 해제를 끄면 그대로 전달".to_string(),
+                decompression_enabled: false,
+                content_encoding: Some("gzip"),
+                expected_status: 200,
+                expected_body: "a,b".to_string(),
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            let mut application_properties =
+                rupring::application_properties::ApplicationProperties::default();
+            application_properties
+                .server
+                .request
+                .body
+                .decompression_enabled = tc.decompression_enabled;
+
+            let client = TestClient::builder(RootModule {})
+                .application_properties(application_properties)
+                .build();
+
+            let mut request = client.post("/events").text("a\nb\n");
+            if let Some(content_encoding) = tc.content_encoding {
+                request = request.header("content-encoding", content_encoding);
+            }
+
+            let response = runtime.block_on(request.send());
+
+            assert_eq!(response.status, tc.expected_status, "{} failed", tc.name);
+            assert_eq!(response.text(), tc.expected_body, "{} failed", tc.name);
+        }
+    }
+}
@@ -0,0 +1,338 @@
+mod test_test_client {
+    use crate::{self as rupring, http::cookie::Cookie, test::TestClient};
+    use std::sync::Arc;
+
+    pub trait IGreetingRepository {
+        fn greeting(&self) -> String;
+    }
+
+    pub struct GreetingRepository {}
+
+    impl IGreetingRepository for GreetingRepository {
+        fn greeting(&self) -> String {
+            "hello".to_string()
+        }
+    }
+
+    pub struct FakeGreetingRepository {}
+
+    impl IGreetingRepository for FakeGreetingRepository {
+        fn greeting(&self) -> String {
+            "fake".to_string()
+        }
+    }
+
+    pub struct GreetingService {
+        repository: Arc<dyn IGreetingRepository>,
+    }
+
+    #[rupring_macro::Injectable]
+    fn inject_greeting_repository() -> Arc<dyn IGreetingRepository> {
+        Arc::new(GreetingRepository {})
+    }
+
+    #[rupring_macro::Injectable]
+    fn inject_greeting_service(repository: Arc<dyn IGreetingRepository>) -> GreetingService {
+        GreetingService { repository }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    #[rupring_macro::Module(
+        controllers=[GreetingController{}],
+        modules=[],
+        providers=[inject_greeting_repository{}, inject_greeting_service{}],
+        middlewares=[]
+    )]
+    pub struct RootModule {}
+
+    #[derive(Debug, Clone)]
+    #[rupring_macro::Controller(prefix=/, routes=[greet, echo_json, login])]
+    pub struct GreetingController {}
+
+    #[rupring_macro::Get(path = /greet/:name)]
+    pub fn greet(request: rupring::Request) -> rupring::Response {
+        let service = request.get_provider::<GreetingService>().unwrap();
+
+        let suffix = request
+            .query_parameters
+            .get("suffix")
+            .and_then(|e| e.first().cloned())
+            .unwrap_or_default();
+
+        rupring::Response::new()
+            .header(
+                "x-request-id",
+                request
+                    .headers
+                    .get("x-request-id")
+                    .cloned()
+                    .unwrap_or_default(),
+            )
+            .text(format!(
+                "{} {}{}",
+                service.repository.greeting(),
+                request.path_parameters["name"],
+                suffix
+            ))
+    }
+
+    #[rupring_macro::Post(path = /echo)]
+    pub fn echo_json(request: rupring::Request) -> rupring::Response {
+        match serde_json::from_str::<serde_json::Value>(&request.body) {
+            Ok(value) => rupring::Response::new().json(value),
+            Err(_) => rupring::Response::new().status(400).text("bad request"),
+        }
+    }
+
+    #[rupring_macro::Post(path = /login)]
+    pub fn login(request: rupring::Request) -> rupring::Response {
+        let session = request.cookies.get("session").cloned().unwrap_or_default();
+
+        rupring::Response::new()
+            .text(session)
+            .add_cookie(Cookie::new("token", "abc").path("/").http_only(true))
+    }
+
+    #[test]
+    fn test_test_client() {
+        struct TestCase {
+            name: String,
+            method: rupring::Method,
+            path: String,
+            headers: Vec<(&'static str, &'static str)>,
+            cookies: Vec<(&'static str, &'static str)>,
+            query: Vec<(&'static str, &'static str)>,
+            json: Option<serde_json::Value>,
+            expected_status: u16,
+            expected_body: String,
+            expected_headers: Vec<(&'static str, &'static str)>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "경로 파라미터와 헤더".to_string(),
+                method: rupring::Method::GET,
+                path: "/greet/world".to_string(),
+                headers: vec![("x-request-id", "1234")],
+                cookies: vec![],
+                query: vec![("suffix", "!?")],
+                json: None,
+                expected_status: 200,
+                expected_body: "hello world!?".to_string(),
+                expected_headers: vec![("x-request-id", "1234")],
+            },
+            TestCase {
+                name: "JSON 본문".to_string(),
+                method: rupring::Method::POST,
+                path: "/echo".to_string(),
+                headers: vec![],
+                cookies: vec![],
+                query: vec![],
+                json: Some(serde_json::json!({"id": 1, "name": "foo"})),
+                expected_status: 200,
+                expected_body: r#"{"id":1,"name":"foo"}"#.to_string(),
+                expected_headers: vec![("content-type", "application/json")],
+            },
+            TestCase {
+                name: "쿠키".to_string(),
+                method: rupring::Method::POST,
+                path: "/login".to_string(),
+                headers: vec![],
+                cookies: vec![("session", "s1")],
+                query: vec![],
+                json: None,
+                expected_status: 200,
+                expected_body: "s1".to_string(),
+                expected_headers: vec![],
+            },
+            TestCase {
+                name: "없는 경로".to_string(),
+                method: rupring::Method::GET,
+                path: "/unknown".to_string(),
+                headers: vec![],
+                cookies: vec![],
+                query: vec![],
+                json: None,
+                expected_status: 404,
+                expected_body: "Not Found".to_string(),
+                expected_headers: vec![],
+            },
+            TestCase {
+                name: "허용되지 않은 메서드".to_string(),
+                method: rupring::Method::DELETE,
+                path: "/echo".to_string(),
+                headers: vec![],
+                cookies: vec![],
+                query: vec![],
+                json: None,
+                expected_status: 405,
+                expected_body: "Method Not Allowed".to_string(),
+                expected_headers: vec![("allow", "POST, OPTIONS")],
+            },
+        ];
+
+        let client = TestClient::builder(RootModule {})
+            .application_properties(Default::default())
+            .build();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            let mut request = client.request(tc.method, &tc.path);
+
+            for (name, value) in tc.headers {
+                request = request.header(name, value);
+            }
+            for (name, value) in tc.cookies {
+                request = request.cookie(name, value);
+            }
+            for (name, value) in tc.query {
+                request = request.query(name, value);
+            }
+            if let Some(json) = tc.json {
+                request = request.json(json);
+            }
+
+            let response = runtime.block_on(request.send());
+
+            assert_eq!(response.status, tc.expected_status, "{} failed", tc.name);
+            assert_eq!(response.text(), tc.expected_body, "{} failed", tc.name);
+
+            for (name, value) in tc.expected_headers {
+                assert_eq!(response.header(name), Some(value), "{} failed", tc.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_test_client_response() {
+        let client = TestClient::builder(RootModule {})
+            .application_properties(Default::default())
+            .build();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        let response = runtime.block_on(
+            client
+                .post("/echo")
+                .json(serde_json::json!({"id": 1}))
+                .send(),
+        );
+        let body: serde_json::Value = response.json().unwrap();
+        assert_eq!(body["id"], 1);
+
+        let response = runtime.block_on(client.post("/login").send());
+        let cookie = response.cookie("token").unwrap();
+        assert_eq!(cookie.value, "abc");
+        assert_eq!(cookie.path.as_deref(), Some("/"));
+        assert_eq!(cookie.http_only, Some(true));
+        assert!(response.cookie("unknown").is_none());
+    }
+
+    #[test]
+    fn test_test_client_provider_override() {
+        struct TestCase {
+            name: String,
+            client: TestClient,
+            expected_body: String,
+        }
+
+        let fake_repository: Arc<dyn IGreetingRepository> = Arc::new(FakeGreetingRepository {});
+
+        let test_cases = vec![
+            TestCase {
+                name: "모듈의 프로바이더".to_string(),
+                client: TestClient::builder(RootModule {})
+                    .application_properties(Default::default())
+                    .build(),
+                expected_body: "hello world".to_string(),
+            },
+            TestCase {
+                name: "의존하는 프로바이더도 대체된 값을 주입받음".to_string(),
+                client: TestClient::builder(RootModule {})
+                    .application_properties(Default::default())
+                    .provider(fake_repository)
+                    .build(),
+                expected_body: "fake world".to_string(),
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            // The request can be awaited without send().
+            let response = runtime.block_on(async { tc.client.get("/greet/world").await });
+
+            assert_eq!(response.status, 200, "{} failed", tc.name);
+            assert_eq!(response.text(), tc.expected_body, "{} failed", tc.name);
+        }
+    }
+
+    #[test]
+    fn test_test_client_static_files() {
+        let location =
+            std::env::temp_dir().join(format!("rupring-static-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&location).unwrap();
+        std::fs::write(location.join("app.js"), "console.log(1);".repeat(100)).unwrap();
+
+        let mut application_properties =
+            rupring::application_properties::ApplicationProperties::default();
+        application_properties.server.static_files.enabled = true;
+        application_properties.server.static_files.location =
+            location.to_string_lossy().to_string();
+        application_properties.server.compression.enabled = true;
+        application_properties.server.compression.min_response_size = 100;
+
+        let client = TestClient::builder(RootModule {})
+            .application_properties(application_properties)
+            .build();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        // The file is streamed with its length.
+        let response = runtime.block_on(client.get("/app.js").send());
+        assert_eq!(response.status, 200);
+        assert_eq!(response.header("content-length"), Some("1500"));
+        assert_eq!(response.header("accept-ranges"), Some("bytes"));
+        assert_eq!(response.text(), "console.log(1);".repeat(100));
+
+        let etag = response.header("etag").unwrap().to_string();
+        assert!(etag.starts_with('"'));
+
+        // HEAD does not read the file.
+        let response = runtime.block_on(client.head("/app.js").send());
+        assert_eq!(response.status, 200);
+        assert_eq!(response.header("content-length"), Some("1500"));
+        assert!(response.body.is_empty());
+
+        // The compressed size is not known in advance.
+        let response = runtime.block_on(
+            client
+                .get("/app.js")
+                .header("accept-encoding", "gzip")
+                .send(),
+        );
+        assert_eq!(response.status, 200);
+        assert_eq!(response.header("content-encoding"), Some("gzip"));
+        assert_eq!(response.header("content-length"), None);
+        assert!(response.body.len() < 1500);
+
+        // The compressed body only matches the file weakly, and ranges of it can't be served.
+        assert_eq!(response.header("etag"), Some(format!("W/{etag}").as_str()));
+        assert_eq!(response.header("accept-ranges"), None);
+
+        std::fs::remove_dir_all(location).unwrap();
+    }
+}
@@ -0,0 +1,136 @@
+mod test_validation {
+    use crate::{
+        self as rupring,
+        error::Errors,
+        swagger::{
+            macros::{SwaggerDefinitionContext, SwaggerDefinitionNode, ToSwaggerDefinitionNode},
+            SwaggerProperty,
+        },
+        validation::FieldError,
+    };
+
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct Address {
+        #[length(min = 1)]
+        pub city: String,
+    }
+
+    #[derive(Debug, Default, serde::Serialize, serde::Deserialize, rupring_macro::RupringDto)]
+    pub struct CreateUserRequest {
+        #[length(min = 2, max = 10)]
+        #[pattern = "^[a-z]+$"]
+        pub username: String,
+
+        #[email]
+        pub email: Option<String>,
+
+        #[range(min = 14, max = 150)]
+        pub age: i32,
+
+        #[validate]
+        pub addresses: Vec<Address>,
+
+        #[path_param = "group"]
+        #[min = 1]
+        pub group_id: i64,
+    }
+
+    fn field_error(field: &str, message: &str) -> FieldError {
+        FieldError {
+            field: field.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validation() {
+        struct TestCase {
+            name: String,
+            body: String,
+            group_id: String,
+            expected: Vec<FieldError>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "검증 통과".to_string(),
+                body: r#"{"username":"foo","email":"foo@example.com","age":20,"addresses":[{"city":"Seoul"}]}"#.to_string(),
+                group_id: "1".to_string(),
+                expected: vec![],
+            },
+            TestCase {
+                name: "None은 검증하지 않음".to_string(),
+                body: r#"{"username":"foo","email":null,"age":20,"addresses":[]}"#.to_string(),
+                group_id: "1".to_string(),
+                expected: vec![],
+            },
+            TestCase {
+                name: "필드별 에러 목록".to_string(),
+                body: r#"{"username":"Foo","email":"foo","age":3,"addresses":[{"city":"Seoul"},{"city":""}]}"#.to_string(),
+                group_id: "0".to_string(),
+                expected: vec![
+                    field_error("username", "must match the pattern ^[a-z]+$"),
+                    field_error("email", "must be a valid email address"),
+                    field_error("age", "must be between 14 and 150"),
+                    field_error("addresses[1].city", "length must be at least 1"),
+                    field_error("group", "must be greater than or equal to 1"),
+                ],
+            },
+        ];
+
+        for tc in test_cases {
+            let request = rupring::Request::for_test()
+                .with_method(rupring::Method::POST)
+                .with_body(&tc.body)
+                .with_path_parameter("group", &tc.group_id);
+
+            let errors = match request.bind::<CreateUserRequest>() {
+                Ok(_) => vec![],
+                Err(error) => match error.downcast::<Errors>() {
+                    Ok(Errors::ValidationFailed(errors)) => errors.errors,
+                    other => panic!("{} failed: unexpected error {:?}", tc.name, other),
+                },
+            };
+
+            assert_eq!(errors, tc.expected, "{} failed", tc.name);
+        }
+    }
+
+    #[test]
+    fn test_validation_swagger() {
+        let mut context = SwaggerDefinitionContext {
+            definitions: Default::default(),
+        };
+
+        let SwaggerDefinitionNode::Object(definition) =
+            CreateUserRequest::to_swagger_definition(&mut context)
+        else {
+            panic!("object expected");
+        };
+
+        let Some(SwaggerProperty::Single(username)) = definition.properties.get("username") else {
+            panic!("username should be a single property");
+        };
+        assert_eq!(username.validation.min_length, Some(2));
+        assert_eq!(username.validation.max_length, Some(10));
+        assert_eq!(username.validation.pattern, Some("^[a-z]+$".to_string()));
+
+        let Some(SwaggerProperty::Single(email)) = definition.properties.get("email") else {
+            panic!("email should be a single property");
+        };
+        assert_eq!(email.validation.format, Some("email".to_string()));
+
+        let Some(SwaggerProperty::Single(age)) = definition.properties.get("age") else {
+            panic!("age should be a single property");
+        };
+        assert_eq!(age.validation.minimum, Some(14.0));
+        assert_eq!(age.validation.maximum, Some(150.0));
+
+        let group_id = &definition.path_parameters[0];
+        let Some(rupring::swagger::SwaggerTypeOrReference::Type(group_id_type)) = &group_id.schema
+        else {
+            panic!("group_id should have a type");
+        };
+        assert_eq!(group_id_type.validation.minimum, Some(1.0));
+    }
+}
@@ -0,0 +1,118 @@
+mod test_websocket {
+    use crate::{
+        self as rupring,
+        http::websocket::{Message, WebSocket},
+        IRoute,
+    };
+    use futures_util::{SinkExt, StreamExt};
+    use std::sync::{atomic::AtomicBool, Arc};
+    use tokio_tungstenite::{
+        tungstenite::{self, protocol::Role},
+        WebSocketStream,
+    };
+
+    #[rupring_macro::WebSocket(path = /ws)]
+    pub async fn echo(request: rupring::Request, mut socket: WebSocket) {
+        while let Some(Ok(Message::Text(text))) = socket.recv().await {
+            let _ = socket
+                .send_text(format!("{}: {}", request.path, text))
+                .await;
+        }
+    }
+
+    #[test]
+    fn test_websocket_route() {
+        struct TestCase {
+            name: String,
+            headers: Vec<(&'static str, &'static str)>,
+            expected_status: u16,
+            expected_accept: Option<String>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "핸드셰이크 성공".to_string(),
+                headers: vec![
+                    ("upgrade", "websocket"),
+                    ("connection", "Upgrade"),
+                    ("sec-websocket-version", "13"),
+                    ("sec-websocket-key", "dGhlIHNhbXBsZSBub25jZQ=="),
+                ],
+                expected_status: 101,
+                expected_accept: Some("s3pPLMBiTxaQ9kYGzzhZRbK+xOo=".to_string()),
+            },
+            TestCase {
+                name: "일반 GET 요청은 400".to_string(),
+                headers: vec![],
+                expected_status: 400,
+                expected_accept: None,
+            },
+        ];
+
+        let route = Route_echo {};
+        assert_eq!(route.method(), rupring::Method::GET);
+        assert_eq!(route.path(), "/ws");
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        for tc in test_cases {
+            let request = tc.headers.iter().fold(
+                rupring::Request::for_test().with_path("/ws"),
+                |request, (name, value)| request.with_header(name, value),
+            );
+
+            let response =
+                runtime.block_on(route.handler().handle(request, rupring::Response::new()));
+
+            assert_eq!(response.status, tc.expected_status, "{} failed", tc.name);
+
+            let accept = response
+                .headers
+                .get(&rupring::HeaderName::from_static(
+                    rupring::header::SEC_WEBSOCKET_ACCEPT,
+                ))
+                .map(|values| values[0].clone());
+            assert_eq!(accept, tc.expected_accept, "{} failed", tc.name);
+
+            let rupring::response::ResponseData::WebSocket(websocket_response) = response.data
+            else {
+                assert!(response.error.is_some(), "{} failed", tc.name);
+                continue;
+            };
+
+            // The handler receives the request and the upgraded connection.
+            runtime.block_on(async {
+                let (server_io, client_io) = tokio::io::duplex(1024);
+
+                let socket = WebSocket::new(
+                    server_io,
+                    Arc::new(AtomicBool::new(false)),
+                    Arc::new(AtomicBool::new(true)),
+                )
+                .await;
+                let session = tokio::spawn((websocket_response.handler)(socket));
+
+                let mut client =
+                    WebSocketStream::from_raw_socket(client_io, Role::Client, None).await;
+
+                client
+                    .send(tungstenite::Message::text("hello"))
+                    .await
+                    .unwrap();
+                let echo = client.next().await.unwrap().unwrap();
+                assert_eq!(
+                    echo,
+                    tungstenite::Message::text("/ws: hello"),
+                    "{} failed",
+                    tc.name
+                );
+
+                client.close(None).await.unwrap();
+                session.await.unwrap();
+            });
+        }
+    }
+}
@@ -0,0 +1,456 @@
+/*!
+# About Validation
+- The validation attributes of RupringDto are checked when the request is bound with [crate::request::Request::bind].
+- If validation fails, the error is [crate::error::Errors::ValidationFailed], and the default response is `400 Bad Request` with the list of field errors.
+
+```rust
+use rupring::RupringDto;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize, RupringDto)]
+pub struct Address {
+    #[length(min = 1)]
+    pub city: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, RupringDto)]
+pub struct CreateUserRequest {
+    #[length(min = 2, max = 20)]
+    #[pattern = "^[a-z0-9_]+$"]
+    pub username: String,
+
+    #[email]
+    pub email: String,
+
+    #[range(min = 14, max = 150)]
+    pub age: i32,
+
+    #[validate]
+    pub address: Address,
+}
+
+#[rupring::Post(path = /users)]
+pub fn create_user(request: rupring::Request) -> rupring::anyhow::Result<rupring::Response> {
+    let body = request.bind::<CreateUserRequest>()?;
+
+    Ok(rupring::Response::new().text(body.username))
+}
+```
+
+The default response looks like this.
+```json
+{
+    "errors": [
+        { "field": "username", "message": "length must be between 2 and 20" },
+        { "field": "address.city", "message": "length must be at least 1" }
+    ]
+}
+```
+
+## Attributes
+1. `#[min = 0]`, `#[max = 100]`: Minimum and maximum value of a number.
+2. `#[range(min = 0, max = 100)]`: Both at once. (Either can be omitted)
+3. `#[length(min = 1, max = 20)]`: Length of a string (in characters) or the number of items of a Vec.
+4. `#[pattern = "^[a-z]+$"]`: Regular expression that the string should match.
+5. `#[email]`: The string should be an email address.
+6. `#[validate]`: Validate the nested RupringDto. (Also works for `Option<T>` and `Vec<T>`)
+
+- `None` values of `Option` fields are not validated.
+- The constraints are also written to the swagger document. (minimum, maximum, minLength, maxLength, pattern, format)
+*/
+
+use std::sync::OnceLock;
+
+/// Validation error of a field
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct FieldError {
+    /// Path of the field. (ex. `address.city`, `items[0].name`)
+    pub field: String,
+    pub message: String,
+}
+
+/// Validation errors of a request
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ValidationErrors {
+    pub errors: Vec<FieldError>,
+}
+
+impl std::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let errors = self
+            .errors
+            .iter()
+            .map(|error| format!("{}: {}", error.field, error.message))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(f, "{}", errors)
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// Implemented by RupringDto.
+pub trait Validate {
+    /// Push the errors of the value to `errors`. `path` is the path of the value itself. (empty for the root)
+    fn validate_fields(&self, path: &str, errors: &mut Vec<FieldError>);
+
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = vec![];
+        self.validate_fields("", &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors { errors })
+        }
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_fields(&self, path: &str, errors: &mut Vec<FieldError>) {
+        if let Some(value) = self {
+            value.validate_fields(path, errors);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_fields(&self, path: &str, errors: &mut Vec<FieldError>) {
+        for (index, value) in self.iter().enumerate() {
+            value.validate_fields(&format!("{path}[{index}]"), errors);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Box<T> {
+    fn validate_fields(&self, path: &str, errors: &mut Vec<FieldError>) {
+        self.as_ref().validate_fields(path, errors);
+    }
+}
+
+// The route macro calls `(&&ValidateOf(&value)).validate_if_implemented()` for the extractor parameters,
+// which resolves to ValidateImplemented if T implements Validate, and to ValidateNotImplemented otherwise.
+pub struct ValidateOf<'a, T>(pub &'a T);
+
+pub trait ValidateImplemented {
+    fn validate_if_implemented(&self) -> Result<(), ValidationErrors>;
+}
+
+impl<T: Validate> ValidateImplemented for &ValidateOf<'_, T> {
+    fn validate_if_implemented(&self) -> Result<(), ValidationErrors> {
+        self.0.validate()
+    }
+}
+
+pub trait ValidateNotImplemented {
+    fn validate_if_implemented(&self) -> Result<(), ValidationErrors> {
+        Ok(())
+    }
+}
+
+impl<T> ValidateNotImplemented for ValidateOf<'_, T> {}
+
+// address + city -> address.city
+pub fn field_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{path}.{name}")
+    }
+}
+
+/// Values that can be validated with `min`, `max` and `range`.
+pub trait AsNumber {
+    fn as_number(&self) -> Option<f64>;
+}
+
+macro_rules! impl_as_number {
+    ($($type:ty),*) => {
+        $(
+            impl AsNumber for $type {
+                fn as_number(&self) -> Option<f64> {
+                    Some(*self as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_as_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<T: AsNumber> AsNumber for Option<T> {
+    fn as_number(&self) -> Option<f64> {
+        self.as_ref().and_then(|value| value.as_number())
+    }
+}
+
+/// Values that can be validated with `length`.
+pub trait HasLength {
+    fn length(&self) -> Option<usize>;
+}
+
+impl HasLength for String {
+    fn length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl HasLength for &str {
+    fn length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl<T> HasLength for Vec<T> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T: HasLength> HasLength for Option<T> {
+    fn length(&self) -> Option<usize> {
+        self.as_ref().and_then(|value| value.length())
+    }
+}
+
+/// Values that can be validated with `pattern` and `email`.
+pub trait AsText {
+    fn as_text(&self) -> Option<&str>;
+}
+
+impl AsText for String {
+    fn as_text(&self) -> Option<&str> {
+        Some(self.as_str())
+    }
+}
+
+impl AsText for &str {
+    fn as_text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl<T: AsText> AsText for Option<T> {
+    fn as_text(&self) -> Option<&str> {
+        self.as_ref().and_then(|value| value.as_text())
+    }
+}
+
+// The generated code keeps the compiled regex in a static, so it is compiled only once.
+pub struct Pattern {
+    pattern: &'static str,
+    regex: OnceLock<regex::Regex>,
+}
+
+impl Pattern {
+    pub const fn new(pattern: &'static str) -> Self {
+        Pattern {
+            pattern,
+            regex: OnceLock::new(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.pattern
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        // The pattern is checked at compile time by RupringDto.
+        self.regex
+            .get_or_init(|| regex::Regex::new(self.pattern).expect("invalid pattern"))
+            .is_match(text)
+    }
+}
+
+pub fn min(value: &impl AsNumber, min: f64) -> Result<(), String> {
+    match value.as_number() {
+        Some(value) if value < min => Err(format!(
+            "must be greater than or equal to {}",
+            format_number(min)
+        )),
+        _ => Ok(()),
+    }
+}
+
+pub fn max(value: &impl AsNumber, max: f64) -> Result<(), String> {
+    match value.as_number() {
+        Some(value) if value > max => Err(format!(
+            "must be less than or equal to {}",
+            format_number(max)
+        )),
+        _ => Ok(()),
+    }
+}
+
+pub fn range(value: &impl AsNumber, min: Option<f64>, max: Option<f64>) -> Result<(), String> {
+    let Some(number) = value.as_number() else {
+        return Ok(());
+    };
+
+    match (min, max) {
+        (Some(min), Some(max)) if number < min || number > max => Err(format!(
+            "must be between {} and {}",
+            format_number(min),
+            format_number(max)
+        )),
+        (Some(min), None) => self::min(value, min),
+        (None, Some(max)) => self::max(value, max),
+        _ => Ok(()),
+    }
+}
+
+pub fn length(
+    value: &impl HasLength,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Result<(), String> {
+    let Some(length) = value.length() else {
+        return Ok(());
+    };
+
+    match (min, max) {
+        (Some(min), Some(max)) if length < min || length > max => {
+            Err(format!("length must be between {min} and {max}"))
+        }
+        (Some(min), None) if length < min => Err(format!("length must be at least {min}")),
+        (None, Some(max)) if length > max => Err(format!("length must be at most {max}")),
+        _ => Ok(()),
+    }
+}
+
+pub fn pattern(value: &impl AsText, pattern: &Pattern) -> Result<(), String> {
+    match value.as_text() {
+        Some(text) if !pattern.is_match(text) => {
+            Err(format!("must match the pattern {}", pattern.as_str()))
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn email(value: &impl AsText) -> Result<(), String> {
+    match value.as_text() {
+        Some(text) if !is_email(text) => Err("must be a valid email address".to_string()),
+        _ => Ok(()),
+    }
+}
+
+// local@domain.tld (not the full RFC 5322 grammar)
+fn is_email(text: &str) -> bool {
+    let Some((local, domain)) = text.split_once('@') else {
+        return false;
+    };
+
+    if local.is_empty() || domain.contains('@') || text.chars().any(char::is_whitespace) {
+        return false;
+    }
+
+    let labels = domain.split('.').collect::<Vec<_>>();
+
+    labels.len() >= 2
+        && labels
+            .iter()
+            .all(|label| !label.is_empty() && !label.starts_with('-') && !label.ends_with('-'))
+}
+
+// 10.0 -> 10, 0.5 -> 0.5
+fn format_number(number: f64) -> String {
+    if number.fract() == 0.0 && number.abs() < 1e15 {
+        format!("{}", number as i64)
+    } else {
+        number.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validators() {
+        struct TestCase {
+            name: String,
+            result: Result<(), String>,
+            expected: Result<(), String>,
+        }
+
+        static USERNAME: Pattern = Pattern::new("^[a-z]+$");
+
+        let test_cases = vec![
+            TestCase {
+                name: "min 통과".to_string(),
+                result: min(&10, 10.0),
+                expected: Ok(()),
+            },
+            TestCase {
+                name: "min 실패".to_string(),
+                result: min(&9, 10.0),
+                expected: Err("must be greater than or equal to 10".to_string()),
+            },
+            TestCase {
+                name: "max 실패".to_string(),
+                result: max(&1.5, 1.0),
+                expected: Err("must be less than or equal to 1".to_string()),
+            },
+            TestCase {
+                name: "None은 검증하지 않음".to_string(),
+                result: min(&None::<i32>, 10.0),
+                expected: Ok(()),
+            },
+            TestCase {
+                name: "range 실패".to_string(),
+                result: range(&Some(0), Some(1.0), Some(10.0)),
+                expected: Err("must be between 1 and 10".to_string()),
+            },
+            TestCase {
+                name: "range 최소값만".to_string(),
+                result: range(&0, Some(0.5), None),
+                expected: Err("must be greater than or equal to 0.5".to_string()),
+            },
+            TestCase {
+                name: "length는 글자 수 기준".to_string(),
+                result: length(&"가나다".to_string(), Some(1), Some(3)),
+                expected: Ok(()),
+            },
+            TestCase {
+                name: "length 최소값 실패".to_string(),
+                result: length(&Vec::<i32>::new(), Some(1), None),
+                expected: Err("length must be at least 1".to_string()),
+            },
+            TestCase {
+                name: "length 범위 실패".to_string(),
+                result: length(&"abcd".to_string(), Some(1), Some(3)),
+                expected: Err("length must be between 1 and 3".to_string()),
+            },
+            TestCase {
+                name: "pattern 통과".to_string(),
+                result: pattern(&"abc".to_string(), &USERNAME),
+                expected: Ok(()),
+            },
+            TestCase {
+                name: "pattern 실패".to_string(),
+                result: pattern(&"ABC".to_string(), &USERNAME),
+                expected: Err("must match the pattern ^[a-z]+$".to_string()),
+            },
+            TestCase {
+                name: "email 통과".to_string(),
+                result: email(&"foo.bar@example.com".to_string()),
+                expected: Ok(()),
+            },
+            TestCase {
+                name: "email 실패 - 도메인 없음".to_string(),
+                result: email(&"foo@localhost".to_string()),
+                expected: Err("must be a valid email address".to_string()),
+            },
+            TestCase {
+                name: "email 실패 - @ 없음".to_string(),
+                result: email(&Some("foo.example.com".to_string())),
+                expected: Err("must be a valid email address".to_string()),
+            },
+        ];
+
+        for tc in test_cases {
+            assert_eq!(tc.result, tc.expected, "{} failed", tc.name);
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use rupring::http::cookie::Cookie;
+use rupring::http::multipart::MultipartPart;
+use rupring::http::websocket::{Message, WebSocket};
 
 #[derive(Debug, Clone)]
-#[rupring::Controller(prefix=/, routes=[index, slow, download, multipart, multipart_page], tags=["root"])]
+#[rupring::Controller(prefix=/, routes=[index, slow, download, multipart, multipart_stream, multipart_page, ingest_events, echo_websocket], tags=["root"])]
 pub struct RootController {}
 
 #[rupring::Get(path = /)]
@@ -50,6 +52,38 @@ pub fn multipart(mut request: rupring::Request) -> rupring::Response {
     rupring::Response::new().text("Hello, World!")
 }
 
+#[rupring::Post(path = /multipart-upload-stream, stream_multipart = true)]
+#[summary = "대용량 파일 업로드 API입니다."]
+#[description = "파일을 메모리에 모두 올리지 않고 스트리밍으로 수신합니다."]
+#[tags = [root]]
+pub async fn multipart_stream(request: rupring::Request) -> rupring::Response {
+    let mut multipart = match request.multipart() {
+        Ok(multipart) => multipart,
+        Err(error) => return rupring::Response::new().error(error),
+    };
+
+    let mut uploaded = vec![];
+
+    loop {
+        match multipart.next_part().await {
+            Ok(Some(MultipartPart::Field(field))) => println!("{} = {}", field.name, field.value),
+            Ok(Some(MultipartPart::File(file))) => {
+                uploaded.push(format!("{} ({} bytes)", file.filename, file.size));
+
+                let path = format!("{}.foo", file.name);
+
+                if let Err(error) = file.persist(path).await {
+                    return rupring::Response::new().status(500).text(error.to_string());
+                }
+            }
+            Ok(None) => break,
+            Err(error) => return rupring::Response::new().error(error),
+        }
+    }
+
+    rupring::Response::new().text(uploaded.join("\n"))
+}
+
 #[rupring::Get(path = /multipart-upload-page)]
 #[summary = "단순 파일 업로드 API입니다."]
 #[description = "별다른 기능은 없습니다."]
@@ -69,3 +103,44 @@ pub fn multipart_page(request: rupring::Request) -> rupring::Response {
         "#,
     )
 }
+
+#[rupring::Post(path = /events, stream_body = true)]
+#[summary = "NDJSON 이벤트 수집 API입니다."]
+#[description = "본문을 메모리에 모두 올리지 않고 한 줄씩 읽습니다."]
+#[tags = [root]]
+pub async fn ingest_events(request: rupring::Request) -> rupring::Response {
+    let mut body = match request.body_stream() {
+        Ok(body) => body,
+        Err(error) => return rupring::Response::new().error(error),
+    };
+
+    let mut count = 0;
+
+    loop {
+        match body.next_line().await {
+            Ok(Some(line)) => {
+                println!("event: {}", String::from_utf8_lossy(&line));
+                count += 1;
+            }
+            Ok(None) => break,
+            Err(error) => return rupring::Response::new().error(error),
+        }
+    }
+
+    rupring::Response::new().text(format!("{count} events"))
+}
+
+#[rupring::WebSocket(path = /ws/echo)]
+pub async fn echo_websocket(mut socket: WebSocket) {
+    while let Some(Ok(message)) = socket.recv().await {
+        let result = match message {
+            Message::Text(text) => socket.send_text(text).await,
+            Message::Binary(bytes) => socket.send_binary(bytes).await,
+            _ => Ok(()),
+        };
+
+        if result.is_err() {
+            break;
+        }
+    }
+}
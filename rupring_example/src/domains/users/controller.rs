@@ -44,6 +44,7 @@ pub fn get_user(
 #[tags = [user]]
 #[summary = "user 생성"]
 #[params = crate::domains::users::dto::CreateUserRequest]
+#[responses(200, 400)]
 #[auth]
 pub fn create_user(request: rupring::Request, _: rupring::Response) -> rupring::Response {
     let user_service = request.get_provider::<Arc<dyn IUserService>>().cloned().unwrap();
@@ -209,6 +210,7 @@ const SERVE_SSE_HTML: &str = r#"
 #[rupring::Get(path = /sse-page)]
 #[tags = [user]]
 #[summary = "SSE 페이지"]
+#[produces = "text/html"]
 pub fn serve_sse_page(request: rupring::Request) -> rupring::Response {
     rupring::Response::new()
         .html(SERVE_SSE_HTML).header("Content-Type", "text/html")
@@ -217,6 +219,7 @@ pub fn serve_sse_page(request: rupring::Request) -> rupring::Response {
 #[rupring::Get(path = /sse)]
 #[tags = [user]]
 #[summary = "SSE 페이지"]
+#[sse]
 pub fn serve_sse(request: rupring::Request) -> rupring::Response {
     rupring::Response::new()
         .sse_stream(async move |stream_handler|  {
@@ -240,4 +243,96 @@ pub fn serve_sse(request: rupring::Request) -> rupring::Response {
         })
 }
 
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
 
+    use rupring::test::TestClient;
+
+    use crate::domains::users::{
+        dto::GetUserResponse,
+        interface::{IUserRepository, MockIUserRepository},
+        model::User,
+        module::UserModule,
+    };
+
+    #[test]
+    fn test_get_user() {
+        struct TestCase {
+            name: String,
+            path: String,
+            expected_status: u16,
+            expected_username: Option<String>,
+            mock_user_repository: fn() -> Arc<dyn IUserRepository>,
+        }
+
+        let test_cases = vec![
+            TestCase {
+                name: "get user success".to_string(),
+                path: "/users/1".to_string(),
+                expected_status: 200,
+                expected_username: Some("username".to_string()),
+                mock_user_repository: || {
+                    let mut repository = MockIUserRepository::new();
+
+                    repository
+                        .expect_get_user()
+                        .times(1)
+                        .returning(|params| {
+                            Ok(Some(User {
+                                id: params.id,
+                                name: "username".to_string(),
+                                email: "email".to_string(),
+                                password: "password".to_string(),
+                            }))
+                        });
+
+                    Arc::new(repository)
+                },
+            },
+            TestCase {
+                name: "user not found".to_string(),
+                path: "/users/2".to_string(),
+                expected_status: 500,
+                expected_username: None,
+                mock_user_repository: || {
+                    let mut repository = MockIUserRepository::new();
+
+                    repository
+                        .expect_get_user()
+                        .times(1)
+                        .returning(|_| Ok(None));
+
+                    Arc::new(repository)
+                },
+            },
+            TestCase {
+                name: "invalid id".to_string(),
+                path: "/users/abc".to_string(),
+                expected_status: 400,
+                expected_username: None,
+                mock_user_repository: || Arc::new(MockIUserRepository::new()),
+            },
+        ];
+
+        let runtime = rupring::tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+
+        for t in test_cases {
+            let client = TestClient::builder(UserModule {})
+                .provider((t.mock_user_repository)())
+                .build();
+
+            let response = runtime.block_on(client.get(&t.path).send());
+
+            assert_eq!(response.status, t.expected_status, "{}", t.name);
+
+            if let Some(username) = t.expected_username {
+                let body: GetUserResponse = response.json().unwrap();
+                assert_eq!(body.username, username, "{}", t.name);
+            }
+        }
+    }
+}
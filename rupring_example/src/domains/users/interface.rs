@@ -19,7 +19,7 @@ pub trait IUserService {
 }
 
 #[mockall::automock]
-pub trait IUserRepository {
+pub trait IUserRepository: Send + Sync {
     fn create_user(&self, params: CreateUserParams) -> rupring::Result<i32>;
     fn update_user(&self, params: UpdateUserParams) -> rupring::Result<()>;
     fn delete_user(&self, params: DeleteUserParams) -> rupring::Result<()>;
@@ -33,19 +33,14 @@ pub(crate) fn extract_additional_attributes(
         }
 
         match tree {
-            proc_macro::TokenTree::Punct(ref punct) => {
-                if punct.to_string().as_str() == "#" {
-                    // [Key1 = value1, key2, value2, ...] 형태의 attribute를 파싱해서 map에 할당한다.
-                    if let Some(proc_macro::TokenTree::Group(group)) = iter.next() {
-                        let attributes = parse_attribute(group.stream(), true);
-
-                        for (key, value) in attributes {
-                            map.insert(key, value);
-                        }
+            proc_macro::TokenTree::Punct(ref punct) if punct.to_string().as_str() == "#" => {
+                // [Key1 = value1, key2, value2, ...] 형태의 attribute를 파싱해서 map에 할당한다.
+                if let Some(proc_macro::TokenTree::Group(group)) = iter.next() {
+                    let attributes = parse_attribute(group.stream(), true);
+
+                    for (key, value) in attributes {
+                        map.insert(key, value);
                     }
-                } else {
-                    code_without_attributes.push(tree);
-                    done = true;
                 }
             }
             _ => {
@@ -124,6 +119,29 @@ pub(crate) fn parse_attribute(
 
             attribute_map.insert(attribute_name, attribute_value);
 
+            attribute_name_outer = None;
+        } else if let (Some(attribute_name), proc_macro::TokenTree::Group(group)) =
+            (&attribute_name_outer, &token)
+        {
+            // name(a, b, c) is the same as name = [a, b, c]
+            if group.delimiter() != proc_macro::Delimiter::Parenthesis {
+                attribute_name_outer = Some(token_string);
+                continue;
+            }
+
+            let attribute_value = group
+                .stream()
+                .to_string()
+                .split(",")
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<String>>();
+
+            attribute_map.insert(
+                attribute_name.clone(),
+                AttributeValue::ListOfString(attribute_value),
+            );
+
             attribute_name_outer = None;
         } else {
             attribute_name_outer = Some(token_string);
@@ -137,9 +155,35 @@ pub(crate) fn parse_attribute(
     attribute_map
 }
 
+// [X-Total-Count, "Location"] => ["X-Total-Count", "Location"]
+pub(crate) fn parse_list(value: &AttributeValue) -> Vec<String> {
+    let values = match value {
+        AttributeValue::ListOfString(values) => values.clone(),
+        AttributeValue::String(value) => vec![value.clone()],
+    };
+
+    values
+        .iter()
+        .map(|e| e.replace(" ", "").replace("\"", ""))
+        .filter(|e| !e.is_empty())
+        .collect()
+}
+
+// (201: UserDto, 404: ProblemDetail, 204) => [("201", Some("UserDto")), ("404", Some("ProblemDetail")), ("204", None)]
+pub(crate) fn parse_response_list(value: &AttributeValue) -> Vec<(String, Option<String>)> {
+    parse_list(value)
+        .into_iter()
+        .map(|e| match e.split_once(':') {
+            Some((status, type_)) => (status.to_string(), Some(type_.to_string())),
+            None => (e, None),
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct AnnotatedParameter {
     pub(crate) attributes: HashMap<String, AttributeValue>,
     pub(crate) name: String,
     pub(crate) type_: String,
+    pub(crate) syn_type: syn::Type,
 }
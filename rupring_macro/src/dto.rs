@@ -0,0 +1,321 @@
+use std::str::FromStr;
+
+use proc_macro::TokenStream;
+use quote::ToTokens;
+use syn::{Attribute, Expr, Fields, Generics, ItemEnum};
+
+use crate::{
+    parse,
+    serde_attribute::{self, SerdeContainer, SerdeField},
+};
+
+// Code that adds a field to `swagger_definition` in to_swagger_definition of RupringDto.
+pub(crate) struct SwaggerFieldCode {
+    pub field_type: String,
+    pub property_name: String,
+    pub description: String,
+    pub example: String,
+    pub swagger_validation: String,
+    pub swagger_array_validation: String,
+    pub is_required: bool,
+    pub is_nullable: bool,
+    pub is_flatten: bool,
+}
+
+impl SwaggerFieldCode {
+    pub(crate) fn make_code(&self) -> String {
+        let SwaggerFieldCode {
+            field_type,
+            property_name,
+            description,
+            example,
+            swagger_validation,
+            swagger_array_validation,
+            is_required,
+            is_nullable,
+            is_flatten,
+        } = self;
+
+        // #[serde(flatten)]: the properties of the field type are the properties of the struct.
+        if *is_flatten {
+            return format!(
+                r#"rupring::swagger::macros::flatten_into::<{field_type}>(context, &mut swagger_definition, {});"#,
+                !is_nullable
+            );
+        }
+
+        let mut code = "".to_string();
+
+        if *is_required {
+            code += format!(r#"swagger_definition.required.push("{property_name}".to_string());"#)
+                .as_str();
+        }
+
+        if *is_nullable {
+            code += format!(r#"swagger_definition.nullable.push("{property_name}".to_string());"#)
+                .as_str();
+        }
+
+        code += format!(
+            r#"let property_value = rupring::swagger::macros::field_property::<{field_type}>(
+                context,
+                "{description}",
+                {example}.into(),
+                {swagger_validation},
+                {swagger_array_validation},
+            );"#
+        )
+        .as_str();
+
+        code += format!(
+            r#"swagger_definition.properties.insert("{property_name}".to_string(), property_value);"#
+        )
+        .as_str();
+
+        code
+    }
+}
+
+// The generics of the impl, with `bound` on every type parameter.
+// (impl_generics, type_generics, where_clause)
+pub(crate) fn split_generics(generics: &Generics, bound: Option<&str>) -> (String, String, String) {
+    let mut generics = generics.clone();
+
+    if let Some(bound) = bound {
+        let bound: syn::TypeParamBound = syn::parse_str(bound).unwrap();
+
+        for param in generics.type_params_mut() {
+            param.bounds.push(bound.clone());
+        }
+    }
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    (
+        impl_generics.to_token_stream().to_string(),
+        type_generics.to_token_stream().to_string(),
+        where_clause.to_token_stream().to_string(),
+    )
+}
+
+// where A: B => where A: B, {predicate}
+pub(crate) fn add_where_predicate(where_clause: &str, predicate: &str) -> String {
+    if where_clause.is_empty() {
+        return format!("where {predicate}");
+    }
+
+    format!("{}, {predicate}", where_clause.trim_end_matches(','))
+}
+
+// module::Name, or module::Name<A,B> for a generic type
+pub(crate) fn make_definition_name_code(name: &str, generics: &Generics) -> String {
+    let type_names = generics
+        .type_params()
+        .map(|param| {
+            format!(
+                "rupring::swagger::macros::type_name_of::<{}>()",
+                param.ident
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut code = "fn get_definition_name() -> String {".to_string();
+    code += r#"let current_module_name = module_path!().to_string();"#;
+
+    if type_names.is_empty() {
+        code += format!(r#"let definition_name = format!("{{current_module_name}}::{name}");"#)
+            .as_str();
+    } else {
+        code += format!(
+            r#"let definition_name = format!("{{current_module_name}}::{name}<{{}}>", [{}].join(","));"#,
+            type_names.join(", ")
+        )
+        .as_str();
+    }
+
+    code += "definition_name";
+    code += "}";
+
+    code
+}
+
+// #[desc = "..."], #[example = ...] of a variant field
+fn parse_description_and_example(attributes: &[Attribute]) -> (String, String) {
+    let mut description = "".to_string();
+    let mut example = r#""""#.to_string();
+
+    for attribute in attributes {
+        let path = attribute.meta.path().to_token_stream().to_string();
+
+        let Some(Expr::Lit(lit)) = attribute
+            .meta
+            .require_name_value()
+            .ok()
+            .map(|e| e.value.clone())
+        else {
+            continue;
+        };
+
+        match path.as_str() {
+            "example" => example = format!("{:?}", lit.to_token_stream().to_string()),
+            "description" | "desc" => {
+                description = lit
+                    .to_token_stream()
+                    .to_string()
+                    .trim_start_matches('"')
+                    .trim_end_matches('"')
+                    .to_string();
+            }
+            _ => {}
+        }
+    }
+
+    (description, example)
+}
+
+// derive(RupringDto) for an enum.
+// A unit enum is a string enum, and an enum with data is oneOf the variants in the serde representation.
+pub(crate) fn derive_rupring_dto_enum(ast: ItemEnum) -> TokenStream {
+    let enum_name = ast.ident.to_string();
+    let serde_container = SerdeContainer::parse(&ast.attrs);
+
+    let (impl_generics, type_generics, where_clause) = split_generics(
+        &ast.generics,
+        Some("rupring::swagger::macros::ToSwaggerDefinitionNode"),
+    );
+
+    let mut code = "".to_string();
+
+    code += format!(
+        r#"impl {impl_generics} rupring::swagger::macros::ToSwaggerDefinitionNode for {enum_name} {type_generics} {where_clause} {{"#
+    )
+    .as_str();
+
+    code += make_definition_name_code(&enum_name, &ast.generics).as_str();
+
+    code += "fn to_swagger_definition(context: &mut rupring::swagger::macros::SwaggerDefinitionContext) -> rupring::swagger::macros::SwaggerDefinitionNode {";
+
+    let tagging = match (
+        serde_container.untagged,
+        &serde_container.tag,
+        &serde_container.content,
+    ) {
+        (true, _, _) => "rupring::swagger::macros::SwaggerEnumTagging::Untagged".to_string(),
+        (false, Some(tag), Some(content)) => format!(
+            r#"rupring::swagger::macros::SwaggerEnumTagging::Adjacent {{ tag: "{tag}".to_string(), content: "{content}".to_string() }}"#
+        ),
+        (false, Some(tag), None) => format!(
+            r#"rupring::swagger::macros::SwaggerEnumTagging::Internal {{ tag: "{tag}".to_string() }}"#
+        ),
+        (false, None, _) => "rupring::swagger::macros::SwaggerEnumTagging::External".to_string(),
+    };
+
+    code += format!("let tagging = {tagging};").as_str();
+    code += "let mut variants = vec![];";
+
+    for variant in ast.variants.iter() {
+        let serde_variant = SerdeField::parse(&variant.attrs);
+
+        if serde_variant.skip {
+            continue;
+        }
+
+        let variant_name = serde_variant.rename.clone().unwrap_or_else(|| {
+            serde_attribute::rename_variant(&variant.ident.to_string(), &serde_container.rename_all)
+        });
+
+        let content = match &variant.fields {
+            Fields::Unit => "None".to_string(),
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let field_type = fields.unnamed[0].ty.to_token_stream().to_string();
+
+                format!("Some(rupring::swagger::macros::property_of::<{field_type}>(context))")
+            }
+            Fields::Unnamed(fields) => {
+                let items = fields
+                    .unnamed
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "rupring::swagger::macros::property_of::<{}>(context)",
+                            field.ty.to_token_stream()
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                format!(
+                    "Some(rupring::swagger::macros::tuple_property(vec![{}]))",
+                    items.join(", ")
+                )
+            }
+            Fields::Named(fields) => {
+                let rename_rule = serde_variant
+                    .rename_all
+                    .clone()
+                    .or(serde_container.rename_all_fields.clone());
+
+                let mut content = "{".to_string();
+                content += r#"let mut swagger_definition = rupring::swagger::json::SwaggerDefinition {
+                    type_: "object".to_string(),
+                    ..Default::default()
+                };"#;
+
+                for field in fields.named.iter() {
+                    let serde_field = SerdeField::parse(&field.attrs);
+
+                    if serde_field.skip {
+                        continue;
+                    }
+
+                    let field_name = field.ident.as_ref().unwrap().to_string();
+                    let is_nullable = parse::is_option_type(&field.ty);
+                    let (description, example) = parse_description_and_example(&field.attrs);
+
+                    content += SwaggerFieldCode {
+                        field_type: field.ty.to_token_stream().to_string(),
+                        property_name: serde_field.rename.clone().unwrap_or_else(|| {
+                            serde_attribute::rename_field(&field_name, &rename_rule)
+                        }),
+                        description,
+                        example,
+                        swagger_validation: "Default::default()".to_string(),
+                        swagger_array_validation: "Default::default()".to_string(),
+                        is_required: !is_nullable && !serde_field.optional,
+                        is_nullable,
+                        is_flatten: serde_field.flatten,
+                    }
+                    .make_code()
+                    .as_str();
+                }
+
+                content +=
+                    "Some(rupring::swagger::json::SwaggerProperty::Object(swagger_definition))";
+                content += "}";
+
+                content
+            }
+        };
+
+        code += format!(
+            r#"let content = {content};
+            variants.push(rupring::swagger::macros::enum_variant(context, &tagging, "{variant_name}", content));"#
+        )
+        .as_str();
+    }
+
+    code += "rupring::swagger::macros::enum_definition(variants)";
+    code += "}";
+    code += "}";
+
+    // An enum has no validation attributes, but it can be a field with #[validate].
+    let (impl_generics, type_generics, where_clause) = split_generics(&ast.generics, None);
+
+    code += format!(
+        r#"impl {impl_generics} rupring::validation::Validate for {enum_name} {type_generics} {where_clause} {{
+            fn validate_fields(&self, _: &str, _: &mut Vec<rupring::validation::FieldError>) {{}}
+        }}"#
+    )
+    .as_str();
+
+    TokenStream::from_str(code.as_str()).unwrap()
+}
@@ -1,6 +1,9 @@
 mod attribute;
+mod dto;
 mod parse;
 mod rule;
+mod serde_attribute;
+mod validation;
 use std::str::FromStr;
 
 use attribute::AttributeValue;
@@ -8,8 +11,6 @@ use proc_macro::TokenStream;
 use quote::ToTokens;
 use syn::Expr;
 
-const SHARP: &str = "#";
-
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
 pub fn Module(attr: TokenStream, mut item: TokenStream) -> TokenStream {
@@ -73,7 +74,43 @@ pub fn Module(attr: TokenStream, mut item: TokenStream) -> TokenStream {
 
     let middlewares = middlewares
         .iter()
-        .map(|middleware| format!("Box::new({})", middleware.to_owned()))
+        .map(|middleware| {
+            format!(
+                "rupring::IntoMiddleware::into_middleware({})",
+                middleware.to_owned()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    let error_handlers = match attribute_map.get("error_handlers") {
+        Some(error_handlers) => match error_handlers {
+            AttributeValue::ListOfString(error_handlers) => error_handlers.to_owned(),
+            AttributeValue::String(error_handler) => vec![error_handler.to_owned()],
+        },
+        None => vec![],
+    };
+
+    let error_handlers = error_handlers
+        .iter()
+        .map(|error_handler| {
+            let mut scopes = error_handler
+                .split("::")
+                .map(|e| e.trim())
+                .collect::<Vec<&str>>();
+
+            let error_handler = scopes.pop().unwrap();
+
+            let error_handler_name = rule::make_error_handler_name(error_handler);
+
+            let scopes = scopes.join("::");
+
+            if scopes.is_empty() {
+                format!("Box::new({error_handler_name}{{}})")
+            } else {
+                format!("Box::new({scopes}::{error_handler_name}{{}})")
+            }
+        })
         .collect::<Vec<String>>()
         .join(", ");
 
@@ -94,6 +131,10 @@ pub fn Module(attr: TokenStream, mut item: TokenStream) -> TokenStream {
     fn middlewares(&self) -> Vec<rupring::MiddlewareFunction> {{
         vec![{middlewares}]
     }}
+
+    fn error_handlers(&self) -> Vec<rupring::ErrorHandlerFunction> {{
+        vec![{error_handlers}]
+    }}
 }}
 "#
     );
@@ -157,10 +198,49 @@ pub fn Controller(attr: TokenStream, mut item: TokenStream) -> TokenStream {
 
     let middlewares = middlewares
         .iter()
-        .map(|middleware| format!("Box::new({})", middleware.to_owned()))
+        .map(|middleware| {
+            format!(
+                "rupring::IntoMiddleware::into_middleware({})",
+                middleware.to_owned()
+            )
+        })
         .collect::<Vec<String>>()
         .join(", ");
 
+    // cors=function_name (fn() -> rupring::http::cors::CorsConfig)
+    let cors_code = match attribute_map.get("cors") {
+        Some(AttributeValue::String(cors)) if !cors.is_empty() => format!(
+            r#"
+            fn cors(&self) -> Option<rupring::http::cors::CorsConfig> {{
+                Some({cors}())
+            }}"#
+        ),
+        _ => "".to_string(),
+    };
+
+    // resolve_prefix=function_name (fn(&rupring::application_properties::ApplicationProperties) -> String)
+    let resolve_prefix_code = match attribute_map.get("resolve_prefix") {
+        Some(AttributeValue::String(resolve_prefix)) if !resolve_prefix.is_empty() => format!(
+            r#"
+            fn resolve_prefix(&self, application_properties: &rupring::application_properties::ApplicationProperties) -> String {{
+                {resolve_prefix}(application_properties)
+            }}"#
+        ),
+        _ => "".to_string(),
+    };
+
+    // auth=ApiKeyAuth, auth=[ApiKeyAuth, OAuth2]: the default security of the routes
+    let swagger_security_code = match attribute_map.get("auth") {
+        Some(auth) => make_swagger_security_code(
+            &attribute::parse_list(auth),
+            &attribute_map
+                .get("scopes")
+                .map(attribute::parse_list)
+                .unwrap_or_default(),
+        ),
+        None => "".to_string(),
+    };
+
     let new_code = format!(
         r#"impl rupring::IController for {struct_name} {{
             fn prefix(&self) -> String {{
@@ -174,6 +254,9 @@ pub fn Controller(attr: TokenStream, mut item: TokenStream) -> TokenStream {
             fn middlewares(&self) -> Vec<rupring::MiddlewareFunction> {{
                 vec![{middlewares}]
             }}
+            {cors_code}
+            {resolve_prefix_code}
+            {swagger_security_code}
         }}"#
     );
 
@@ -208,35 +291,54 @@ pub fn Bean(attr: TokenStream, item: TokenStream) -> TokenStream {
 
 #[proc_macro_attribute]
 #[allow(non_snake_case)]
-pub fn Injectable(attr: TokenStream, mut item: TokenStream) -> TokenStream {
-    let _item = item.clone();
-    let function_ast = syn::parse_macro_input!(_item as syn::ItemFn);
+pub fn Injectable(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut function_ast = syn::parse_macro_input!(item as syn::ItemFn);
 
-    let _provider_type = parse::find_function_return_type(&function_ast);
+    let provider_type = parse::find_function_return_type(&function_ast);
     let parameters_types = parse::find_function_parameter_types(&function_ast);
+    let parameters_qualifiers = match parse::take_parameter_qualifiers(&mut function_ast) {
+        Ok(qualifiers) => qualifiers,
+        Err(error) => return error.to_compile_error().into(),
+    };
     let function_name = parse::find_function_name(&function_ast);
 
     let mut dependencies = vec![];
+    let mut qualified_dependencies = vec![];
     let mut arguments = vec![];
-    for parameter_type in parameters_types {
-        dependencies.push(format!("std::any::TypeId::of::<{parameter_type}>()",));
+    for (parameter_type, qualifier) in parameters_types.iter().zip(parameters_qualifiers) {
+        let getter = match &qualifier {
+            Some(qualifier) => {
+                qualified_dependencies.push(format!(
+                    "(std::any::TypeId::of::<{parameter_type}>(), \"{qualifier}\".to_string())"
+                ));
+
+                format!("di_context.get_qualified::<{parameter_type}>(\"{qualifier}\")")
+            }
+            None => {
+                dependencies.push(format!("std::any::TypeId::of::<{parameter_type}>()",));
+
+                format!("di_context.get::<{parameter_type}>()")
+            }
+        };
 
         if parameter_type.contains("&") {
-            arguments.push(format!("di_context.get::<{parameter_type}>().unwrap()",));
+            arguments.push(format!("{getter}.unwrap()",));
         } else {
-            arguments.push(format!(
-                "di_context.get::<{parameter_type}>().unwrap().to_owned()"
-            ));
+            arguments.push(format!("{getter}.unwrap().to_owned()"));
         }
     }
 
+    let attribute_map = if attr.clone().into_iter().count() > 1 {
+        attribute::parse_attribute(attr.clone(), false)
+    } else {
+        Default::default()
+    };
+
     let struct_name = if attr.is_empty() {
         function_name.clone()
     } else if attr.clone().into_iter().count() == 1 {
         attr.into_iter().next().unwrap().to_string()
     } else {
-        let attribute_map = attribute::parse_attribute(attr.clone(), false);
-
         match attribute_map.get("name") {
             Some(AttributeValue::String(name)) => name.to_owned(),
             Some(_) => function_name.clone(),
@@ -244,8 +346,28 @@ pub fn Injectable(attr: TokenStream, mut item: TokenStream) -> TokenStream {
         }
     };
 
+    // #[Injectable(qualifier = "redis")]: selected with di_context.get_qualified
+    let qualifier_code = match attribute_map.get("qualifier") {
+        Some(AttributeValue::String(qualifier)) => format!(
+            r#"fn qualifier(&self) -> Option<String> {{
+        Some("{}".to_string())
+    }}"#,
+            qualifier.trim_matches('"')
+        ),
+        _ => "".to_string(),
+    };
+
+    // #[Injectable(primary = true)]: selected when several providers exist for the same type
+    let primary_code = match attribute_map.get("primary") {
+        Some(AttributeValue::String(primary)) if primary == "true" => {
+            "fn primary(&self) -> bool { true }"
+        }
+        _ => "",
+    };
+
     let function_call = format!("{function_name}({})", arguments.join(", "));
     let dependencies = dependencies.join(", ");
+    let qualified_dependencies = qualified_dependencies.join(", ");
 
     let new_code = format!(
         r#"
@@ -256,17 +378,139 @@ impl rupring::IProvider for {struct_name} {{
         vec![{dependencies}]
     }}
 
+    fn qualified_dependencies(&self) -> Vec<(std::any::TypeId, String)> {{
+        vec![{qualified_dependencies}]
+    }}
+
+    fn provided_type(&self) -> Option<std::any::TypeId> {{
+        Some(std::any::TypeId::of::<{provider_type}>())
+    }}
+
+    {qualifier_code}
+
+    {primary_code}
+
     fn provide(&self, di_context: &rupring::DIContext) -> Box<dyn std::any::Any> {{
         Box::new({function_call})
     }}
 }}"#
     );
 
+    let mut item: TokenStream = function_ast.to_token_stream().into();
+    item.extend(TokenStream::from_str(new_code.as_str()).unwrap());
+
+    item
+}
+
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn ExceptionHandler(attr: TokenStream, item: TokenStream) -> TokenStream {
+    ErrorHandler(attr, item)
+}
+
+// #[ErrorHandler(status = 404)] fn f(error: &rupring::error::HttpError) -> rupring::Response
+// #[ErrorHandler] fn f(error: &SomeError) -> rupring::Response
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn ErrorHandler(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let _item = item.clone();
+    let function_ast = syn::parse_macro_input!(_item as syn::ItemFn);
+
+    let (mut item, additional_attributes) = attribute::extract_additional_attributes(item);
+
+    let function_name = parse::find_function_name(&function_ast);
+    let parameters_types = parse::find_function_parameter_types(&function_ast);
+
+    let attribute_map = attribute::parse_attribute(attr.clone(), false);
+
+    // #[responses(404: ErrorDto)], or the status of the handler with #[response = ErrorDto]
+    let mut responses = additional_attributes
+        .get("responses")
+        .map(attribute::parse_response_list)
+        .unwrap_or_default();
+
+    if let Some(AttributeValue::String(status)) = attribute_map.get("status") {
+        if !responses.iter().any(|(e, _)| e == status) {
+            let response = additional_attributes
+                .get("response")
+                .map(|e| e.as_string().replace(" ", ""));
+
+            responses.push((status.clone(), response));
+        }
+    }
+
+    let swagger_responses_code = make_swagger_responses_code(&responses);
+
+    let status_code = match attribute_map.get("status") {
+        Some(AttributeValue::String(status)) => format!(
+            r#"
+            if error.status != {status} {{
+                return None;
+            }}"#
+        ),
+        _ => "".to_string(),
+    };
+
+    let is_http_error = match function_ast.sig.inputs.first() {
+        Some(syn::FnArg::Typed(pat_type)) => parse::is_http_error_type(&pat_type.ty),
+        _ => false,
+    };
+
+    // If the parameter is not HttpError, the error is handled only when it is of that type.
+    let call_code = match parameters_types.first() {
+        None => format!("{function_name}()"),
+        Some(_) if is_http_error => format!("{function_name}(error)"),
+        Some(parameter_type) => {
+            let error_type = parameter_type.trim_start_matches("&").trim();
+
+            format!("{function_name}(error.downcast_ref::<{error_type}>()?)")
+        }
+    };
+
+    let error_handler_name = rule::make_error_handler_name(function_name.as_str());
+
+    let new_code = format!(
+        r#"
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub(crate) struct {error_handler_name} {{}}
+
+impl rupring::IErrorHandler for {error_handler_name} {{
+    fn handle(&self, error: &rupring::error::HttpError) -> Option<rupring::Response> {{
+        {status_code}
+
+        Some(rupring::response::IntoResponse::into_response({call_code}))
+    }}
+
+    {swagger_responses_code}
+}}
+"#
+    );
+
     item.extend(TokenStream::from_str(new_code.as_str()).unwrap());
 
     item
 }
 
+// The value of the extractor is validated if the type implements rupring::validation::Validate. (RupringDto)
+fn make_validation_code(parameter_name: &str) -> String {
+    format!(
+        r###"
+        {{
+            use rupring::validation::{{ValidateImplemented as _, ValidateNotImplemented as _}};
+            if let Err(errors) = (&&rupring::validation::ValidateOf(&{parameter_name})).validate_if_implemented() {{
+                return rupring::Response::new().error(rupring::error::Errors::ValidationFailed(errors)).into();
+            }}
+        }}
+        "###
+    )
+}
+
+// Vec < String > => Vec<String>
+fn type_name_of(type_: &syn::Type) -> String {
+    type_.to_token_stream().to_string().replace(' ', "")
+}
+
 fn convert_rust_type_to_js_type(rust_type: &str) -> String {
     match rust_type {
         "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128" => {
@@ -283,6 +527,97 @@ fn convert_rust_type_to_js_type(rust_type: &str) -> String {
     }
 }
 
+fn make_swagger_parameter_code(
+    name: &str,
+    category: &str,
+    description: &str,
+    required: bool,
+    type_: &str,
+) -> String {
+    format!(
+        r##"
+        swagger.parameters.push(
+            rupring::swagger::SwaggerParameter {{
+                name: "{name}".to_string(),
+                in_: rupring::swagger::SwaggerParameterCategory::{category},
+                description: "{description}".to_string(),
+                required: {required},
+                schema: Some(rupring::swagger::SwaggerTypeOrReference::Type(
+                    rupring::swagger::SwaggerType {{
+                        type_: "{type_}".to_string(),
+                        ..Default::default()
+                    }}
+                )),
+                type_: None,
+            }}
+        );
+        "##
+    )
+}
+
+// [("201", Some("UserDto")), ("204", None)] => fn swagger_responses(&self) -> Vec<SwaggerResponseInfo>
+fn make_swagger_responses_code(responses: &[(String, Option<String>)]) -> String {
+    if responses.is_empty() {
+        return "".to_string();
+    }
+
+    let responses = responses
+        .iter()
+        .map(|(status, response)| {
+            let body = match response {
+                Some(response) => format!(
+                    "rupring::swagger::macros::generate_swagger_request_info::<{response}>()"
+                ),
+                None => "None".to_string(),
+            };
+
+            format!(r#"rupring::swagger::macros::SwaggerResponseInfo::new("{status}", {body})"#)
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        r#"
+        fn swagger_responses(&self) -> Vec<rupring::swagger::macros::SwaggerResponseInfo> {{
+            vec![{responses}]
+        }}
+        "#
+    )
+}
+
+// [ApiKeyAuth, OAuth2] => fn swagger_security_info (each name is an alternative requirement)
+fn make_swagger_security_code(auth: &[String], scopes: &[String]) -> String {
+    let auth = if auth.is_empty() {
+        vec!["BearerAuth".to_string()]
+    } else {
+        auth.to_vec()
+    };
+
+    let scopes = scopes
+        .iter()
+        .map(|scope| format!("{scope:?}.to_string()"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let requirements = auth
+        .iter()
+        .map(|name| {
+            format!(
+                r#"std::collections::HashMap::from_iter(vec![("{name}".to_string(), vec![{scopes}])])"#
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        r#"
+        fn swagger_security_info(&self) -> Vec<rupring::swagger::json::SwaggerSecurity> {{
+            vec![{requirements}]
+        }}
+        "#
+    )
+}
+
 #[allow(non_snake_case)]
 fn MapRoute(method: String, attr: TokenStream, item: TokenStream) -> TokenStream {
     let _item = item.clone();
@@ -343,54 +678,100 @@ fn MapRoute(method: String, attr: TokenStream, item: TokenStream) -> TokenStream
         .trim_end_matches("\"")
         .to_owned();
 
-    let auth = additional_attributes.get("auth").map(|e| {
-        let mut auth_value = e
-            .as_string()
-            .trim_start_matches("\"")
-            .trim_end_matches("\"")
-            .to_owned();
+    let responses = additional_attributes
+        .get("responses")
+        .map(attribute::parse_response_list)
+        .unwrap_or_default();
 
-        if auth_value.is_empty() {
-            auth_value = "BearerAuth".to_string();
-        }
+    let response_headers = additional_attributes
+        .get("response_headers")
+        .map(attribute::parse_list)
+        .unwrap_or_default();
 
-        auth_value
-    });
+    let mut produces = additional_attributes
+        .get("produces")
+        .map(attribute::parse_list)
+        .unwrap_or_default();
+
+    let mut consumes = additional_attributes
+        .get("consumes")
+        .map(attribute::parse_list)
+        .unwrap_or_default();
+
+    // #[sse]: Server-Sent Events
+    if additional_attributes.contains_key("sse") && produces.is_empty() {
+        produces.push("text/event-stream".to_string());
+    }
+
+    // #[auth], #[auth = ApiKeyAuth], #[auth = [ApiKeyAuth, OAuth2]], #[auth = none]
+    let auth = additional_attributes.get("auth").map(attribute::parse_list);
+    let auth_disabled = auth
+        .as_ref()
+        .is_some_and(|auth| auth.len() == 1 && auth[0] == "none");
+    let auth_scopes = additional_attributes
+        .get("scopes")
+        .map(attribute::parse_list)
+        .unwrap_or_default();
+
+    let attribute_map = attribute::parse_attribute(attr.clone(), false);
+
+    let path = match attribute_map.get("path") {
+        Some(AttributeValue::String(path)) => parse::parse_route_path(path),
+        Some(_) => "".to_string(),
+        None => "".to_string(),
+    };
 
     let (item, annotated_parameters) = parse::manipulate_route_function_parameters(item);
 
     let mut swagger_code = "".to_string();
     let mut variables_code = "".to_string();
 
+    // Type of the #[RequestBody] parameter, for the swagger request body.
+    let mut request_body_parameter_type = None;
+
     // 함수 최상단에 코드를 주입합니다.
 
     for anotated_parameter in annotated_parameters {
+        let description = anotated_parameter
+            .attributes
+            .get("Description")
+            .map(|e| {
+                e.as_string()
+                    .trim_start_matches("\"")
+                    .trim_end_matches("\"")
+                    .to_string()
+            })
+            .unwrap_or_default();
+
         if anotated_parameter.attributes.contains_key("PathVariable") {
             let parameter_name = anotated_parameter.name;
             let parameter_type = anotated_parameter.type_;
             let path_name = anotated_parameter.attributes["PathVariable"].as_string();
             let path_name = path_name.trim_start_matches("\"").trim_end_matches("\"");
-            let required = !parameter_type.starts_with("Option<");
-            let type_ = convert_rust_type_to_js_type(parameter_type.as_str());
+            let required = !parse::is_option_type(&anotated_parameter.syn_type);
+            let type_ = convert_rust_type_to_js_type(&type_name_of(
+                parse::option_inner_type(&anotated_parameter.syn_type)
+                    .unwrap_or(&anotated_parameter.syn_type),
+            ));
 
-            let description = anotated_parameter
-                .attributes
-                .get("Description")
-                .map(|e| {
-                    e.as_string()
-                        .trim_start_matches("\"")
-                        .trim_end_matches("\"")
-                        .to_string()
-                })
-                .unwrap_or_default();
+            // An optional segment (ex. /posts/:page?) may not be captured.
+            let code_if_parameter_is_missing = if required {
+                format!(
+                    r#"return rupring::Response::new().status(400).text("Invalid parameter: {parameter_name}").into()"#
+                )
+            } else {
+                "None".to_string()
+            };
 
             let variable_expression = format!(
                 r###"
                 use rupring::ParamStringDeserializer;
-                let ___{parameter_name} = rupring::ParamString(request.path_parameters["{path_name}"].clone());
-                let {parameter_name}: {parameter_type} = match ___{parameter_name}.deserialize() {{
-                    Ok(v) => v,
-                    Err(_) => return rupring::Response::new().status(400).text("Invalid parameter: {parameter_name}"),
+                let {parameter_name}: {parameter_type} = match request.path_parameters.get("{path_name}") {{
+                    Some(___{parameter_name}) => match rupring::ParamString(___{parameter_name}.clone()).deserialize() {{
+                        Ok(v) => v,
+                        Err(_) => return rupring::Response::new().status(400).text("Invalid parameter: {parameter_name}").into(),
+                    }},
+                    None => {code_if_parameter_is_missing},
                 }};
                 "###
             );
@@ -409,7 +790,9 @@ fn MapRoute(method: String, attr: TokenStream, item: TokenStream) -> TokenStream
                         schema: Some(rupring::swagger::SwaggerTypeOrReference::Type(
                             rupring::swagger::SwaggerType {{
                                 type_: "{type_}".to_string(),
-                            }} 
+                                // The constraint of the route segment (ex. {{id:int}})
+                                validation: rupring::swagger::macros::path_parameter_validation({path:?}, "{path_name}"),
+                            }}
                         )),
                         type_: None,
                     }}
@@ -421,6 +804,186 @@ fn MapRoute(method: String, attr: TokenStream, item: TokenStream) -> TokenStream
 
             continue;
         }
+
+        let parameter_name = anotated_parameter.name;
+        let parameter_type = anotated_parameter.type_;
+        let required = !parse::is_option_type(&anotated_parameter.syn_type);
+
+        // Option<T> => T
+        let inner_type = type_name_of(
+            parse::option_inner_type(&anotated_parameter.syn_type)
+                .unwrap_or(&anotated_parameter.syn_type),
+        );
+
+        if anotated_parameter.attributes.contains_key("RequestBody") {
+            // An empty body is None for Option types.
+            let code_if_body_is_empty = if required {
+                "".to_string()
+            } else {
+                "if request.raw_body.is_empty() { None } else".to_string()
+            };
+
+            // The body is a form or JSON, the same as bind(). (see Request::deserialize_body)
+            let some_code = if required { "" } else { "Some" };
+
+            variables_code.push_str(&format!(
+                r###"
+                let {parameter_name}: {parameter_type} = {{
+                    {code_if_body_is_empty} {{
+                        match request.deserialize_body::<{inner_type}>() {{
+                            Ok(v) => {some_code}(v),
+                            Err(error) => return rupring::Response::new().error(rupring::error::Errors::BindFailed(format!("invalid request body: {{error}}"))).into(),
+                        }}
+                    }}
+                }};
+                {validation_code}
+                "###,
+                validation_code = make_validation_code(&parameter_name),
+            ));
+
+            request_body_parameter_type = Some(inner_type);
+
+            continue;
+        }
+
+        if let Some(query_name) = anotated_parameter.attributes.get("Query") {
+            let query_name = query_name.as_string();
+            let query_name = query_name.trim_start_matches("\"").trim_end_matches("\"");
+
+            // #[Query] without a name binds the whole query string to a struct.
+            if query_name.is_empty() {
+                variables_code.push_str(&format!(
+                    r###"
+                    let {parameter_name}: {parameter_type} = {{
+                        use rupring::request::QueryMapDeserializer;
+                        match rupring::request::QueryMap(request.query_parameters.clone()).deserialize_query_map() {{
+                            Ok(v) => v,
+                            Err(error) => return rupring::Response::new().error(rupring::error::Errors::BindFailed(format!("invalid query: {{error}}"))).into(),
+                        }}
+                    }};
+                    {validation_code}
+                    "###,
+                    validation_code = make_validation_code(&parameter_name),
+                ));
+
+                swagger_code.push_str(&format!(
+                    r##"
+                    swagger.parameters.extend({{
+                        use rupring::swagger::macros::{{SwaggerSchemaDocumented as _, SwaggerSchemaUndocumented as _}};
+                        (&&rupring::swagger::macros::SwaggerSchemaOf::<{inner_type}>::new()).query_parameters()
+                    }});
+                    "##
+                ));
+
+                continue;
+            }
+
+            let type_ = convert_rust_type_to_js_type(&inner_type);
+
+            // QueryString deserializes missing values of Option types to None.
+            variables_code.push_str(&format!(
+                r###"
+                let {parameter_name}: {parameter_type} = {{
+                    use rupring::request::QueryStringDeserializer;
+                    let query = rupring::request::QueryString(request.query_parameters.get("{query_name}").cloned().unwrap_or_default());
+                    match query.deserialize_query_string() {{
+                        Ok(v) => v,
+                        Err(_) => return rupring::Response::new().error(rupring::error::Errors::BindFailed("invalid query parameter: {query_name}".to_string())).into(),
+                    }}
+                }};
+                "###
+            ));
+
+            swagger_code.push_str(&make_swagger_parameter_code(
+                query_name,
+                "Query",
+                &description,
+                required,
+                &type_,
+            ));
+
+            continue;
+        }
+
+        let header_or_cookie = [
+            ("Header", "headers", "Header"),
+            ("Cookie", "cookies", "Cookie"),
+        ]
+        .into_iter()
+        .find_map(|(attribute_name, field_name, category)| {
+            anotated_parameter
+                .attributes
+                .get(attribute_name)
+                .map(|name| (name.as_string(), field_name, category))
+        });
+
+        if let Some((name, field_name, category)) = header_or_cookie {
+            let mut name = name
+                .trim_start_matches("\"")
+                .trim_end_matches("\"")
+                .to_string();
+
+            // Header names are stored in lowercase.
+            if category == "Header" {
+                name = name.to_lowercase();
+            }
+
+            let kind = category.to_lowercase();
+            let type_ = convert_rust_type_to_js_type(&inner_type);
+
+            let code_if_value_is_missing = if required {
+                format!(
+                    r#"return rupring::Response::new().error(rupring::error::Errors::BindFailed("missing {kind}: {name}".to_string())).into()"#
+                )
+            } else {
+                "None".to_string()
+            };
+
+            variables_code.push_str(&format!(
+                r###"
+                let {parameter_name}: {parameter_type} = {{
+                    use rupring::ParamStringDeserializer;
+                    match request.{field_name}.get("{name}") {{
+                        Some(___{parameter_name}) => match rupring::ParamString(___{parameter_name}.clone()).deserialize() {{
+                            Ok(v) => v,
+                            Err(_) => return rupring::Response::new().error(rupring::error::Errors::BindFailed("invalid {kind}: {name}".to_string())).into(),
+                        }},
+                        None => {code_if_value_is_missing},
+                    }}
+                }};
+                "###
+            ));
+
+            swagger_code.push_str(&make_swagger_parameter_code(
+                &name,
+                category,
+                &description,
+                required,
+                &type_,
+            ));
+
+            continue;
+        }
+
+        if anotated_parameter.attributes.contains_key("Inject") {
+            // &T borrows the provider from the DI context, T is cloned.
+            let (provider_type, clone_code) = match parameter_type.strip_prefix("&") {
+                Some(provider_type) => (provider_type.to_string(), ""),
+                None => (parameter_type.clone(), ".clone()"),
+            };
+
+            variables_code.push_str(&format!(
+                r###"
+                let ___{parameter_name}_di_context = request.di_context();
+                let {parameter_name}: {parameter_type} = match ___{parameter_name}_di_context.get::<{provider_type}>() {{
+                    Some(provider) => provider{clone_code},
+                    None => return rupring::Response::new().error(rupring::error::Errors::Unknown("provider not found: {provider_type}".to_string())).into(),
+                }};
+                "###
+            ));
+
+            continue;
+        }
     }
 
     let mut item = parse::prepend_code_to_function_body(
@@ -429,17 +992,65 @@ fn MapRoute(method: String, attr: TokenStream, item: TokenStream) -> TokenStream
     );
 
     let function_name = parse::find_function_name(&function_ast);
-    let attribute_map = attribute::parse_attribute(attr.clone(), false);
 
-    let path = match attribute_map.get("path") {
-        Some(AttributeValue::String(path)) => path.to_owned(),
-        Some(_) => "".to_string(),
-        None => "".to_string(),
+    // stream_multipart = true: the handler reads the multipart body with request.multipart()
+    let stream_multipart_code = match attribute_map.get("stream_multipart") {
+        Some(AttributeValue::String(value)) if value == "true" => {
+            "fn stream_multipart(&self) -> bool { true }"
+        }
+        _ => "",
+    };
+
+    // stream_body = true: the handler reads the body with request.body_stream()
+    let stream_body_code = match attribute_map.get("stream_body") {
+        Some(AttributeValue::String(value)) if value == "true" => {
+            "fn stream_body(&self) -> bool { true }"
+        }
+        _ => "",
     };
 
+    // hidden = true: the route is not documented.
+    let hidden = matches!(
+        attribute_map.get("hidden"),
+        Some(AttributeValue::String(value)) if value == "true"
+    );
+
+    // The streaming routes read the raw body.
+    if consumes.is_empty() && !stream_multipart_code.is_empty() {
+        consumes.push("multipart/form-data".to_string());
+    }
+
+    if consumes.is_empty() && !stream_body_code.is_empty() {
+        consumes.push("application/octet-stream".to_string());
+    }
+
     let route_name = rule::make_route_name(function_name.as_str());
     let handler_name = rule::make_handler_name(function_name.as_str());
 
+    let handler_code = if parse::is_async_function(&function_ast) {
+        format!(
+            r#"
+impl rupring::IAsyncHandler for {handler_name} {{
+    fn handle(&self, request: rupring::Request, response: rupring::Response) -> rupring::HandlerFuture {{
+        Box::pin(async move {{
+            rupring::response::IntoResponse::into_response({function_name}(request, response).await)
+        }})
+    }}
+}}
+"#
+        )
+    } else {
+        format!(
+            r#"
+impl rupring::IHandler for {handler_name} {{
+    fn handle(&self, request: rupring::Request, response: rupring::Response) -> rupring::Response {{
+        rupring::response::IntoResponse::into_response({function_name}(request, response))
+    }}
+}}
+"#
+        )
+    };
+
     let mut swagger_request_body_code = "".to_string();
     if !request_body.is_empty() {
         swagger_request_body_code = format!(
@@ -449,6 +1060,15 @@ fn MapRoute(method: String, attr: TokenStream, item: TokenStream) -> TokenStream
             }}
             "#
         );
+    } else if let Some(request_body_parameter_type) = request_body_parameter_type {
+        swagger_request_body_code = format!(
+            r#"
+            fn swagger_request_info(&self) -> Option<rupring::swagger::macros::SwaggerRequestBody> {{
+                use rupring::swagger::macros::{{SwaggerSchemaDocumented as _, SwaggerSchemaUndocumented as _}};
+                (&&rupring::swagger::macros::SwaggerSchemaOf::<{request_body_parameter_type}>::new()).request_info()
+            }}
+            "#
+        );
     }
 
     let mut swagger_response_body_code = "".to_string();
@@ -462,23 +1082,54 @@ fn MapRoute(method: String, attr: TokenStream, item: TokenStream) -> TokenStream
         );
     }
 
+    let swagger_responses_code = make_swagger_responses_code(&responses);
+
     let mut swagger_security_code = "".to_string();
-    if let Some(auth) = auth {
-        swagger_security_code = format!(
-            r#"
-            fn swagger_security_info(&self) -> Vec<rupring::swagger::json::SwaggerSecurity> {{
-                vec![
-                    std::collections::HashMap::from_iter(vec![("{auth}".to_string(), vec![])])
-                ]
-            }}
-            "#
-        );
+    if let Some(auth) = auth.filter(|_| !auth_disabled) {
+        swagger_security_code = make_swagger_security_code(&auth, &auth_scopes);
+    }
+
+    if auth_disabled {
+        swagger_code.push_str("swagger.auth_disabled = true;");
     }
 
     swagger_code.push_str(format!("swagger.summary = \"{summary}\".to_string();").as_str());
     swagger_code.push_str(format!("swagger.description = \"{description}\".to_string();").as_str());
     swagger_code.push_str(format!("swagger.tags = {tags};", tags = tags).as_str());
 
+    let to_vec_code = |values: &[String]| {
+        format!(
+            "vec![{}]",
+            values
+                .iter()
+                .map(|e| format!("{e:?}.to_string()"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+
+    if !produces.is_empty() {
+        swagger_code.push_str(format!("swagger.produces = {};", to_vec_code(&produces)).as_str());
+    }
+
+    if !consumes.is_empty() {
+        swagger_code.push_str(format!("swagger.consumes = {};", to_vec_code(&consumes)).as_str());
+    }
+
+    if !response_headers.is_empty() {
+        swagger_code.push_str(
+            format!(
+                "swagger.response_headers = {};",
+                to_vec_code(&response_headers)
+            )
+            .as_str(),
+        );
+    }
+
+    if hidden {
+        swagger_code.push_str("swagger.hidden = true;");
+    }
+
     let new_code = format!(
         r#"
 #[allow(non_camel_case_types)]
@@ -491,10 +1142,10 @@ impl rupring::IRoute for {route_name} {{
     }}
 
     fn path(&self) -> String {{
-        "{path}".to_string()
+        {path:?}.to_string()
     }}
 
-    fn handler(&self) -> Box<dyn rupring::IHandler + Send + 'static> {{
+    fn handler(&self) -> Box<dyn rupring::IAsyncHandler + Send + Sync + 'static> {{
         Box::new({handler_name}{{}})
     }}
 
@@ -508,18 +1159,20 @@ impl rupring::IRoute for {route_name} {{
 
     {swagger_response_body_code}
 
+    {swagger_responses_code}
+
     {swagger_security_code}
+
+    {stream_multipart_code}
+
+    {stream_body_code}
 }}
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Clone)]
 pub(crate) struct {handler_name}{{}}
 
-impl rupring::IHandler for {handler_name} {{
-    fn handle(&self, request: rupring::Request, response: rupring::Response) -> rupring::Response {{
-        {function_name}(request, response)
-    }}
-}}
+{handler_code}
 "#,
     );
 
@@ -588,6 +1241,114 @@ pub fn PatchMapping(attr: TokenStream, item: TokenStream) -> TokenStream {
     Patch(attr, item)
 }
 
+#[proc_macro_attribute]
+#[allow(non_snake_case)]
+pub fn WebSocket(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let _item = item.clone();
+    let function_ast = syn::parse_macro_input!(_item as syn::ItemFn);
+
+    let function_name = parse::find_function_name(&function_ast);
+
+    if !parse::is_async_function(&function_ast) {
+        panic!("WebSocket handler {function_name} should be an async function");
+    }
+
+    // The handler is called after the handshake, with the parameters matched by type.
+    let mut arguments = vec![];
+    let mut use_request = false;
+
+    for parameter_type in parse::find_function_parameter_types(&function_ast) {
+        let type_name = parameter_type
+            .rsplit("::")
+            .next()
+            .unwrap_or_default()
+            .trim();
+
+        match type_name {
+            "Request" => {
+                use_request = true;
+                arguments.push("request.clone()");
+            }
+            "WebSocket" => {
+                arguments.push("socket");
+            }
+            _ => panic!(
+                "invalid parameter type of WebSocket handler {function_name}: {parameter_type} (Request or WebSocket expected)"
+            ),
+        }
+    }
+
+    let arguments = arguments.join(", ");
+
+    let request_code = if use_request {
+        "let ___request = request.clone();"
+    } else {
+        ""
+    };
+
+    let request_clone_code = if use_request {
+        "let request = ___request.clone();"
+    } else {
+        ""
+    };
+
+    let attribute_map = attribute::parse_attribute(attr.clone(), false);
+
+    let path = match attribute_map.get("path") {
+        Some(AttributeValue::String(path)) => parse::parse_route_path(path),
+        Some(_) => "".to_string(),
+        None => "".to_string(),
+    };
+
+    let route_name = rule::make_route_name(function_name.as_str());
+    let handler_name = rule::make_handler_name(function_name.as_str());
+
+    let new_code = format!(
+        r#"
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub(crate) struct {route_name} {{}}
+
+impl rupring::IRoute for {route_name} {{
+    fn method(&self) -> rupring::Method {{
+        rupring::Method::GET
+    }}
+
+    fn path(&self) -> String {{
+        {path:?}.to_string()
+    }}
+
+    fn handler(&self) -> Box<dyn rupring::IAsyncHandler + Send + Sync + 'static> {{
+        Box::new({handler_name}{{}})
+    }}
+}}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub(crate) struct {handler_name}{{}}
+
+impl rupring::IHandler for {handler_name} {{
+    fn handle(&self, request: rupring::Request, response: rupring::Response) -> rupring::Response {{
+        {request_code}
+
+        response.websocket(&request, move |socket: rupring::http::websocket::WebSocket| {{
+            {request_clone_code}
+
+            async move {{
+                let _ = {function_name}({arguments}).await;
+            }}
+        }})
+    }}
+}}
+"#,
+    );
+
+    let mut item = item;
+    item.extend(TokenStream::from_str(new_code.as_str()).unwrap());
+
+    item
+}
+
 /**
 ## What is RupringDto?
 - This is a macro used to automatically generate Swagger documents.
@@ -603,7 +1364,12 @@ pub fn PatchMapping(attr: TokenStream, item: TokenStream) -> TokenStream {
 7. query: Query parameter.
 8. body: Body parameter.
 9. ignore: Ignore the field.
+10. min, max, range, length, pattern, email: Validation of the field. (checked when binding the request)
+11. validate: Validate the nested RupringDto.
+
+It can be derived for enums and generic structs, and the serde attributes (rename_all, rename, skip, flatten, default) are applied to the document and the binding.
  */
+#[allow(ambiguous_derive_helpers)]
 #[proc_macro_derive(
     RupringDto,
     attributes(
@@ -617,43 +1383,69 @@ pub fn PatchMapping(attr: TokenStream, item: TokenStream) -> TokenStream {
         query,
         body,
         ignore,
+        min,
+        max,
+        range,
+        length,
+        pattern,
+        email,
+        validate,
     )
 )]
 pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
-    let ast = syn::parse_macro_input!(item as syn::ItemStruct);
+    let ast = match syn::parse_macro_input!(item as syn::Item) {
+        syn::Item::Struct(ast) => ast,
+        syn::Item::Enum(ast) => return dto::derive_rupring_dto_enum(ast),
+        _ => panic!("RupringDto can only be derived for a struct or an enum"),
+    };
     let struct_name = parse::find_struct_name(&ast);
+    let serde_container = serde_attribute::SerdeContainer::parse(&ast.attrs);
+
+    let (impl_generics, type_generics, where_clause) = dto::split_generics(
+        &ast.generics,
+        Some("rupring::swagger::macros::ToSwaggerDefinitionNode"),
+    );
 
     let mut code = "".to_string();
 
-    code +=
-        format!(r#"impl rupring::swagger::macros::ToSwaggerDefinitionNode for {struct_name} {{"#)
-            .as_str();
+    code += format!(
+        r#"impl {impl_generics} rupring::swagger::macros::ToSwaggerDefinitionNode for {struct_name} {type_generics} {where_clause} {{"#
+    )
+    .as_str();
 
-    code += "fn get_definition_name() -> String {";
-    code += r#"let current_module_name = module_path!().to_string();"#;
-    code += format!(r#"let definition_name = format!("{{current_module_name}}::{struct_name}");"#)
-        .as_str();
-    code += "definition_name";
-    code += "}";
+    code += dto::make_definition_name_code(&struct_name, &ast.generics).as_str();
 
     code += "fn to_swagger_definition(context: &mut rupring::swagger::macros::SwaggerDefinitionContext) -> rupring::swagger::macros::SwaggerDefinitionNode {";
     code += r#"let mut swagger_definition = rupring::swagger::json::SwaggerDefinition {"#;
     code += r#"type_: "object".to_string(),"#;
-    code += r#"properties: std::collections::HashMap::new(),"#;
-    code += r#"required: vec![],"#;
-    code += r#"path_parameters: vec![],"#;
-    code += r#"query_parameters: vec![],"#;
+    code += r#"..Default::default()"#;
     code += "};";
 
+    let (impl_generics, type_generics, where_clause) = dto::split_generics(&ast.generics, None);
+
+    // The body is deserialized to {struct_name}__JSON, which has the body fields and their serde attributes.
     let mut define_struct_for_json = "".to_string();
     define_struct_for_json += "#[allow(non_camel_case_types)]";
     define_struct_for_json += r#"#[derive(serde::Serialize, serde::Deserialize)]"#;
-    define_struct_for_json += format!(r#"pub struct {struct_name}__JSON {{"#).as_str();
+
+    if !serde_container.passthrough.is_empty() {
+        define_struct_for_json +=
+            format!("#[serde({})]", serde_container.passthrough.join(", ")).as_str();
+    }
+
+    if serde_container.default.is_some() {
+        define_struct_for_json += "#[serde(default)]";
+    }
+
+    define_struct_for_json +=
+        format!(r#"pub struct {struct_name}__JSON {impl_generics} {where_clause} {{"#).as_str();
 
     let mut json_field_names = vec![];
     let mut path_field_names = vec![];
     let mut query_field_names = vec![];
 
+    let mut validation_code = "".to_string();
+
     for field in ast.fields.iter() {
         let mut description = "".to_string();
         let mut example = r#""""#.to_string();
@@ -672,11 +1464,26 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
         let mut is_path_parameter = false;
         let mut is_query_parameter = false;
 
-        if field_type.starts_with("Option<") {
+        let serde_field = serde_attribute::SerdeField::parse(&field.attrs);
+
+        // The name of the property in the body
+        let property_name = serde_field.rename.clone().unwrap_or_else(|| {
+            serde_attribute::rename_field(&field_name, &serde_container.rename_all)
+        });
+
+        let is_nullable = parse::is_option_type(&field.ty);
+
+        if is_nullable || serde_field.optional || serde_container.default.is_some() {
             is_required = false;
         }
 
+        let mut field_validation = validation::FieldValidation::default();
+
         for attribute in attributes {
+            if field_validation.parse_attribute(&field_name, &attribute) {
+                continue;
+            }
+
             let metadata = attribute.meta;
 
             let path = metadata.path().to_token_stream().to_string();
@@ -767,7 +1574,21 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
             continue;
         }
 
-        if is_required {
+        // path and query parameters are reported with the parameter name.
+        let error_field_name = if is_path_parameter || is_query_parameter {
+            param_name.clone()
+        } else {
+            property_name.clone()
+        };
+
+        validation_code += field_validation
+            .make_validation_code(&struct_field_name, &error_field_name)
+            .as_str();
+
+        let swagger_validation = field_validation.make_swagger_code();
+        let swagger_array_validation = field_validation.make_swagger_array_code();
+
+        if is_required && (is_path_parameter || is_query_parameter) {
             code += format!(r#"swagger_definition.required.push("{field_name}".to_string());"#)
                 .as_str();
         }
@@ -782,6 +1603,7 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
                 struct_field_name.clone(),
                 param_name.clone(),
                 field_type.clone(),
+                is_nullable,
             ));
 
             code += format!(
@@ -793,6 +1615,7 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
                 schema: Some(rupring::swagger::json::SwaggerTypeOrReference::Type(
                     rupring::swagger::json::SwaggerType {{
                         type_: "{field_type}".to_string(),
+                        validation: {swagger_validation},
                     }}
                 )),
                 type_: None,
@@ -807,6 +1630,7 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
                 struct_field_name.clone(),
                 param_name.clone(),
                 field_type.clone(),
+                is_nullable,
             ));
 
             code += format!(
@@ -818,6 +1642,7 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
                 schema: Some(rupring::swagger::json::SwaggerTypeOrReference::Type(
                     rupring::swagger::json::SwaggerType {{
                         type_: "{field_type}".to_string(),
+                        validation: {swagger_validation},
                     }}
                 )),
                 type_: None,
@@ -835,47 +1660,45 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
 
         define_struct_for_json += format!(
             r#"
-            pub {struct_field_name}: {field_type}, 
-        "#
+            {}
+            pub {struct_field_name}: {field_type},
+        "#,
+            serde_field.attributes.join(" ")
         )
         .as_str();
 
-        // Body 파라미터 생성 구현
-        code += format!(r#"let property_of_type = {field_type}::to_swagger_definition(context);"#)
-            .as_str();
-
-        code += format!(
-            r#"let property_value = match property_of_type {{
-            rupring::swagger::macros::SwaggerDefinitionNode::Single(leaf) => {{
-                rupring::swagger::json::SwaggerProperty::Single(rupring::swagger::json::SwaggerSingleProperty {{
-                    type_: leaf.type_,
-                    description: "{description}".to_string(),
-                    example: Some({example}.into()),
-                }})
-            }},
-            rupring::swagger::macros::SwaggerDefinitionNode::Array(array) => {{
-                rupring::swagger::json::SwaggerProperty::Array(rupring::swagger::json::SwaggerArrayProperty {{
-                    type_: array.type_,
-                    items: array.items,
-                    description: "{description}".to_string(),
-                }})
-            }},
-            rupring::swagger::macros::SwaggerDefinitionNode::Object(object) => {{
-                let definition_name = {field_type}::get_definition_name();
-
-                context.definitions.insert(definition_name.clone(), object);
+        // #[serde(skip)] fields are not in the body.
+        if serde_field.skip {
+            continue;
+        }
 
-                rupring::swagger::json::SwaggerProperty::Reference(rupring::swagger::json::SwaggerReferenceProperty {{
-                    reference: "{SHARP}/definitions/".to_string() + definition_name.as_str(),
-                    description: "{description}".to_string(),
-                }})
-            }},
-        }};"#
-        )
+        // Body 파라미터 생성 구현
+        code += dto::SwaggerFieldCode {
+            field_type: field_type.clone(),
+            property_name,
+            description,
+            example,
+            swagger_validation,
+            swagger_array_validation,
+            is_required,
+            is_nullable,
+            is_flatten: serde_field.flatten,
+        }
+        .make_code()
         .as_str();
+    }
 
-        code += format!(
-            r#"swagger_definition.properties.insert("{field_name}".to_string(), property_value);"#
+    let type_parameters = ast
+        .generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect::<Vec<_>>();
+
+    // Type parameters that are used only by path and query parameters
+    if !type_parameters.is_empty() {
+        define_struct_for_json += format!(
+            "#[serde(skip)] pub __phantom: std::marker::PhantomData<fn() -> ({},)>,",
+            type_parameters.join(", ")
         )
         .as_str();
     }
@@ -890,16 +1713,61 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
 
     code += define_struct_for_json.as_str();
 
+    // #[serde(default)]: the missing fields of the body are the fields of Default::default().
+    if let Some(default_function) = &serde_container.default {
+        let default_function = match default_function {
+            Some(function) => format!("{function}()"),
+            None => "Default::default()".to_string(),
+        };
+
+        let mut fields = json_field_names
+            .iter()
+            .map(|(struct_field_name, _, _)| {
+                format!("{struct_field_name}: default.{struct_field_name},")
+            })
+            .collect::<String>();
+
+        if !type_parameters.is_empty() {
+            fields += "__phantom: std::marker::PhantomData,";
+        }
+
+        code += format!(
+            r#"impl {impl_generics} Default for {struct_name}__JSON {type_generics} {where_clause} {{
+                fn default() -> Self {{
+                    let default: {struct_name} {type_generics} = {default_function};
+
+                    {struct_name}__JSON {{ {fields} }}
+                }}
+            }}"#
+        )
+        .as_str();
+    }
+
+    // Generic bodies are deserialized if the type arguments are deserializable.
+    let bind_where_clause = if type_parameters.is_empty() {
+        where_clause.clone()
+    } else {
+        dto::add_where_predicate(
+            &where_clause,
+            &format!("{struct_name}__JSON {type_generics}: serde::de::DeserializeOwned"),
+        )
+    };
+
     let mut request_bind_code = "".to_string();
-    request_bind_code +=
-        format!(r#"impl rupring::request::BindFromRequest for {struct_name} {{"#).as_str();
+    request_bind_code += format!(
+        r#"impl {impl_generics} rupring::request::BindFromRequest for {struct_name} {type_generics} {bind_where_clause} {{"#
+    )
+    .as_str();
 
     request_bind_code +=
         "fn bind(request: rupring::request::Request) -> rupring::anyhow::Result<Self> {";
     request_bind_code += "use rupring::request::ParamStringDeserializer;";
     request_bind_code += "use rupring::request::QueryStringDeserializer;";
 
-    request_bind_code += format!("let mut json_bound = rupring::serde_json::from_str::<{struct_name}__JSON>(request.body.as_str())?;").as_str();
+    request_bind_code += format!(
+        "let mut json_bound = request.deserialize_body::<{struct_name}__JSON {type_generics}>()?;"
+    )
+    .as_str();
 
     request_bind_code += format!("let bound = {struct_name} {{").as_str();
 
@@ -908,30 +1776,39 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
             format!("{struct_field_name}: json_bound.{struct_field_name},").as_str();
     }
 
-    for (struct_field_name, param_name, field_type) in path_field_names {
-        request_bind_code += format!(
-            r#"{struct_field_name}: {{
-                let param = rupring::request::ParamString(
-                    request.path_parameters["{param_name}"].clone()
-                );
+    for (struct_field_name, param_name, field_type, is_nullable) in path_field_names {
+        // An optional segment (ex. /posts/:page?) may not be captured.
+        let mut code_if_param_is_missing =
+            format!(r#"return Err(rupring::anyhow::anyhow!("invalid parameter: {param_name}"))"#);
 
-                let deserialized: {field_type} = match param.deserialize() {{
-                    Ok(v) => v,
-                    Err(_) => return Err(rupring::anyhow::anyhow!("invalid parameter: {param_name}")),
-                }};
+        if is_nullable {
+            code_if_param_is_missing = "None".to_string();
+        }
 
-                deserialized
-            }}
+        request_bind_code += format!(
+            r#"{struct_field_name}: match request.path_parameters.get("{param_name}") {{
+                Some(param) => {{
+                    let param = rupring::request::ParamString(param.clone());
+
+                    let deserialized: {field_type} = match param.deserialize() {{
+                        Ok(v) => v,
+                        Err(_) => return Err(rupring::anyhow::anyhow!("invalid parameter: {param_name}")),
+                    }};
+
+                    deserialized
+                }}
+                None => {code_if_param_is_missing},
+            }},
             "#
         )
         .as_str();
     }
 
-    for (struct_field_name, param_name, field_type) in query_field_names {
+    for (struct_field_name, param_name, field_type, is_nullable) in query_field_names {
         let mut code_if_field_type_is_none =
             format!(r#"return Err(rupring::anyhow::anyhow!("invalid parameter: {param_name}"));"#);
 
-        if field_type.starts_with("Option<") || field_type.starts_with("Option::<") {
+        if is_nullable {
             code_if_field_type_is_none = r#"
                 rupring::request::QueryString(vec![])
             "#
@@ -960,6 +1837,10 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
 
     request_bind_code += "};";
 
+    request_bind_code += r#"if let Err(errors) = rupring::validation::Validate::validate(&bound) {
+        return Err(rupring::error::Errors::ValidationFailed(errors).into());
+    }"#;
+
     request_bind_code += "Ok(bound)";
     request_bind_code += "}";
 
@@ -967,5 +1848,15 @@ pub fn derive_rupring_dto(item: TokenStream) -> TokenStream {
 
     code += request_bind_code.as_str();
 
+    code += format!(
+        r#"impl {impl_generics} rupring::validation::Validate for {struct_name} {type_generics} {where_clause} {{
+            #[allow(unused_variables)]
+            fn validate_fields(&self, path: &str, errors: &mut Vec<rupring::validation::FieldError>) {{
+                {validation_code}
+            }}
+        }}"#
+    )
+    .as_str();
+
     TokenStream::from_str(code.as_str()).unwrap()
 }
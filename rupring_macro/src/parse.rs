@@ -1,6 +1,6 @@
 use proc_macro::{TokenStream, TokenTree};
 use quote::ToTokens;
-use syn::{FnArg, ItemFn, ItemStruct};
+use syn::{FnArg, ItemFn, ItemStruct, Type};
 
 use crate::attribute::{self, AnnotatedParameter};
 
@@ -9,11 +9,71 @@ pub(crate) fn find_struct_name(struct_ast: &ItemStruct) -> String {
     struct_ast.ident.to_string()
 }
 
+// Option<T>, std::option::Option<T>
+pub(crate) fn is_option_type(type_: &Type) -> bool {
+    match type_ {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        Type::Group(group) => is_option_type(&group.elem),
+        Type::Paren(paren) => is_option_type(&paren.elem),
+        _ => false,
+    }
+}
+
+// &rupring::error::HttpError, &HttpError
+pub(crate) fn is_http_error_type(type_: &Type) -> bool {
+    match type_ {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "HttpError"),
+        Type::Reference(reference) => is_http_error_type(&reference.elem),
+        Type::Group(group) => is_http_error_type(&group.elem),
+        Type::Paren(paren) => is_http_error_type(&paren.elem),
+        _ => false,
+    }
+}
+
+// Option<T> => Some(T)
+pub(crate) fn option_inner_type(type_: &Type) -> Option<&Type> {
+    match type_ {
+        Type::Path(path) => {
+            let segment = path.path.segments.last()?;
+
+            if segment.ident != "Option" {
+                return None;
+            }
+
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(arguments) => {
+                    arguments.args.iter().find_map(|argument| match argument {
+                        syn::GenericArgument::Type(type_) => Some(type_),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            }
+        }
+        Type::Group(group) => option_inner_type(&group.elem),
+        Type::Paren(paren) => option_inner_type(&paren.elem),
+        _ => None,
+    }
+}
+
 // Find the structure name immediately to the right of the fn keyword.
 pub(crate) fn find_function_name(function_ast: &ItemFn) -> String {
     function_ast.sig.ident.to_string()
 }
 
+// Check whether the function is declared with the async keyword.
+pub(crate) fn is_async_function(function_ast: &ItemFn) -> bool {
+    function_ast.sig.asyncness.is_some()
+}
+
 // Returns the types of function parameters as an array.
 pub(crate) fn find_function_parameter_types(function_ast: &ItemFn) -> Vec<String> {
     let parameters = function_ast.sig.inputs.clone();
@@ -34,17 +94,70 @@ pub(crate) fn find_function_parameter_types(function_ast: &ItemFn) -> Vec<String
     parameters_types
 }
 
+// Removes the #[qualifier = "name"] attributes of the parameters, and returns the qualifier of each parameter.
+// Any other form, such as #[qualifier(name)] or #[qualifier = name], is an error.
+pub(crate) fn take_parameter_qualifiers(
+    function_ast: &mut ItemFn,
+) -> Result<Vec<Option<String>>, syn::Error> {
+    let mut qualifiers = vec![];
+
+    for arg in function_ast.sig.inputs.iter_mut() {
+        if let FnArg::Typed(pat_type) = arg {
+            let mut qualifier = None;
+
+            for attr in pat_type.attrs.iter() {
+                if !attr.path().is_ident("qualifier") {
+                    continue;
+                }
+
+                match &attr.meta {
+                    syn::Meta::NameValue(syn::MetaNameValue {
+                        value:
+                            syn::Expr::Lit(syn::ExprLit {
+                                lit: syn::Lit::Str(value),
+                                ..
+                            }),
+                        ..
+                    }) => qualifier = Some(value.value()),
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            attr,
+                            "invalid qualifier attribute (expected #[qualifier = \"name\"])",
+                        ))
+                    }
+                }
+            }
+
+            pat_type
+                .attrs
+                .retain(|attr| !attr.path().is_ident("qualifier"));
+
+            qualifiers.push(qualifier);
+        }
+    }
+
+    Ok(qualifiers)
+}
+
 // Find the return type of the function.
 pub(crate) fn find_function_return_type(function_ast: &ItemFn) -> String {
-    let return_type = function_ast.sig.output.to_token_stream().to_string();
-
-    // 화살표 제거
-    let return_type = return_type.replace(" -> ", "");
+    match &function_ast.sig.output {
+        syn::ReturnType::Type(_, return_type) => return_type.to_token_stream().to_string(),
+        syn::ReturnType::Default => "()".to_string(),
+    }
+}
 
-    // 공백 제거
-    let return_type = return_type.trim().to_owned();
+// path = /users/:id or path = "/users/:id(\\d+)"
+// Patterns such as \d cannot be written as tokens, so the path can also be given as a string literal.
+pub(crate) fn parse_route_path(path: &str) -> String {
+    if path.starts_with("\"") {
+        if let Ok(literal) = syn::parse_str::<syn::LitStr>(path) {
+            return literal.value();
+        }
+    }
 
-    return_type
+    // Tokens such as {id:int} are stringified with spaces.
+    path.chars().filter(|c| !c.is_whitespace()).collect()
 }
 
 // 1. 타입 일관성을 위해 Request와 Response 매개변수가 존재하지 않는다면 강제로 추가합니다.
@@ -61,154 +174,151 @@ pub(crate) fn manipulate_route_function_parameters(
     for mut token_tree in item.into_iter() {
         match token_tree.clone() {
             proc_macro::TokenTree::Ident(ident) => match ident.to_string().as_str() {
-                "fn" => {
-                    if !out_of_parameter {
-                        fn_passed = true;
-                    }
+                "fn" if !out_of_parameter => {
+                    fn_passed = true;
                 }
                 _ => {}
             },
-            proc_macro::TokenTree::Group(group) => {
-                if fn_passed && !out_of_parameter {
-                    // 파라미터 영역을 분석합니다.
-                    let mut iter = group.stream().into_iter().peekable();
-                    let mut annotation_removed = vec![];
-
-                    // annotation이 달린 특수한 파라미터들을 추출합니다.
-                    // ,를 기준으로 파라미터를 분리해서 분석합니다.
-                    while let Some(token_tree) = iter.next() {
-                        match token_tree.clone() {
-                            proc_macro::TokenTree::Punct(punct) => {
-                                let punct = punct.to_string();
-
-                                match punct.as_str() {
-                                    "#" => {
-                                        let group = iter.next().unwrap();
-
-                                        let mut attributes =
-                                            if let proc_macro::TokenTree::Group(group) = group {
-                                                attribute::parse_attribute(group.stream(), true)
-                                            } else {
-                                                panic!(
-                                                    "invalid annotation parameter (group expected)"
-                                                );
-                                            };
-
-                                        while let Some(TokenTree::Punct(punct)) = iter.peek() {
-                                            if punct.to_string() == "#" {
-                                                iter.next().unwrap();
-
-                                                let group = iter.next().unwrap();
-
-                                                let new_attributes =
-                                                    if let proc_macro::TokenTree::Group(group) =
-                                                        group
-                                                    {
-                                                        attribute::parse_attribute(
-                                                            group.stream(),
-                                                            true,
-                                                        )
-                                                    } else {
-                                                        panic!(
-                                                            "invalid annotation parameter (group expected)"
-                                                        );
-                                                    };
-
-                                                for (key, value) in new_attributes {
-                                                    attributes.insert(key, value);
-                                                }
-
-                                                continue;
+            proc_macro::TokenTree::Group(group) if fn_passed && !out_of_parameter => {
+                // 파라미터 영역을 분석합니다.
+                let mut iter = group.stream().into_iter().peekable();
+                let mut annotation_removed = vec![];
+
+                // annotation이 달린 특수한 파라미터들을 추출합니다.
+                // ,를 기준으로 파라미터를 분리해서 분석합니다.
+                while let Some(token_tree) = iter.next() {
+                    match token_tree.clone() {
+                        proc_macro::TokenTree::Punct(punct) => {
+                            let punct = punct.to_string();
+
+                            match punct.as_str() {
+                                "#" => {
+                                    let group = iter.next().unwrap();
+
+                                    let mut attributes =
+                                        if let proc_macro::TokenTree::Group(group) = group {
+                                            attribute::parse_attribute(group.stream(), true)
+                                        } else {
+                                            panic!("invalid annotation parameter (group expected)");
+                                        };
+
+                                    while let Some(TokenTree::Punct(punct)) = iter.peek() {
+                                        if punct.to_string() == "#" {
+                                            iter.next().unwrap();
+
+                                            let group = iter.next().unwrap();
+
+                                            let new_attributes =
+                                                if let proc_macro::TokenTree::Group(group) = group {
+                                                    attribute::parse_attribute(group.stream(), true)
+                                                } else {
+                                                    panic!(
+                                                        "invalid annotation parameter (group expected)"
+                                                    );
+                                                };
+
+                                            for (key, value) in new_attributes {
+                                                attributes.insert(key, value);
                                             }
 
-                                            break;
+                                            continue;
                                         }
 
-                                        let expect_name = iter.next().unwrap();
-                                        let name = if let proc_macro::TokenTree::Ident(ident) =
-                                            expect_name
-                                        {
+                                        break;
+                                    }
+
+                                    let expect_name = iter.next().unwrap();
+                                    let name =
+                                        if let proc_macro::TokenTree::Ident(ident) = expect_name {
                                             ident.to_string()
                                         } else {
                                             panic!("invalid annotation parameter (ident expected)");
                                         };
 
-                                        let expect_colon = iter.next().unwrap();
-                                        if expect_colon.to_string().as_str() != ":" {
-                                            panic!("invalid annotation parameter (: expected)");
-                                        }
-
-                                        let mut type_ = "".to_string();
-                                        for token_tree in iter.by_ref() {
-                                            let token_tree = token_tree.clone();
-                                            let token_tree = token_tree.to_string();
-
-                                            if token_tree.as_str() == "," {
-                                                break;
-                                            }
+                                    let expect_colon = iter.next().unwrap();
+                                    if expect_colon.to_string().as_str() != ":" {
+                                        panic!("invalid annotation parameter (: expected)");
+                                    }
 
-                                            type_.push_str(token_tree.as_str());
+                                    // , in generic arguments (ex. HashMap<String, String>) is part of the type.
+                                    let mut type_ = "".to_string();
+                                    let mut angle_bracket_depth = 0;
+                                    for token_tree in iter.by_ref() {
+                                        let token_tree = token_tree.clone();
+                                        let token_tree = token_tree.to_string();
+
+                                        match token_tree.as_str() {
+                                            "<" => angle_bracket_depth += 1,
+                                            ">" => angle_bracket_depth -= 1,
+                                            "," if angle_bracket_depth == 0 => break,
+                                            _ => {}
                                         }
 
-                                        parameters.push(AnnotatedParameter {
-                                            attributes,
-                                            name,
-                                            type_,
-                                        });
-                                    }
-                                    _ => {
-                                        annotation_removed.push(token_tree);
+                                        type_.push_str(token_tree.as_str());
                                     }
+
+                                    let syn_type = syn::parse_str(&type_).unwrap_or_else(|_| {
+                                        panic!("invalid annotation parameter (type expected)")
+                                    });
+
+                                    parameters.push(AnnotatedParameter {
+                                        attributes,
+                                        name,
+                                        type_,
+                                        syn_type,
+                                    });
+                                }
+                                _ => {
+                                    annotation_removed.push(token_tree);
                                 }
                             }
-                            _ => annotation_removed.push(token_tree),
                         }
+                        _ => annotation_removed.push(token_tree),
                     }
+                }
 
-                    // 여기서부턴 request, response 파라미터에 대한 기본 처리를 수행합니다.
-                    // ,를 기준으로 파라미터를 분리해서 분석합니다.
-                    let mut request_name = "request".to_string();
-                    let mut response_name = "response".to_string();
-
-                    let replaced_parameter: TokenStream = annotation_removed.into_iter().collect();
-                    let parameter_text = replaced_parameter.to_string();
-
-                    for parameter in parameter_text.split(",") {
-                        let parameter = parameter.trim();
-
-                        if parameter.contains("rupring::Request") || parameter.contains("Request") {
-                            request_name = parameter
-                                .split(":")
-                                .next()
-                                .unwrap()
-                                .to_string()
-                                .trim()
-                                .to_string();
-                        }
+                // 여기서부턴 request, response 파라미터에 대한 기본 처리를 수행합니다.
+                // ,를 기준으로 파라미터를 분리해서 분석합니다.
+                let mut request_name = "request".to_string();
+                let mut response_name = "response".to_string();
+
+                let replaced_parameter: TokenStream = annotation_removed.into_iter().collect();
+                let parameter_text = replaced_parameter.to_string();
+
+                for parameter in parameter_text.split(",") {
+                    let parameter = parameter.trim();
+
+                    if parameter.contains("rupring::Request") || parameter.contains("Request") {
+                        request_name = parameter
+                            .split(":")
+                            .next()
+                            .unwrap()
+                            .to_string()
+                            .trim()
+                            .to_string();
+                    }
 
-                        if parameter.contains("rupring::Response") || parameter.contains("Response")
-                        {
-                            response_name = parameter
-                                .split(":")
-                                .next()
-                                .unwrap()
-                                .to_string()
-                                .trim()
-                                .to_string();
-                        }
+                    if parameter.contains("rupring::Response") || parameter.contains("Response") {
+                        response_name = parameter
+                            .split(":")
+                            .next()
+                            .unwrap()
+                            .to_string()
+                            .trim()
+                            .to_string();
                     }
+                }
 
-                    let new_parameter_code = format!(
-                        "{request_name}: rupring::Request, {response_name}: rupring::Response",
-                    );
+                let new_parameter_code = format!(
+                    "{request_name}: rupring::Request, {response_name}: rupring::Response",
+                );
 
-                    token_tree = proc_macro::TokenTree::Group(proc_macro::Group::new(
-                        group.delimiter(),
-                        new_parameter_code.parse().unwrap(),
-                    ));
+                token_tree = proc_macro::TokenTree::Group(proc_macro::Group::new(
+                    group.delimiter(),
+                    new_parameter_code.parse().unwrap(),
+                ));
 
-                    out_of_parameter = true;
-                }
+                out_of_parameter = true;
             }
 
             _ => {}
@@ -13,3 +13,11 @@ pub(crate) fn make_handler_name(function_name: &str) -> String {
 
     handler_name
 }
+
+pub(crate) fn make_error_handler_name(function_name: &str) -> String {
+    let mut error_handler_name = String::new();
+
+    error_handler_name.push_str(&format!("ErrorHandler_{}", function_name));
+
+    error_handler_name
+}
@@ -0,0 +1,207 @@
+use quote::ToTokens;
+use syn::{punctuated::Punctuated, Attribute, Expr, Lit, Meta, Token};
+
+// #[serde(...)] attributes of a RupringDto struct or enum
+// #[serde(rename_all = "camelCase")], #[serde(tag = "type", content = "data")], #[serde(untagged)], #[serde(default)]
+#[derive(Debug, Default)]
+pub(crate) struct SerdeContainer {
+    pub rename_all: Option<String>,
+    pub rename_all_fields: Option<String>,
+    pub tag: Option<String>,
+    pub content: Option<String>,
+    pub untagged: bool,
+    // Some(None) for #[serde(default)], Some(Some(path)) for #[serde(default = "path")]
+    pub default: Option<Option<String>>,
+    // Attributes copied to the __JSON struct as they are. (ex. rename_all, deny_unknown_fields)
+    pub passthrough: Vec<String>,
+}
+
+// #[serde(...)] attributes of a field or a variant
+// #[serde(rename = "userName")], #[serde(skip)], #[serde(flatten)], #[serde(default)]
+#[derive(Debug, Default)]
+pub(crate) struct SerdeField {
+    pub rename: Option<String>,
+    pub rename_all: Option<String>,
+    pub skip: bool,
+    pub flatten: bool,
+    // The field may be missing. (default, skip_deserializing, skip_serializing_if, ...)
+    pub optional: bool,
+    // The attributes of the field, copied to the __JSON struct.
+    pub attributes: Vec<String>,
+}
+
+fn serde_metas(attributes: &[Attribute]) -> Vec<Meta> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("serde"))
+        .filter_map(|attribute| {
+            attribute
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .collect()
+}
+
+fn meta_name(meta: &Meta) -> String {
+    meta.path().to_token_stream().to_string()
+}
+
+// rename = "a" => "a"
+// rename(serialize = "a", deserialize = "b") => "b"
+fn meta_string(meta: &Meta) -> Option<String> {
+    match meta {
+        Meta::NameValue(name_value) => match &name_value.value {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Str(text) => Some(text.value()),
+                _ => None,
+            },
+            _ => None,
+        },
+        Meta::List(list) => {
+            let nested = list
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()?;
+
+            let find = |name: &str| {
+                nested
+                    .iter()
+                    .find(|meta| meta_name(meta) == name)
+                    .and_then(meta_string)
+            };
+
+            find("deserialize").or_else(|| find("serialize"))
+        }
+        Meta::Path(_) => None,
+    }
+}
+
+impl SerdeContainer {
+    pub(crate) fn parse(attributes: &[Attribute]) -> SerdeContainer {
+        let mut container = SerdeContainer::default();
+
+        for meta in serde_metas(attributes) {
+            match meta_name(&meta).as_str() {
+                "rename_all" => container.rename_all = meta_string(&meta),
+                "rename_all_fields" => container.rename_all_fields = meta_string(&meta),
+                "tag" => container.tag = meta_string(&meta),
+                "content" => container.content = meta_string(&meta),
+                "untagged" => container.untagged = true,
+                "default" => {
+                    container.default = Some(meta_string(&meta));
+
+                    // The default of the __JSON struct is generated from the default of the struct.
+                    continue;
+                }
+                // Conversions of the struct don't apply to the __JSON struct.
+                "from" | "try_from" | "into" | "remote" => continue,
+                _ => {}
+            }
+
+            container
+                .passthrough
+                .push(meta.to_token_stream().to_string());
+        }
+
+        container
+    }
+}
+
+impl SerdeField {
+    pub(crate) fn parse(attributes: &[Attribute]) -> SerdeField {
+        let mut field = SerdeField {
+            attributes: attributes
+                .iter()
+                .filter(|attribute| attribute.path().is_ident("serde"))
+                .map(|attribute| attribute.to_token_stream().to_string())
+                .collect(),
+            ..Default::default()
+        };
+
+        for meta in serde_metas(attributes) {
+            match meta_name(&meta).as_str() {
+                "rename" => field.rename = meta_string(&meta),
+                "rename_all" => field.rename_all = meta_string(&meta),
+                "skip" => field.skip = true,
+                "flatten" => field.flatten = true,
+                "default" | "skip_deserializing" | "skip_serializing" | "skip_serializing_if" => {
+                    field.optional = true
+                }
+                _ => {}
+            }
+        }
+
+        field
+    }
+}
+
+// The name of a snake_case field with #[serde(rename_all = "...")]
+pub(crate) fn rename_field(name: &str, rule: &Option<String>) -> String {
+    let Some(rule) = rule else {
+        return name.to_string();
+    };
+
+    match rule.as_str() {
+        "lowercase" | "snake_case" => name.to_string(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => name.to_ascii_uppercase(),
+        "PascalCase" => name.split('_').map(capitalize).collect(),
+        "camelCase" => {
+            let pascal = rename_field(name, &Some("PascalCase".to_string()));
+            uncapitalize(&pascal)
+        }
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_ascii_uppercase(),
+        _ => name.to_string(),
+    }
+}
+
+// The name of a PascalCase variant with #[serde(rename_all = "...")]
+pub(crate) fn rename_variant(name: &str, rule: &Option<String>) -> String {
+    let Some(rule) = rule else {
+        return name.to_string();
+    };
+
+    let snake_case = || {
+        let mut snake = String::new();
+
+        for (i, c) in name.char_indices() {
+            if c.is_uppercase() && i > 0 {
+                snake.push('_');
+            }
+
+            snake.push(c.to_ascii_lowercase());
+        }
+
+        snake
+    };
+
+    match rule.as_str() {
+        "lowercase" => name.to_ascii_lowercase(),
+        "UPPERCASE" => name.to_ascii_uppercase(),
+        "PascalCase" => name.to_string(),
+        "camelCase" => uncapitalize(name),
+        "snake_case" => snake_case(),
+        "SCREAMING_SNAKE_CASE" => snake_case().to_ascii_uppercase(),
+        "kebab-case" => snake_case().replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => snake_case().replace('_', "-").to_ascii_uppercase(),
+        _ => name.to_string(),
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => "".to_string(),
+    }
+}
+
+fn uncapitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => "".to_string(),
+    }
+}
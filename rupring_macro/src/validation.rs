@@ -0,0 +1,233 @@
+use quote::ToTokens;
+use syn::{Attribute, Expr, Lit};
+
+// Validation attributes of a RupringDto field
+// #[min = 0], #[max = 10], #[range(min = 0, max = 10)], #[length(min = 1, max = 20)], #[pattern = "..."], #[email], #[validate]
+#[derive(Debug, Default)]
+pub(crate) struct FieldValidation {
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<String>,
+    pub email: bool,
+    pub nested: bool,
+}
+
+impl FieldValidation {
+    // Returns false if the attribute is not a validation attribute.
+    pub(crate) fn parse_attribute(&mut self, field_name: &str, attribute: &Attribute) -> bool {
+        let path = attribute.meta.path().to_token_stream().to_string();
+
+        match path.as_str() {
+            "min" => {
+                self.minimum = Some(parse_number(field_name, &path, name_value(attribute)));
+            }
+            "max" => {
+                self.maximum = Some(parse_number(field_name, &path, name_value(attribute)));
+            }
+            "range" => {
+                for (key, value) in list(field_name, attribute) {
+                    match key.as_str() {
+                        "min" => self.minimum = Some(parse_number(field_name, &path, Some(&value))),
+                        "max" => self.maximum = Some(parse_number(field_name, &path, Some(&value))),
+                        _ => panic!(
+                            "invalid range attribute of field {field_name} (min or max expected)"
+                        ),
+                    }
+                }
+            }
+            "length" => {
+                for (key, value) in list(field_name, attribute) {
+                    let length = parse_number(field_name, &path, Some(&value));
+
+                    if length < 0.0 || length.fract() != 0.0 {
+                        panic!("invalid length attribute of field {field_name} (unsigned integer expected)");
+                    }
+
+                    match key.as_str() {
+                        "min" => self.min_length = Some(length as usize),
+                        "max" => self.max_length = Some(length as usize),
+                        _ => panic!(
+                            "invalid length attribute of field {field_name} (min or max expected)"
+                        ),
+                    }
+                }
+            }
+            "pattern" => {
+                let pattern = match name_value(attribute) {
+                    Some(Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(pattern),
+                        ..
+                    })) => pattern.value(),
+                    _ => {
+                        panic!("invalid pattern attribute of field {field_name} (string expected)")
+                    }
+                };
+
+                if let Err(error) = regex::Regex::new(&pattern) {
+                    panic!("invalid pattern attribute of field {field_name}: {error}");
+                }
+
+                self.pattern = Some(pattern);
+            }
+            "email" => {
+                self.email = true;
+            }
+            "validate" => {
+                self.nested = true;
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    // Body of Validate::validate_fields for the field.
+    pub(crate) fn make_validation_code(
+        &self,
+        struct_field_name: &str,
+        error_field_name: &str,
+    ) -> String {
+        let mut checks = vec![];
+
+        if let (Some(minimum), Some(maximum)) = (self.minimum, self.maximum) {
+            checks.push(format!(
+                "rupring::validation::range(&self.{struct_field_name}, Some({minimum:?}), Some({maximum:?}))"
+            ));
+        } else if let Some(minimum) = self.minimum {
+            checks.push(format!(
+                "rupring::validation::min(&self.{struct_field_name}, {minimum:?})"
+            ));
+        } else if let Some(maximum) = self.maximum {
+            checks.push(format!(
+                "rupring::validation::max(&self.{struct_field_name}, {maximum:?})"
+            ));
+        }
+
+        if self.min_length.is_some() || self.max_length.is_some() {
+            checks.push(format!(
+                "rupring::validation::length(&self.{struct_field_name}, {:?}, {:?})",
+                self.min_length, self.max_length
+            ));
+        }
+
+        if let Some(pattern) = &self.pattern {
+            checks.push(format!(
+                r#"{{
+                    static PATTERN: rupring::validation::Pattern = rupring::validation::Pattern::new({pattern:?});
+                    rupring::validation::pattern(&self.{struct_field_name}, &PATTERN)
+                }}"#
+            ));
+        }
+
+        if self.email {
+            checks.push(format!(
+                "rupring::validation::email(&self.{struct_field_name})"
+            ));
+        }
+
+        let mut code = "".to_string();
+
+        for check in checks {
+            code += format!(
+                r#"if let Err(message) = {check} {{
+                    errors.push(rupring::validation::FieldError {{
+                        field: rupring::validation::field_path(path, "{error_field_name}"),
+                        message,
+                    }});
+                }}"#
+            )
+            .as_str();
+        }
+
+        if self.nested {
+            code += format!(
+                r#"rupring::validation::Validate::validate_fields(
+                    &self.{struct_field_name},
+                    &rupring::validation::field_path(path, "{error_field_name}"),
+                    errors,
+                );"#
+            )
+            .as_str();
+        }
+
+        code
+    }
+
+    // SwaggerValidation for single value properties and parameters
+    pub(crate) fn make_swagger_code(&self) -> String {
+        let format = if self.email {
+            r#"Some("email".to_string())"#
+        } else {
+            "None"
+        };
+
+        let pattern = match &self.pattern {
+            Some(pattern) => format!("Some({pattern:?}.to_string())"),
+            None => "None".to_string(),
+        };
+
+        format!(
+            r#"rupring::swagger::json::SwaggerValidation {{
+                minimum: {:?},
+                maximum: {:?},
+                min_length: {:?},
+                max_length: {:?},
+                pattern: {pattern},
+                format: {format},
+                ..Default::default()
+            }}"#,
+            self.minimum, self.maximum, self.min_length, self.max_length
+        )
+    }
+
+    // SwaggerValidation for array properties (length is the number of items)
+    pub(crate) fn make_swagger_array_code(&self) -> String {
+        format!(
+            r#"rupring::swagger::json::SwaggerValidation {{
+                min_items: {:?},
+                max_items: {:?},
+                ..Default::default()
+            }}"#,
+            self.min_length, self.max_length
+        )
+    }
+}
+
+fn name_value(attribute: &Attribute) -> Option<&Expr> {
+    attribute.meta.require_name_value().ok().map(|e| &e.value)
+}
+
+// (min = 1, max = 20) => [("min", 1), ("max", 20)]
+fn list(field_name: &str, attribute: &Attribute) -> Vec<(String, Expr)> {
+    let mut values = vec![];
+
+    let result = attribute.parse_nested_meta(|meta| {
+        let key = meta.path.to_token_stream().to_string();
+        let value: Expr = meta.value()?.parse()?;
+
+        values.push((key, value));
+
+        Ok(())
+    });
+
+    if let Err(error) = result {
+        panic!("invalid validation attribute of field {field_name}: {error}");
+    }
+
+    values
+}
+
+fn parse_number(field_name: &str, attribute_name: &str, value: Option<&Expr>) -> f64 {
+    let number = value
+        .map(|value| value.to_token_stream().to_string().replace(' ', ""))
+        .and_then(|value| value.parse::<f64>().ok());
+
+    match number {
+        Some(number) => number,
+        None => {
+            panic!("invalid {attribute_name} attribute of field {field_name} (number expected)")
+        }
+    }
+}